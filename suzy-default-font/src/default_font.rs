@@ -1,10 +1,8960 @@
 pub const TEXTURE_WIDTH: u16 = 1024;pub const TEXTURE_HEIGHT: u16 = 512;pub mod regular {
-                use super::Glyph;
+                use super::{Glyph, KerningPair};
                 pub const PADDING_RATIO: f32 = 0.2f32;
                 pub const LINE_SPACING: f32 = 1f32;
                 pub const ASCENT: f32 = 0.9277344f32;
                 pub const DESCENT: f32 = -0.24414063f32;
-                pub static GLYPHS: &[Glyph] = &[
+                pub static KERNING: &[KerningPair] = &[
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{22}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{27}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{41}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{61}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{63}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{64}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{65}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{67}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{6d}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{6e}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{6f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{70}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{71}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{73}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{77}',
+                        kerning:0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c0}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c1}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c2}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c3}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c4}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c5}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e0}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e1}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e2}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e3}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e4}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e5}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e7}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e8}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e9}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{ea}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{eb}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f1}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f2}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f3}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f4}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f5}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f6}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{22}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{27}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{41}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{61}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{63}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{64}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{65}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{67}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{6d}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{6e}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{6f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{70}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{71}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{73}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{77}',
+                        kerning:0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c0}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c1}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c2}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c3}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c4}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c5}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e0}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e1}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e2}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e3}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e4}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e5}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e7}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e8}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e9}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{ea}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{eb}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f1}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f2}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f3}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f4}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f5}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f6}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{56}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{57}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{59}',
+                        kerning:0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{dd}',
+                        kerning:0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{2c}',
+                        right:'\u{22}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{2c}',
+                        right:'\u{27}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{2e}',
+                        right:'\u{22}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{2e}',
+                        right:'\u{27}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{2f}',
+                        right:'\u{2f}',
+                        kerning:-0.109375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{3f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{43}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{47}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{4f}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{51}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{54}',
+                        kerning:-0.06298828f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{56}',
+                        kerning:-0.04248047f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{57}',
+                        kerning:-0.033691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{59}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{c7}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d6}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d8}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{dd}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{56}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{59}',
+                        kerning:-0.026855469f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{dd}',
+                        kerning:-0.026855469f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{29}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{5d}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{7d}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{2c}',
+                        kerning:-0.11425781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{2e}',
+                        kerning:-0.11425781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{41}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{4a}',
+                        kerning:-0.12890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{61}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{63}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{64}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{65}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{67}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{6f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{71}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{72}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{75}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{76}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{79}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c0}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c1}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c2}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c3}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c4}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c5}',
+                        kerning:-0.08300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e0}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e1}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e2}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e3}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e4}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e5}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e9}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{ea}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{eb}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f9}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fa}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fb}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fc}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fd}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{ff}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{2d}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{43}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{47}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{4f}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{51}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{63}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{64}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{65}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{67}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{6d}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{6e}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{6f}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{70}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{71}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{75}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{76}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{77}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{79}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{ad}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{c7}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d2}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d3}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d4}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d5}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d6}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d8}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{e7}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{e8}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{e9}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{ea}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{eb}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f1}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f2}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f3}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f4}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f5}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f6}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f9}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fa}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fb}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fc}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fd}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{ff}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{22}',
+                        kerning:-0.1640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{27}',
+                        kerning:-0.1640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{41}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{43}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{47}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{4f}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{51}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{54}',
+                        kerning:-0.13427734f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{55}',
+                        kerning:-0.026367188f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{56}',
+                        kerning:-0.08544922f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{57}',
+                        kerning:-0.06982422f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{59}',
+                        kerning:-0.11669922f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{75}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{76}',
+                        kerning:-0.064941406f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{77}',
+                        kerning:-0.044921875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{79}',
+                        kerning:-0.064941406f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c0}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c1}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c2}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c3}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c4}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c5}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c7}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d2}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d3}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d4}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d5}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d6}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d8}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d9}',
+                        kerning:-0.026367188f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{da}',
+                        kerning:-0.026367188f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{db}',
+                        kerning:-0.026367188f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{dc}',
+                        kerning:-0.026367188f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{dd}',
+                        kerning:-0.11669922f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{f9}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fa}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fb}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fc}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fd}',
+                        kerning:-0.064941406f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{ff}',
+                        kerning:-0.064941406f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{2c}',
+                        kerning:-0.15820313f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{2e}',
+                        kerning:-0.15820313f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{41}',
+                        kerning:-0.06738281f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{4a}',
+                        kerning:-0.09765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{58}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{5a}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{61}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{6f}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{74}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{76}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{79}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c0}',
+                        kerning:-0.06738281f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c1}',
+                        kerning:-0.06738281f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c2}',
+                        kerning:-0.06738281f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c3}',
+                        kerning:-0.06738281f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c4}',
+                        kerning:-0.06738281f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c5}',
+                        kerning:-0.06738281f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c6}',
+                        kerning:-0.04736328f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e0}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e1}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f2}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f3}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f4}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f5}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f6}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{fd}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{ff}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{54}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{56}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{57}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{59}',
+                        kerning:-0.017089844f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{dd}',
+                        kerning:-0.017089844f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{54}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{56}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{59}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{dd}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{2c}',
+                        kerning:-0.10644531f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{2d}',
+                        kerning:-0.11328125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{2e}',
+                        kerning:-0.10644531f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{41}',
+                        kerning:-0.03857422f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{43}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{47}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{4a}',
+                        kerning:-0.1171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{4f}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{51}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{53}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{54}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{56}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{57}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{59}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{61}',
+                        kerning:-0.05517578f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{63}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{64}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{65}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{67}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{6d}',
+                        kerning:-0.053222656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{6e}',
+                        kerning:-0.053222656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{6f}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{70}',
+                        kerning:-0.053222656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{71}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{72}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{73}',
+                        kerning:-0.056640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{75}',
+                        kerning:-0.04638672f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{76}',
+                        kerning:-0.03515625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{77}',
+                        kerning:-0.027832031f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{78}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{79}',
+                        kerning:-0.03515625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{7a}',
+                        kerning:-0.029296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ab}',
+                        kerning:-0.16015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ad}',
+                        kerning:-0.11328125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{bb}',
+                        kerning:-0.10546875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c0}',
+                        kerning:-0.03857422f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c1}',
+                        kerning:-0.03857422f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c2}',
+                        kerning:-0.03857422f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c3}',
+                        kerning:-0.03857422f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c4}',
+                        kerning:-0.03857422f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c5}',
+                        kerning:-0.03857422f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c6}',
+                        kerning:-0.087402344f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c7}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d2}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d3}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d4}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d5}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d6}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d8}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{dd}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e0}',
+                        kerning:-0.05517578f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e1}',
+                        kerning:-0.05517578f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e2}',
+                        kerning:-0.05517578f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e3}',
+                        kerning:-0.05517578f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e4}',
+                        kerning:-0.05517578f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e5}',
+                        kerning:-0.05517578f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e6}',
+                        kerning:-0.055664063f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e7}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e8}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e9}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ea}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{eb}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f1}',
+                        kerning:-0.053222656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f2}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f3}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f4}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f5}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f6}',
+                        kerning:-0.048339844f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f8}',
+                        kerning:-0.04638672f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f9}',
+                        kerning:-0.04638672f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fa}',
+                        kerning:-0.04638672f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fb}',
+                        kerning:-0.04638672f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fc}',
+                        kerning:-0.04638672f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fd}',
+                        kerning:-0.03515625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ff}',
+                        kerning:-0.03515625f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{2c}',
+                        kerning:-0.10986328f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{2d}',
+                        kerning:-0.018066406f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{2e}',
+                        kerning:-0.10986328f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{41}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{43}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{47}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{4f}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{51}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{5d}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{61}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{63}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{64}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{65}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{67}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{6f}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{71}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{72}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{75}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{ad}',
+                        kerning:-0.018066406f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c0}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c1}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c2}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c3}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c4}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c5}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d2}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d3}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d4}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d5}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d6}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e0}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e1}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e2}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e3}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e4}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e5}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e7}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e8}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e9}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{ea}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{eb}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f2}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f3}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f4}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f5}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f6}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f9}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fa}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fb}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fc}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{29}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{2c}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{2d}',
+                        kerning:-0.029296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{2e}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{41}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{54}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{5d}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{61}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{63}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{64}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{65}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{67}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{6f}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{71}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{72}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{75}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{7d}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{ad}',
+                        kerning:-0.029296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c0}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c1}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c2}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c3}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c4}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c5}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e0}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e1}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e2}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e3}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e4}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e5}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e7}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e8}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e9}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{ea}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{eb}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f2}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f3}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f4}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f5}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f6}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{fa}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{fb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{fc}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{2d}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{43}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{47}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{4f}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{51}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{56}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{63}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{64}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{65}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{67}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{6f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{71}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{75}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{76}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{79}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{ad}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{c7}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d2}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d3}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d4}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d5}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d6}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d8}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{e7}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{e8}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{e9}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{ea}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{eb}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f9}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fa}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fb}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fc}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fd}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{ff}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{26}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2a}',
+                        kerning:-0.023925781f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2c}',
+                        kerning:-0.103027344f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2d}',
+                        kerning:-0.025390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2e}',
+                        kerning:-0.103027344f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{41}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{43}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{47}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{4a}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{4f}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{51}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{53}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{54}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{55}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{56}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{57}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{58}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{59}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{5d}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{61}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{63}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{64}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{65}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{66}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{67}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{6d}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{6e}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{6f}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{70}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{71}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{72}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{73}',
+                        kerning:-0.028320313f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{74}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{75}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{76}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{78}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{79}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{7a}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ab}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ad}',
+                        kerning:-0.025390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{bb}',
+                        kerning:-0.024902344f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c0}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c1}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c2}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c3}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c4}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c5}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c6}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c7}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d2}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d3}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d4}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d5}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d6}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d8}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d9}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{da}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{db}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{dc}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{dd}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e0}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e1}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e2}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e3}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e4}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e5}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e6}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e7}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e8}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e9}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ea}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{eb}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f1}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f2}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f3}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f4}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f5}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f6}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f8}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f9}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fa}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fb}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fc}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fd}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ff}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{41}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{43}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{47}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{4f}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{51}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{63}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{64}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{65}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{67}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{6f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{71}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{75}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{76}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{77}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{79}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c0}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c1}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c2}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c3}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c4}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c5}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c7}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d2}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d3}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d4}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d5}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d6}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d8}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{e7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{e8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{e9}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{ea}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{eb}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fa}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fc}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fd}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{ff}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{4a}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{55}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{d9}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{da}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{db}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{dc}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{22}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{27}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{22}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{27}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{78}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{7a}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{63}',
+                        right:'\u{22}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{63}',
+                        right:'\u{27}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{22}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{27}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{5d}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{63}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{64}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{65}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{67}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{71}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{e7}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{e8}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{e9}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{ea}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{eb}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{68}',
+                        right:'\u{22}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{68}',
+                        right:'\u{27}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{63}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{64}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{65}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{67}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{71}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{e7}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{e8}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{e9}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{ea}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{eb}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6d}',
+                        right:'\u{22}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{6d}',
+                        right:'\u{27}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{6e}',
+                        right:'\u{22}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{6e}',
+                        right:'\u{27}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{22}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{27}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{22}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{27}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{78}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{7a}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{22}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{27}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{2c}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{2e}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{61}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{66}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{6f}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{74}',
+                        kerning:0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{76}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{77}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{79}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e0}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e1}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e2}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e3}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e4}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e5}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f2}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f3}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f4}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f5}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f6}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{fd}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{ff}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{6f}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f2}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f3}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f4}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f5}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f6}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{2c}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{2e}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{77}',
+                        right:'\u{2c}',
+                        kerning:-0.060546875f32,
+                    },
+                    KerningPair {
+                        left:'\u{77}',
+                        right:'\u{2e}',
+                        kerning:-0.060546875f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{63}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{64}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{65}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{67}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{6f}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{71}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{e7}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{e8}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{e9}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{ea}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{eb}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f2}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f3}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f4}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f5}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f6}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{2c}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{2e}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{63}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{64}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{65}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{67}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{6f}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{71}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{e7}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{e8}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{e9}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{ea}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{eb}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f2}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f3}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f4}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f5}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f6}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{4a}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{55}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{d9}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{da}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{db}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{dc}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{3f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{43}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{47}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{4f}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{51}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{54}',
+                        kerning:-0.06298828f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{56}',
+                        kerning:-0.04248047f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{57}',
+                        kerning:-0.033691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{59}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{c7}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d6}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d8}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{dd}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{3f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{43}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{47}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{4f}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{51}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{54}',
+                        kerning:-0.06298828f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{56}',
+                        kerning:-0.04248047f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{57}',
+                        kerning:-0.033691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{59}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{c7}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d6}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d8}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{dd}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{3f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{43}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{47}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{4f}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{51}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{54}',
+                        kerning:-0.06298828f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{56}',
+                        kerning:-0.04248047f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{57}',
+                        kerning:-0.033691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{59}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{c7}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d6}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d8}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{dd}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{3f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{43}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{47}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{4f}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{51}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{54}',
+                        kerning:-0.06298828f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{56}',
+                        kerning:-0.04248047f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{57}',
+                        kerning:-0.033691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{59}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{c7}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d6}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d8}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{dd}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{3f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{43}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{47}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{4f}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{51}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{54}',
+                        kerning:-0.06298828f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{56}',
+                        kerning:-0.04248047f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{57}',
+                        kerning:-0.033691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{59}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{c7}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d6}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d8}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{dd}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{3f}',
+                        kerning:-0.029785156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{43}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{47}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{4f}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{51}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{54}',
+                        kerning:-0.06298828f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{56}',
+                        kerning:-0.04248047f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{57}',
+                        kerning:-0.033691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{59}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{c7}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d6}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d8}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{dd}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{29}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{5d}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{7d}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{2c}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{2e}',
+                        kerning:-0.049804688f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{26}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2a}',
+                        kerning:-0.023925781f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2c}',
+                        kerning:-0.103027344f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2d}',
+                        kerning:-0.025390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2e}',
+                        kerning:-0.103027344f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{41}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{43}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{47}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{4a}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{4f}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{51}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{53}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{54}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{55}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{56}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{57}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{58}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{59}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{5d}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{61}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{63}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{64}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{65}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{66}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{67}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{6d}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{6e}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{6f}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{70}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{71}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{72}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{73}',
+                        kerning:-0.028320313f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{74}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{75}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{76}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{78}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{79}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{7a}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ab}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ad}',
+                        kerning:-0.025390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{bb}',
+                        kerning:-0.024902344f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c0}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c1}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c2}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c3}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c4}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c5}',
+                        kerning:-0.045898438f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c6}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c7}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d2}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d3}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d4}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d5}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d6}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d8}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d9}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{da}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{db}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{dc}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{dd}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e0}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e1}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e2}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e3}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e4}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e5}',
+                        kerning:-0.03564453f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e6}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e7}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e8}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e9}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ea}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{eb}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f1}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f2}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f3}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f4}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f5}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f6}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f8}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f9}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fa}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fb}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fc}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fd}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ff}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{22}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{27}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{22}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{27}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{22}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{27}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{22}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{27}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{22}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{27}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{22}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{27}',
+                        kerning:-0.032714844f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e7}',
+                        right:'\u{22}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{e7}',
+                        right:'\u{27}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{f1}',
+                        right:'\u{22}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f1}',
+                        right:'\u{27}',
+                        kerning:-0.05078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{22}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{27}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{22}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{27}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{22}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{27}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{22}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{27}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{22}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{27}',
+                        kerning:-0.06640625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{2c}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{2e}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{22}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{27}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{78}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{7a}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{2c}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{2e}',
+                        kerning:-0.052246094f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },];pub static GLYPHS: &[Glyph] = &[
                     Glyph {
                         ch:'\u{21}',
                         advance:0.25732422f32,
@@ -2273,12 +11223,9082 @@ pub const TEXTURE_WIDTH: u16 = 1024;pub const TEXTURE_HEIGHT: u16 = 512;pub mod
                         tex_bottom:307,
                         tex_top:354,
                     },];pub const CAPLINE: f32 = 0.7109375f32;pub const X_HEIGHT: f32 = 0.5283203f32;}pub mod bold {
-                use super::Glyph;
+                use super::{Glyph, KerningPair};
                 pub const PADDING_RATIO: f32 = 0.2f32;
                 pub const LINE_SPACING: f32 = 1f32;
                 pub const ASCENT: f32 = 0.9277344f32;
                 pub const DESCENT: f32 = -0.24414063f32;
-                pub static GLYPHS: &[Glyph] = &[
+                pub static KERNING: &[KerningPair] = &[
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{22}',
+                        kerning:-0.018066406f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{27}',
+                        kerning:-0.018066406f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{41}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{61}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{63}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{64}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{65}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{67}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{6d}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{6e}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{6f}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{70}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{71}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{73}',
+                        kerning:-0.044921875f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{77}',
+                        kerning:0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c0}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c1}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c2}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c3}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c4}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{c5}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e0}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e1}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e2}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e3}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e4}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e5}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e7}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e8}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{e9}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{ea}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{eb}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f1}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f2}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f3}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f4}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f5}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{22}',
+                        right:'\u{f6}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{22}',
+                        kerning:-0.018066406f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{27}',
+                        kerning:-0.018066406f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{41}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{61}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{63}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{64}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{65}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{67}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{6d}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{6e}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{6f}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{70}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{71}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{73}',
+                        kerning:-0.044921875f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{77}',
+                        kerning:0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c0}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c1}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c2}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c3}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c4}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{c5}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e0}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e1}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e2}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e3}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e4}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e5}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e7}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e8}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{e9}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{ea}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{eb}',
+                        kerning:-0.028808594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f1}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f2}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f3}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f4}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f5}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{27}',
+                        right:'\u{f6}',
+                        kerning:-0.044433594f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{56}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{57}',
+                        kerning:0.018554688f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{59}',
+                        kerning:0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{28}',
+                        right:'\u{dd}',
+                        kerning:0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{2c}',
+                        right:'\u{22}',
+                        kerning:-0.13916016f32,
+                    },
+                    KerningPair {
+                        left:'\u{2c}',
+                        right:'\u{27}',
+                        kerning:-0.13916016f32,
+                    },
+                    KerningPair {
+                        left:'\u{2e}',
+                        right:'\u{22}',
+                        kerning:-0.13916016f32,
+                    },
+                    KerningPair {
+                        left:'\u{2e}',
+                        right:'\u{27}',
+                        kerning:-0.13916016f32,
+                    },
+                    KerningPair {
+                        left:'\u{2f}',
+                        right:'\u{2f}',
+                        kerning:-0.12109375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{3f}',
+                        kerning:-0.03955078f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{43}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{47}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{4f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{51}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{54}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{56}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{57}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{59}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{6d}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{6e}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{70}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{c7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{dd}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f1}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{41}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{54}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{56}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{59}',
+                        kerning:-0.026855469f32,
+                    },
+                    KerningPair {
+                        left:'\u{42}',
+                        right:'\u{dd}',
+                        kerning:-0.026855469f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{29}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{5d}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{43}',
+                        right:'\u{7d}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{44}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{45}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{2c}',
+                        kerning:-0.13378906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{2e}',
+                        kerning:-0.13378906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{41}',
+                        kerning:-0.09375f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{4a}',
+                        kerning:-0.1015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{61}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{63}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{64}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{65}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{67}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{6f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{71}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{72}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{75}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{76}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{79}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c0}',
+                        kerning:-0.09375f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c1}',
+                        kerning:-0.09375f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c2}',
+                        kerning:-0.09375f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c3}',
+                        kerning:-0.09375f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c4}',
+                        kerning:-0.09375f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{c5}',
+                        kerning:-0.09375f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e0}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e1}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e2}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e3}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e4}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e5}',
+                        kerning:-0.016601563f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{e9}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{ea}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{eb}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{f9}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fa}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fb}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fc}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{fd}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{46}',
+                        right:'\u{ff}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{48}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{49}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4a}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{2d}',
+                        kerning:-0.080078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{43}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{47}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{4f}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{51}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{63}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{64}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{65}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{67}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{6f}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{71}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{75}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{76}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{77}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{79}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{ad}',
+                        kerning:-0.080078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{c7}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d2}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d3}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d4}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d5}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d6}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{d8}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{e7}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{e8}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{e9}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{ea}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{eb}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f2}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f3}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f4}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f5}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f6}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{f9}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fa}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fb}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fc}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{fd}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4b}',
+                        right:'\u{ff}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{22}',
+                        kerning:-0.140625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{27}',
+                        kerning:-0.140625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{41}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{43}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{47}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{4f}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{51}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{54}',
+                        kerning:-0.100097656f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{55}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{56}',
+                        kerning:-0.10058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{57}',
+                        kerning:-0.045410156f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{59}',
+                        kerning:-0.13623047f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{75}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{76}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{77}',
+                        kerning:-0.025390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{79}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c0}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c1}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c2}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c3}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c4}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c5}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{c7}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d2}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d3}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d4}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d5}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d6}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d8}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{d9}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{da}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{db}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{dc}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{dd}',
+                        kerning:-0.13623047f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{f9}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fa}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fb}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fc}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{fd}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4c}',
+                        right:'\u{ff}',
+                        kerning:-0.060058594f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4d}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{4e}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{4f}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{2c}',
+                        kerning:-0.19726563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{2e}',
+                        kerning:-0.19726563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{41}',
+                        kerning:-0.08691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{4a}',
+                        kerning:-0.08984375f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{58}',
+                        kerning:-0.024902344f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{5a}',
+                        kerning:-0.017578125f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{61}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{6f}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{74}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{76}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{79}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c0}',
+                        kerning:-0.08691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c1}',
+                        kerning:-0.08691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c2}',
+                        kerning:-0.08691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c3}',
+                        kerning:-0.08691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c4}',
+                        kerning:-0.08691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c5}',
+                        kerning:-0.08691406f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{c6}',
+                        kerning:-0.14501953f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e0}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e1}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e2}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e3}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e4}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e5}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f2}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f3}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f4}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f5}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{f6}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{fd}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{50}',
+                        right:'\u{ff}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{54}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{56}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{57}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{59}',
+                        kerning:-0.017089844f32,
+                    },
+                    KerningPair {
+                        left:'\u{51}',
+                        right:'\u{dd}',
+                        kerning:-0.017089844f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{54}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{56}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{59}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{52}',
+                        right:'\u{dd}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{2c}',
+                        kerning:-0.12597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{2d}',
+                        kerning:-0.1328125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{2e}',
+                        kerning:-0.12597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{41}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{43}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{47}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{4a}',
+                        kerning:-0.10546875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{4f}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{51}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{53}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{54}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{56}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{57}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{59}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{61}',
+                        kerning:-0.08203125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{63}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{64}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{65}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{67}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{6d}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{6e}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{6f}',
+                        kerning:-0.1015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{70}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{71}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{72}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{73}',
+                        kerning:-0.037109375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{75}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{76}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{77}',
+                        kerning:-0.022949219f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{78}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{79}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{7a}',
+                        kerning:-0.029296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ab}',
+                        kerning:-0.072265625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ad}',
+                        kerning:-0.1328125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{bb}',
+                        kerning:-0.07128906f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c0}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c1}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c2}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c3}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c4}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c5}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c6}',
+                        kerning:-0.092285156f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{c7}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d2}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d3}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d4}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d5}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d6}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{d8}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{dd}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e0}',
+                        kerning:-0.08203125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e1}',
+                        kerning:-0.08203125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e2}',
+                        kerning:-0.08203125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e3}',
+                        kerning:-0.08203125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e4}',
+                        kerning:-0.08203125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e5}',
+                        kerning:-0.08203125f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e6}',
+                        kerning:-0.041015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e7}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e8}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{e9}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ea}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{eb}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f1}',
+                        kerning:-0.04345703f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f2}',
+                        kerning:-0.1015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f3}',
+                        kerning:-0.1015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f4}',
+                        kerning:-0.1015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f5}',
+                        kerning:-0.1015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f6}',
+                        kerning:-0.1015625f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f8}',
+                        kerning:-0.04638672f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{f9}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fa}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fb}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fc}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{fd}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{54}',
+                        right:'\u{ff}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{55}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{2c}',
+                        kerning:-0.10498047f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{2d}',
+                        kerning:-0.076660156f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{2e}',
+                        kerning:-0.10498047f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{41}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{43}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{47}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{4f}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{51}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{5d}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{61}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{63}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{64}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{65}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{67}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{6f}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{71}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{72}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{75}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{ad}',
+                        kerning:-0.076660156f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c0}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c1}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c2}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c3}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c4}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c5}',
+                        kerning:-0.036621094f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{c7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d2}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d3}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d4}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d5}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d6}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{d8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e0}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e1}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e2}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e3}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e4}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e5}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e7}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e8}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{e9}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{ea}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{eb}',
+                        kerning:-0.021484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f2}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f3}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f4}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f5}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f6}',
+                        kerning:-0.022460938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{f9}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fa}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fb}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fc}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{56}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{29}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{2c}',
+                        kerning:-0.06982422f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{2d}',
+                        kerning:-0.029296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{2e}',
+                        kerning:-0.06982422f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{41}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{54}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{5d}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{61}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{63}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{64}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{65}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{67}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{6f}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{71}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{72}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{75}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{7d}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{ad}',
+                        kerning:-0.029296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c0}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c1}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c2}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c3}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c4}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{c5}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e0}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e1}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e2}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e3}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e4}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e5}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e7}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e8}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{e9}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{ea}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{eb}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f2}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f3}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f4}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f5}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f6}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{f9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{fa}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{fb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{57}',
+                        right:'\u{fc}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{2d}',
+                        kerning:-0.076171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{43}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{47}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{4f}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{51}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{56}',
+                        kerning:0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{63}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{64}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{65}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{67}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{6f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{71}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{75}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{76}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{79}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{ad}',
+                        kerning:-0.076171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{c7}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d2}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d3}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d4}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d5}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d6}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{d8}',
+                        kerning:-0.012207031f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{e7}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{e8}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{e9}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{ea}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{eb}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{f9}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fa}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fb}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fc}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{fd}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{58}',
+                        right:'\u{ff}',
+                        kerning:-0.015136719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{26}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2a}',
+                        kerning:-0.023925781f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2c}',
+                        kerning:-0.11279297f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2d}',
+                        kerning:-0.07421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{2e}',
+                        kerning:-0.11279297f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{41}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{43}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{47}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{4a}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{4f}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{51}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{53}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{54}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{55}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{56}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{57}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{58}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{59}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{5d}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{61}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{63}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{64}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{65}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{66}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{67}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{6d}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{6e}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{6f}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{70}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{71}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{72}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{73}',
+                        kerning:-0.028320313f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{74}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{75}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{76}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{78}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{79}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{7a}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ab}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ad}',
+                        kerning:-0.07421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{bb}',
+                        kerning:-0.024902344f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c0}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c1}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c2}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c3}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c4}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c5}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c6}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{c7}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d2}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d3}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d4}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d5}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d6}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d8}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{d9}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{da}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{db}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{dc}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{dd}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e0}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e1}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e2}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e3}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e4}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e5}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e6}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e7}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e8}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{e9}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ea}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{eb}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f1}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f2}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f3}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f4}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f5}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f6}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f8}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{f9}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fa}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fb}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fc}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{fd}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{59}',
+                        right:'\u{ff}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{41}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{43}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{47}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{4f}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{51}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{63}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{64}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{65}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{67}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{6f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{71}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{75}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{76}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{77}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{79}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c0}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c1}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c2}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c3}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c4}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c5}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{c7}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d2}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d3}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d4}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d5}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d6}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{d8}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{e7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{e8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{e9}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{ea}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{eb}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{f9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fa}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fc}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{fd}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5a}',
+                        right:'\u{ff}',
+                        kerning:-0.013183594f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{4a}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{55}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{d9}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{da}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{db}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{5b}',
+                        right:'\u{dc}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{22}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{27}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{61}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{22}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{27}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{78}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{7a}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{62}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{63}',
+                        right:'\u{22}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{63}',
+                        right:'\u{27}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{65}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{22}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{27}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{5d}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{63}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{64}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{65}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{67}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{71}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{e7}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{e8}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{e9}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{ea}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{66}',
+                        right:'\u{eb}',
+                        kerning:-0.01171875f32,
+                    },
+                    KerningPair {
+                        left:'\u{68}',
+                        right:'\u{22}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{68}',
+                        right:'\u{27}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{63}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{64}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{65}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{67}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{71}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{e7}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{e8}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{e9}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{ea}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6b}',
+                        right:'\u{eb}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6d}',
+                        right:'\u{22}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6d}',
+                        right:'\u{27}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6e}',
+                        right:'\u{22}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6e}',
+                        right:'\u{27}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{22}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{27}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{6f}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{22}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{27}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{78}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{7a}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{70}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{22}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{27}',
+                        kerning:0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{2c}',
+                        kerning:-0.084472656f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{2e}',
+                        kerning:-0.084472656f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{61}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{66}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{6f}',
+                        kerning:-0.017578125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{74}',
+                        kerning:0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{76}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{77}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{79}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e0}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e1}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e2}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e3}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e4}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e5}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f2}',
+                        kerning:-0.017578125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f3}',
+                        kerning:-0.017578125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f4}',
+                        kerning:-0.017578125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f5}',
+                        kerning:-0.017578125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{f6}',
+                        kerning:-0.017578125f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{fd}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{72}',
+                        right:'\u{ff}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{6f}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f2}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f3}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f4}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f5}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{74}',
+                        right:'\u{f6}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{2c}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{2e}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{76}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{77}',
+                        right:'\u{2c}',
+                        kerning:-0.060546875f32,
+                    },
+                    KerningPair {
+                        left:'\u{77}',
+                        right:'\u{2e}',
+                        kerning:-0.060546875f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{63}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{64}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{65}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{67}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{6f}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{71}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{e7}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{e8}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{e9}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{ea}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{eb}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f2}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f3}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f4}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f5}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{78}',
+                        right:'\u{f6}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{2c}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{2e}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{79}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{63}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{64}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{65}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{67}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{6f}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{71}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{e7}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{e8}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{e9}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{ea}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{eb}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f2}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f3}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f4}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f5}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7a}',
+                        right:'\u{f6}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{4a}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{55}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{d9}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{da}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{db}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{7b}',
+                        right:'\u{dc}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{3f}',
+                        kerning:-0.03955078f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{43}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{47}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{4f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{51}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{54}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{56}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{57}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{59}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{6d}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{6e}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{70}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{c7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{dd}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f1}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c0}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{3f}',
+                        kerning:-0.03955078f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{43}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{47}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{4f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{51}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{54}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{56}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{57}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{59}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{6d}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{6e}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{70}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{c7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{dd}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f1}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c1}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{3f}',
+                        kerning:-0.03955078f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{43}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{47}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{4f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{51}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{54}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{56}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{57}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{59}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{6d}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{6e}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{70}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{c7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{dd}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f1}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c2}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{3f}',
+                        kerning:-0.03955078f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{43}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{47}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{4f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{51}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{54}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{56}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{57}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{59}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{6d}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{6e}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{70}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{c7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{dd}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f1}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c3}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{3f}',
+                        kerning:-0.03955078f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{43}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{47}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{4f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{51}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{54}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{56}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{57}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{59}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{6d}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{6e}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{70}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{c7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{dd}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f1}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c4}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{22}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{27}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{3f}',
+                        kerning:-0.03955078f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{43}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{47}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{4f}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{51}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{54}',
+                        kerning:-0.05859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{55}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{56}',
+                        kerning:-0.037597656f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{57}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{59}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{6d}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{6e}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{6f}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{70}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{74}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{75}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{76}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{77}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{79}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{7a}',
+                        kerning:0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{c7}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d6}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d8}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{d9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{da}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{db}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{dc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{dd}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f1}',
+                        kerning:-0.0234375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f2}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f3}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f4}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f5}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f6}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{f9}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fa}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fb}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fc}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{fd}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c5}',
+                        right:'\u{ff}',
+                        kerning:-0.024414063f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{29}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{5d}',
+                        kerning:-0.005859375f32,
+                    },
+                    KerningPair {
+                        left:'\u{c7}',
+                        right:'\u{7d}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c8}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{c9}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{ca}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{54}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{63}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{64}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{65}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{66}',
+                        kerning:-0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{67}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{6f}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{71}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{75}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{76}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{77}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{79}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{e7}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{e8}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{e9}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{ea}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{eb}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f2}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f3}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f4}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f5}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f6}',
+                        kerning:-0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{f9}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fa}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fb}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fc}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{fd}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cb}',
+                        right:'\u{ff}',
+                        kerning:-0.0126953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cc}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cd}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{ce}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{cf}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d0}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{41}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{54}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{58}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{59}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c0}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c1}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c2}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c3}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c4}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{c5}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{d1}',
+                        right:'\u{dd}',
+                        kerning:-0.013671875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d2}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d3}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d4}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d5}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{2c}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{2e}',
+                        kerning:-0.059570313f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{41}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{54}',
+                        kerning:-0.041503906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{56}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{58}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{59}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{5a}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c0}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c1}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c2}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c3}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c4}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c5}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{c6}',
+                        kerning:-0.016113281f32,
+                    },
+                    KerningPair {
+                        left:'\u{d6}',
+                        right:'\u{dd}',
+                        kerning:-0.020996094f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{d9}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{da}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{db}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{41}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c0}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c1}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c2}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c3}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c4}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dc}',
+                        right:'\u{c5}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{26}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{29}',
+                        kerning:0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2a}',
+                        kerning:-0.023925781f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2c}',
+                        kerning:-0.11279297f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2d}',
+                        kerning:-0.07421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{2e}',
+                        kerning:-0.11279297f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{41}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{43}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{47}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{4a}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{4f}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{51}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{53}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{54}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{55}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{56}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{57}',
+                        kerning:0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{58}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{59}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{5d}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{61}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{63}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{64}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{65}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{66}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{67}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{6d}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{6e}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{6f}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{70}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{71}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{72}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{73}',
+                        kerning:-0.028320313f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{74}',
+                        kerning:-0.0107421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{75}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{76}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{78}',
+                        kerning:-0.011230469f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{79}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{7a}',
+                        kerning:-0.0146484375f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{7d}',
+                        kerning:0.009277344f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ab}',
+                        kerning:-0.040039063f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ad}',
+                        kerning:-0.07421875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{bb}',
+                        kerning:-0.024902344f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c0}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c1}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c2}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c3}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c4}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c5}',
+                        kerning:-0.07324219f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c6}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{c7}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d2}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d3}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d4}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d5}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d6}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d8}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{d9}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{da}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{db}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{dc}',
+                        kerning:-0.046875f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{dd}',
+                        kerning:0.0087890625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e0}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e1}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e2}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e3}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e4}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e5}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e6}',
+                        kerning:-0.030761719f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e7}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e8}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{e9}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ea}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{eb}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f1}',
+                        kerning:-0.01953125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f2}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f3}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f4}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f5}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f6}',
+                        kerning:-0.03173828f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f8}',
+                        kerning:-0.03125f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{f9}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fa}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fb}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fc}',
+                        kerning:-0.019042969f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{fd}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{dd}',
+                        right:'\u{ff}',
+                        kerning:-0.009765625f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{22}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{27}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e0}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{22}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{27}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e1}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{22}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{27}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e2}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{22}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{27}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e3}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{22}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{27}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e4}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{22}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{27}',
+                        kerning:-0.008300781f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e5}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{e7}',
+                        right:'\u{22}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{e7}',
+                        right:'\u{27}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e8}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{e9}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ea}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{22}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{27}',
+                        kerning:-0.0068359375f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{76}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{79}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{fd}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{eb}',
+                        right:'\u{ff}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{f1}',
+                        right:'\u{22}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f1}',
+                        right:'\u{27}',
+                        kerning:-0.0390625f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{22}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{27}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f2}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{22}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{27}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f3}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{22}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{27}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f4}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{22}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{27}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f5}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{22}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{27}',
+                        kerning:-0.04296875f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{76}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{78}',
+                        kerning:-0.010253906f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{79}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{7a}',
+                        kerning:-0.0078125f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{fd}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{f6}',
+                        right:'\u{ff}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{2c}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{2e}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fd}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{22}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{27}',
+                        kerning:-0.014160156f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{76}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{78}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{79}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{7a}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{fd}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{fe}',
+                        right:'\u{ff}',
+                        kerning:-0.0053710938f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{22}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{27}',
+                        kerning:0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{2c}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{2e}',
+                        kerning:-0.08154297f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{61}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{63}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{64}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{65}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{66}',
+                        kerning:0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{67}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{6f}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{71}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e0}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e1}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e7}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e8}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{e9}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{ea}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{eb}',
+                        kerning:-0.0063476563f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f2}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f3}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f4}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f5}',
+                        kerning:-0.0073242188f32,
+                    },
+                    KerningPair {
+                        left:'\u{ff}',
+                        right:'\u{f6}',
+                        kerning:-0.0073242188f32,
+                    },];pub static GLYPHS: &[Glyph] = &[
                     Glyph {
                         ch:'\u{21}',
                         advance:0.27197266f32,
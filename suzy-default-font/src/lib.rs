@@ -11,6 +11,12 @@ pub struct Glyph {
     pub tex_top: u16,
 }
 
+pub struct KerningPair {
+    pub left: char,
+    pub right: char,
+    pub kerning: f32,
+}
+
 include! {"default_font.rs"}
 
 pub static TEXTURE_DATA: &[u8] = include_bytes!("default_font.rs.texture");
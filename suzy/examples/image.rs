@@ -5,16 +5,11 @@ use std::rc::Rc;
 
 use suzy::{
     dims::{Padding2d, Rect},
-    platforms::opengl::{
-        self, PopulateTexture, PopulateTextureUtil, SlicedImage, Texture,
-    },
+    platforms::opengl::{ImagePopulator, SlicedImage, Texture},
     widget::{self, RunAsApp},
 };
 
-const IMAGE: &[u8] = include_bytes!("cute.data");
-const IMAGE_WIDTH: u16 = 384;
-const IMAGE_HEIGHT: u16 = 512;
-const IMAGE_ASPECT: f32 = 384.0 / 512.0;
+const IMAGE: &[u8] = include_bytes!("cute.png");
 
 fn main() {
     ImageViewer::run_as_app();
@@ -24,43 +19,25 @@ fn main() {
 #[derive(Default)]
 struct ImageViewer {
     image: SlicedImage,
+    aspect: f32,
 }
 
 impl widget::Content for ImageViewer {
     fn desc(mut desc: impl widget::Desc<Self>) {
         desc.watch(|this, _rect| {
-            this.image.texture = Texture::new(Rc::new(Populator));
+            let populator = ImagePopulator::from_bytes(IMAGE)
+                .expect("example image should be a valid PNG");
+            let [width, height] = populator.size();
+            this.aspect = f32::from(width) / f32::from(height);
+            this.image.texture = Texture::new(Rc::new(populator));
             this.image.padding = Padding2d::zero();
         });
         desc.watch(|this, rect| {
             // fill the screen with the image
             this.image.set_fill(rect, &Padding2d::zero());
             // but shrink it so it stays the same aspect ratio
-            this.image.shrink_to_aspect(IMAGE_ASPECT);
+            this.image.shrink_to_aspect(this.aspect);
         });
         desc.graphic(|this| &mut this.image);
     }
 }
-
-struct Populator;
-
-impl PopulateTexture for Populator {
-    fn populate(
-        &self,
-        gl: &opengl::OpenGlBindings,
-        target: opengl::opengl_bindings::types::GLenum,
-    ) -> Result<opengl::TextureSize, String> {
-        Ok(PopulateTextureUtil::populate_color_rgb(
-            gl,
-            target,
-            IMAGE_WIDTH,
-            IMAGE_HEIGHT,
-            1,
-            IMAGE,
-        ))
-    }
-
-    fn texture_key(&self) -> &[u8] {
-        "cute".as_bytes()
-    }
-}
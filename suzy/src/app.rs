@@ -10,9 +10,12 @@ use std::{cell::RefCell, rc::Rc, time};
 
 use crate::{
     dims::{Padding2d, Rect, SimpleRect},
+    keyboard::{
+        Key, KeyAction, KeyEvent, KeyEventData, TextInputData, TextInputEvent,
+    },
     platform::RenderPlatform,
-    pointer::{PointerEvent, PointerEventData},
-    widget::{self, Widget},
+    pointer::{PointerAction, PointerEvent, PointerEventData},
+    widget::{self, AnonWidget, UniqueHandleId, Widget},
 };
 
 mod tester;
@@ -173,12 +176,139 @@ impl<P> App<P> {
 
     pub fn pointer_event(&mut self, pointer: PointerEventData) -> bool {
         let mut event = PointerEvent::new(pointer, &mut self.pointer_grab_map);
-        let mut handled = false;
-        let mut iter = self.roots.iter_mut().rev();
-        while let (false, Some(root)) = (handled, iter.next()) {
-            handled = root.borrow_mut().pointer_event(&mut event);
+        let roots = &self.roots;
+        let (_state, handled) =
+            AppState::use_as_current(Rc::clone(&self.state), || {
+                let mut handled = false;
+                let mut iter = roots.iter().rev();
+                while let (false, Some(root)) = (handled, iter.next()) {
+                    handled = root.borrow_mut().pointer_event(&mut event);
+                }
+                handled
+            });
+        if let PointerAction::Down = pointer.action {
+            self.state.set_focus(event.take_focus());
         }
         self.needs_draw = true;
         handled
     }
+
+    /// Set the widget which will receive key events.
+    ///
+    /// See [`Content::focus_handle`](widget::Content::focus_handle).
+    pub fn set_focus(&mut self, focus: Option<UniqueHandleId>) {
+        self.state.set_focus(focus);
+    }
+
+    /// Deliver a key event to the focused widget.
+    ///
+    /// If no widget has focus, or the focused widget is no longer part of
+    /// the app, the event is delivered to the root widgets, in reverse order
+    /// of when they were added to the app.
+    ///
+    /// If the event is not handled, the tab key will move focus to the next
+    /// focusable widget, or the previous one if shift is held.
+    pub fn key_event(&mut self, key: KeyEventData) -> bool {
+        let focus = self.state.focus_unwatched();
+        let mut event = KeyEvent::new(key, focus.as_ref());
+        let handled = self.focused_event(
+            focus.is_some(),
+            &mut event,
+            |root, event| root.key_event(event),
+            |root, event| root.key_event_self(event),
+        );
+        let modifiers = key.modifiers;
+        let is_tab = key.key == Key::Tab
+            && !(modifiers.ctrl || modifiers.alt || modifiers.meta);
+        if !handled && is_tab && key.action == KeyAction::Down {
+            self.move_focus(!modifiers.shift);
+        }
+        self.needs_draw = true;
+        handled || is_tab
+    }
+
+    /// Deliver text input to the focused widget.
+    ///
+    /// This follows the same rules as [`App::key_event`].
+    pub fn text_input(&mut self, text: TextInputData) -> bool {
+        let focus = self.state.focus_unwatched();
+        let mut event = TextInputEvent::new(text, focus.as_ref());
+        let handled = self.focused_event(
+            focus.is_some(),
+            &mut event,
+            |root, event| root.text_input(event),
+            |root, event| root.text_input_self(event),
+        );
+        self.needs_draw = true;
+        handled
+    }
+
+    /// Get the text input area of the focused widget.
+    ///
+    /// Returns `None` if no widget has focus, or the focused widget does
+    /// not accept text input.  See
+    /// [`Content::text_input_area`](widget::Content::text_input_area).
+    pub fn text_input_area(&mut self) -> Option<SimpleRect> {
+        let focus = self.state.focus_unwatched()?;
+        self.roots
+            .iter()
+            .rev()
+            .find_map(|root| root.borrow_mut().text_input_area(&focus))
+    }
+
+    fn focused_event<E, F, G>(
+        &self,
+        has_focus: bool,
+        event: &mut E,
+        to_focus: F,
+        to_root: G,
+    ) -> bool
+    where
+        F: Fn(&mut dyn AnonWidget<P>, &mut E) -> Option<bool>,
+        G: Fn(&mut dyn AnonWidget<P>, &mut E) -> bool,
+    {
+        let roots = &self.roots;
+        let (_state, handled) =
+            AppState::use_as_current(Rc::clone(&self.state), || {
+                let mut found = None;
+                if has_focus {
+                    let mut iter = roots.iter().rev();
+                    while let (None, Some(root)) = (found, iter.next()) {
+                        found = to_focus(&mut *root.borrow_mut(), event);
+                    }
+                }
+                found.unwrap_or_else(|| {
+                    let mut handled = false;
+                    let mut iter = roots.iter().rev();
+                    while let (false, Some(root)) = (handled, iter.next()) {
+                        handled = to_root(&mut *root.borrow_mut(), event);
+                    }
+                    handled
+                })
+            });
+        handled
+    }
+
+    /// Move focus to the next focusable widget, in tree order, or the
+    /// previous one if `forward` is false.
+    pub fn move_focus(&mut self, forward: bool) {
+        let mut list = Vec::new();
+        for root in &self.roots {
+            root.borrow_mut().collect_focus(&mut list);
+        }
+        let current = self
+            .state
+            .focus_unwatched()
+            .and_then(|focus| list.iter().position(|id| *id == focus));
+        let len = list.len();
+        let next = match (current, forward) {
+            (_, _) if len == 0 => None,
+            (None, true) => Some(0),
+            (None, false) => Some(len - 1),
+            (Some(index), true) => Some((index + 1) % len),
+            (Some(index), false) => Some((index + len - 1) % len),
+        };
+        self.state
+            .set_focus(next.map(|index| list.swap_remove(index)));
+    }
 }
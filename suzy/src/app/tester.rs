@@ -5,7 +5,11 @@ use std::time;
 
 use drying_paint::WatchedValueCore;
 
-use crate::{platform::RenderPlatform, pointer::PointerEventData};
+use crate::{
+    keyboard::{Key, KeyAction, KeyEventData, Modifiers, ScanCode},
+    platform::RenderPlatform,
+    pointer::PointerEventData,
+};
 
 use super::App;
 
@@ -18,6 +22,11 @@ pub trait TestingExt {
     /// 3) sending a mouse-up pointer event
     fn mouse_click(&mut self, pos: [f32; 2]);
 
+    /// Short-hand to simulate pressing and releasing a key
+    ///
+    /// The key event is sent with no modifiers and an unknown scancode.
+    fn key_press(&mut self, key: Key);
+
     /// Update and draw the current frame, then start a new one, acting as
     /// though `frame_time` has passed (e.g. for the purposes of [`crate::app::time()`]).
     fn next_frame(&mut self, frame_time: time::Duration);
@@ -52,4 +61,16 @@ impl<P: RenderPlatform> TestingExt for App<P> {
             y: py,
         });
     }
+
+    fn key_press(&mut self, key: Key) {
+        for action in [KeyAction::Down, KeyAction::Up] {
+            self.key_event(KeyEventData {
+                key,
+                scancode: ScanCode(0),
+                modifiers: Modifiers::NONE,
+                action,
+                repeat: false,
+            });
+        }
+    }
 }
@@ -1,9 +1,16 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
-use std::{cell::Cell, rc::Rc, time};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time,
+};
 
-use crate::watch::{DefaultOwner, WatchedValue};
+use crate::{
+    watch::{DefaultOwner, WatchArg, WatchedMeta, WatchedValue},
+    widget::UniqueHandleId,
+};
 
 type WatchedCellCore<T> =
     crate::watch::WatchedCellCore<'static, T, DefaultOwner>;
@@ -18,6 +25,8 @@ pub struct AppState {
     pub(super) window_width: WatchedCellCore<f32>,
     pub(super) window_height: WatchedCellCore<f32>,
     pub(super) dpi: WatchedCellCore<[f32; 2]>,
+    focus: RefCell<Option<UniqueHandleId>>,
+    focus_changed: WatchedMeta<'static, DefaultOwner>,
 }
 
 impl AppState {
@@ -45,6 +54,41 @@ impl AppState {
         &self.dpi
     }
 
+    /// Get the handle of the widget which currently has focus.
+    ///
+    /// This will bind watch closures it is called in.
+    pub fn focus(&self) -> Option<UniqueHandleId> {
+        self.focus_changed.watched_auto();
+        self.focus.borrow().clone()
+    }
+
+    /// Check if the identified handle currently has focus.
+    ///
+    /// This will bind watch closures it is called in.
+    pub fn is_focused(&self, id: &UniqueHandleId) -> bool {
+        self.focus_changed.watched_auto();
+        self.focus.borrow().as_ref() == Some(id)
+    }
+
+    pub(crate) fn focus_unwatched(&self) -> Option<UniqueHandleId> {
+        self.focus.borrow().clone()
+    }
+
+    pub(crate) fn set_focus(&self, focus: Option<UniqueHandleId>) {
+        if *self.focus.borrow() == focus {
+            return;
+        }
+        *self.focus.borrow_mut() = focus;
+        let mut triggered = false;
+        WatchArg::try_with_current(|arg| {
+            self.focus_changed.trigger(arg);
+            triggered = true;
+        });
+        if !triggered {
+            self.focus_changed.trigger_external();
+        }
+    }
+
     pub(crate) fn new_now(width: f32, height: f32) -> Self {
         let now = time::Instant::now();
         Self {
@@ -53,6 +97,8 @@ impl AppState {
             window_width: WatchedCellCore::new(width),
             window_height: WatchedCellCore::new(height),
             dpi: WatchedCellCore::new([96.0, 96.0]),
+            focus: RefCell::new(None),
+            focus_changed: WatchedMeta::new(),
         }
     }
 
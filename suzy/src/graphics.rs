@@ -83,3 +83,64 @@ pub enum CornerStyle {
     NotRounded,
     Rounded,
 }
+
+/// The radii of the corners of a rounded rectangle.
+///
+/// Radii larger than half the width or height of the rectangle are reduced
+/// to fit, so [`CornerRadii::uniform(f32::INFINITY)`](Self::uniform) gives a
+/// pill shape.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CornerRadii {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+}
+
+impl CornerRadii {
+    /// Create corner radii, in the same clockwise order as CSS
+    /// `border-radius`.
+    #[must_use]
+    pub fn new(
+        top_left: f32,
+        top_right: f32,
+        bottom_right: f32,
+        bottom_left: f32,
+    ) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        }
+    }
+
+    /// Create corner radii which are the same for every corner.
+    #[must_use]
+    pub fn uniform(radius: f32) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// Create corner radii for square corners.
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::uniform(0.0)
+    }
+}
+
+/// The outline of a vector shape graphic.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShapeOutline {
+    /// A rectangle with rounded corners.
+    Rect(CornerRadii),
+
+    /// An ellipse which fits the shape's rect, or a circle if the rect is
+    /// square.
+    Ellipse,
+}
+
+impl Default for ShapeOutline {
+    fn default() -> Self {
+        Self::Rect(CornerRadii::zero())
+    }
+}
@@ -0,0 +1,345 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Keyboard events are delivered to the widget which currently has focus.
+//!
+//! If the focused widget does not handle a key event, the event "bubbles up"
+//! to each of its ancestors in turn, until one of them handles it.  If no
+//! widget has focus, key events are delivered to the root widgets.
+//!
+//! Text input, including text composed with an input method (IME), is
+//! delivered the same way as a separate [`TextInputEvent`].  Widgets which
+//! edit text should use these events rather than interpreting
+//! [`Key::Character`] key events, and indicate they accept text by
+//! overriding [`crate::widget::Content::text_input_area`].
+
+use std::ops::Range;
+
+use crate::{
+    dims::SimpleRect,
+    widget::{Content, UniqueHandleId, WidgetRect},
+};
+
+/// A virtual key, as interpreted by the current keyboard layout.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+#[non_exhaustive]
+pub enum Key {
+    /// A key which produces a printable character.
+    ///
+    /// Letters are reported in lowercase, regardless of modifiers.
+    Character(char),
+
+    /// The space bar.
+    Space,
+
+    /// The enter (or return) key.
+    Enter,
+
+    /// The tab key.
+    Tab,
+
+    /// The backspace key.
+    Backspace,
+
+    /// The delete key.
+    Delete,
+
+    /// The insert key.
+    Insert,
+
+    /// The escape key.
+    Escape,
+
+    /// The left arrow key.
+    Left,
+
+    /// The right arrow key.
+    Right,
+
+    /// The up arrow key.
+    Up,
+
+    /// The down arrow key.
+    Down,
+
+    /// The home key.
+    Home,
+
+    /// The end key.
+    End,
+
+    /// The page up key.
+    PageUp,
+
+    /// The page down key.
+    PageDown,
+
+    /// A function key, e.g. `F(1)` for F1.
+    F(u8),
+
+    /// Either shift key.
+    Shift,
+
+    /// Either control key.
+    Control,
+
+    /// Either alt key.
+    Alt,
+
+    /// Either "meta" key (e.g. the Windows key, or the Command key).
+    Meta,
+
+    /// The caps lock key.
+    CapsLock,
+
+    /// The context menu key.
+    Menu,
+
+    /// A key which does not correspond to any of the other variants.
+    Unknown,
+}
+
+/// An identifier for a physical key location, independent of the keyboard
+/// layout.
+///
+/// The values follow the USB HID keyboard usage table.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct ScanCode(pub u32);
+
+/// The modifier keys held during a key event.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct Modifiers {
+    /// Either shift key is held.
+    pub shift: bool,
+
+    /// Either control key is held.
+    pub ctrl: bool,
+
+    /// Either alt key is held.
+    pub alt: bool,
+
+    /// Either "meta" key is held.
+    pub meta: bool,
+}
+
+impl Modifiers {
+    /// No modifier keys are held.
+    pub const NONE: Self = Self {
+        shift: false,
+        ctrl: false,
+        alt: false,
+        meta: false,
+    };
+
+    /// Returns true if no modifier keys are held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+/// An enum describing the activity that generated a particular key event
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum KeyAction {
+    /// The key was pressed.
+    Down,
+
+    /// The key was released.
+    Up,
+}
+
+mod internal {
+    /// The data associated with a particular key event.
+    #[derive(Copy, Clone, Debug)]
+    pub struct KeyEvent {
+        /// The virtual key involved in this event
+        pub key: super::Key,
+        /// The physical key involved in this event
+        pub scancode: super::ScanCode,
+        /// The modifier keys held during this event
+        pub modifiers: super::Modifiers,
+        /// The activity that caused this event
+        pub action: super::KeyAction,
+        /// True if this event was generated by the key being held down
+        pub repeat: bool,
+    }
+}
+
+pub use internal::KeyEvent as KeyEventData;
+
+/// This struct will get passed to [`crate::widget::Content::key_event`] method.
+pub struct KeyEvent<'a> {
+    data: KeyEventData,
+    focus: Option<&'a UniqueHandleId>,
+}
+
+impl std::fmt::Debug for KeyEvent<'_> {
+    fn fmt(&self, fmtter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.data, fmtter)
+    }
+}
+
+impl<'a> KeyEvent<'a> {
+    pub(crate) fn new(
+        data: KeyEventData,
+        focus: Option<&'a UniqueHandleId>,
+    ) -> Self {
+        KeyEvent { data, focus }
+    }
+}
+
+impl KeyEvent<'_> {
+    /// Get the virtual key involved in this event
+    #[must_use]
+    pub fn key(&self) -> Key {
+        self.data.key
+    }
+
+    /// Get the physical key involved in this event
+    #[must_use]
+    pub fn scancode(&self) -> ScanCode {
+        self.data.scancode
+    }
+
+    /// Get the modifier keys held during this event
+    #[must_use]
+    pub fn modifiers(&self) -> Modifiers {
+        self.data.modifiers
+    }
+
+    /// Get the activity which triggered this event
+    #[must_use]
+    pub fn action(&self) -> KeyAction {
+        self.data.action
+    }
+
+    /// Check if this event was generated by the key being held down
+    #[must_use]
+    pub fn is_repeat(&self) -> bool {
+        self.data.repeat
+    }
+
+    /// Check if the identified handle is the focus target of this event.
+    ///
+    /// This can be used to distinguish between an event delivered directly
+    /// to a widget, and one which has bubbled up from a descendant.
+    pub fn is_focused<I>(&self, holder: I) -> bool
+    where
+        I: Into<UniqueHandleId>,
+    {
+        self.focus.is_some_and(|focus| focus == &holder.into())
+    }
+}
+
+/// The data associated with a particular text input event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TextInputData {
+    /// Text was committed, and should be inserted at the cursor.
+    Input(String),
+
+    /// The user is composing text with an input method.
+    ///
+    /// The text is provisional: it is replaced by the next editing event,
+    /// and removed when the composition is committed with an `Input` event.
+    /// An empty string indicates the composition was cancelled.
+    Editing {
+        /// The text being composed.
+        text: String,
+        /// The byte range of the composition cursor within `text`.
+        cursor: Range<usize>,
+    },
+}
+
+/// This struct will get passed to [`crate::widget::Content::text_input`]
+/// method.
+pub struct TextInputEvent<'a> {
+    data: TextInputData,
+    focus: Option<&'a UniqueHandleId>,
+}
+
+impl std::fmt::Debug for TextInputEvent<'_> {
+    fn fmt(&self, fmtter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.data, fmtter)
+    }
+}
+
+impl<'a> TextInputEvent<'a> {
+    pub(crate) fn new(
+        data: TextInputData,
+        focus: Option<&'a UniqueHandleId>,
+    ) -> Self {
+        TextInputEvent { data, focus }
+    }
+}
+
+impl TextInputEvent<'_> {
+    /// Get the data associated with this event
+    #[must_use]
+    pub fn data(&self) -> &TextInputData {
+        &self.data
+    }
+
+    /// Check if the identified handle is the focus target of this event.
+    pub fn is_focused<I>(&self, holder: I) -> bool
+    where
+        I: Into<UniqueHandleId>,
+    {
+        self.focus.is_some_and(|focus| focus == &holder.into())
+    }
+}
+
+/// An event which is delivered to the focused widget, and then to each of
+/// its ancestors until it is handled.
+pub(crate) trait FocusedEvent {
+    fn is_target(&self, id: UniqueHandleId) -> bool;
+
+    fn deliver<T, P>(&mut self, content: &mut T, rect: &WidgetRect) -> bool
+    where
+        T: ?Sized + Content<P>;
+}
+
+impl FocusedEvent for KeyEvent<'_> {
+    fn is_target(&self, id: UniqueHandleId) -> bool {
+        self.is_focused(id)
+    }
+
+    fn deliver<T, P>(&mut self, content: &mut T, rect: &WidgetRect) -> bool
+    where
+        T: ?Sized + Content<P>,
+    {
+        T::key_event(content, rect, self)
+    }
+}
+
+impl FocusedEvent for TextInputEvent<'_> {
+    fn is_target(&self, id: UniqueHandleId) -> bool {
+        self.is_focused(id)
+    }
+
+    fn deliver<T, P>(&mut self, content: &mut T, rect: &WidgetRect) -> bool
+    where
+        T: ?Sized + Content<P>,
+    {
+        T::text_input(content, rect, self)
+    }
+}
+
+/// Finds the text input area of the focused widget.
+pub(crate) struct TextInputAreaQuery<'a> {
+    pub focus: &'a UniqueHandleId,
+    pub area: Option<SimpleRect>,
+}
+
+impl FocusedEvent for TextInputAreaQuery<'_> {
+    fn is_target(&self, id: UniqueHandleId) -> bool {
+        *self.focus == id
+    }
+
+    fn deliver<T, P>(&mut self, content: &mut T, rect: &WidgetRect) -> bool
+    where
+        T: ?Sized + Content<P>,
+    {
+        self.area = T::text_input_area(content, rect);
+        true
+    }
+}
@@ -79,6 +79,7 @@ pub mod animation;
 pub mod app;
 pub mod dims;
 pub mod graphics;
+pub mod keyboard;
 pub mod platform;
 #[macro_use]
 pub mod platforms;
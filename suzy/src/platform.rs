@@ -24,6 +24,12 @@ pub trait RenderPlatform: 'static {
         + Default
         + crate::graphics::Graphic<Self>;
 
+    /// The platform's graphic primitive for vector shapes.
+    type Shape: graphics::Shape
+        + Rect
+        + Default
+        + crate::graphics::Graphic<Self>;
+
     type TextStyle: graphics::TextStyle;
 
     /// The platform's graphic primitive for text
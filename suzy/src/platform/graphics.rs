@@ -4,9 +4,11 @@
 //! This describes traits which apply to a set of graphic primitives a
 //! platform must implement to support Suzy's built-in widgets.
 
+use std::ops::Range;
+
 use crate::{
-    dims::Padding2d,
-    graphics::{Color, CornerStyle},
+    dims::{Padding2d, SimpleRect},
+    graphics::{Color, CornerStyle, ShapeOutline},
     text,
 };
 
@@ -17,8 +19,29 @@ pub trait SlicedImage {
     fn set_corners(&mut self, style: CornerStyle);
 }
 
-pub trait TextStyle: Sized {
+/// A platform's vector shape graphic primitive.
+pub trait Shape {
+    fn set_outline(&mut self, outline: ShapeOutline);
+
+    /// Set the color inside the outline.  A transparent fill gives a
+    /// stroke-only border.
+    fn set_fill_color(&mut self, color: Color);
+
+    /// Set the color and width of the stroke drawn along the inside of the
+    /// outline.  A width of zero disables the stroke.
+    fn set_stroke(&mut self, color: Color, width: f32);
+}
+
+/// A platform's style for a span of text.
+pub trait TextStyle: Sized + Clone {
     fn with_size_and_color(size: f32, color: Color) -> Self;
+
+    /// Derive the style for text inside a rich text tag.
+    ///
+    /// `tag` is the contents of the tag, without the angle brackets, for
+    /// example `color=#ff0000`.  On failure, `tag` should be left pointing
+    /// at the part of the tag which could not be understood; the position
+    /// of the returned error is filled in by [`text::parse_rich`].
     fn push_tag(
         &self,
         tag: &mut &str,
@@ -31,4 +54,54 @@ pub trait Text<Style> {
     fn clear(&mut self);
     fn push_span(&mut self, style: Style, text: &str);
     fn finish(&mut self);
+
+    // The following methods query the layout computed by `finish`.  Byte
+    // indices refer to the concatenation of all the spans pushed since the
+    // last call to `clear`.
+
+    /// Find the character nearest a point.
+    ///
+    /// Returns `None` if there is no laid-out text.
+    fn hit_test(&self, point: [f32; 2]) -> Option<text::TextHit>;
+
+    /// Get the caret position before the character at the byte index
+    /// `index`.
+    ///
+    /// The returned rect has zero width, and spans the font's ascent and
+    /// descent for the line the caret is on.  An index equal to the total
+    /// length of the text gives the position after the final character.
+    ///
+    /// Returns `None` if `finish` has not been called, or `index` does not
+    /// lie on a character boundary, or lies in text hidden by truncation.
+    fn caret_rect(&self, index: usize) -> Option<SimpleRect>;
+
+    /// Get the rectangles covering a range of text, one per line.
+    fn selection_rects(&self, range: Range<usize>) -> Vec<SimpleRect>;
+
+    /// Get the metrics of each line of text, from top to bottom.
+    fn line_metrics(&self) -> &[text::LineMetrics];
+
+    /// Check if some of the text was hidden because it didn't fit within
+    /// the layout's vertical limit.
+    ///
+    /// Hidden text has no caret positions, except for the start of the
+    /// hidden text when it is replaced by an ellipsis.
+    fn is_truncated(&self) -> bool;
+
+    /// Get the bounds of all the laid-out lines.
+    ///
+    /// Returns `None` if there is no laid-out text.
+    fn bounds(&self) -> Option<SimpleRect>;
+
+    /// Measure the size of some text without laying it out for drawing.
+    ///
+    /// The spans are measured as if they were pushed in order between calls
+    /// to `clear` and `finish`, using `layout` in place of the layout set
+    /// with `set_layout`.  The text already laid out in `self` is not
+    /// affected.
+    ///
+    /// Returns the `[width, height]` of the bounds the text would have.
+    fn measure<'a, I>(&self, layout: text::Layout, spans: I) -> [f32; 2]
+    where
+        I: IntoIterator<Item = (Style, &'a str)>;
 }
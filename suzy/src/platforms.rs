@@ -8,6 +8,12 @@ pub mod no_graphics;
 #[cfg(feature = "platform-opengl")]
 pub mod opengl;
 
+#[cfg(feature = "platform-software")]
+pub mod software;
+
+#[cfg(feature = "platform-recording")]
+pub mod recording;
+
 #[cfg(feature = "platform-sdl")]
 pub mod sdl2;
 
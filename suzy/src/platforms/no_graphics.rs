@@ -10,6 +10,7 @@ use crate::{
 #[derive(Clone, Copy, Debug, Default)]
 pub struct NoGraphics;
 
+#[derive(Clone)]
 pub enum TextStyle {}
 pub enum Graphic {}
 
@@ -18,6 +19,8 @@ impl RenderPlatform for NoGraphics {
 
     type SlicedImage = Graphic;
 
+    type Shape = Graphic;
+
     type TextStyle = TextStyle;
 
     type Text = Graphic;
@@ -123,6 +126,20 @@ impl graphics::SlicedImage for Graphic {
     }
 }
 
+impl graphics::Shape for Graphic {
+    fn set_outline(&mut self, _outline: crate::graphics::ShapeOutline) {
+        unreachable!()
+    }
+
+    fn set_fill_color(&mut self, _color: Color) {
+        unreachable!()
+    }
+
+    fn set_stroke(&mut self, _color: Color, _width: f32) {
+        unreachable!()
+    }
+}
+
 impl graphics::Text<TextStyle> for Graphic {
     fn set_layout(&mut self, _layout: crate::text::Layout) {
         unreachable!()
@@ -139,4 +156,42 @@ impl graphics::Text<TextStyle> for Graphic {
     fn finish(&mut self) {
         unreachable!()
     }
+
+    fn hit_test(&self, _point: [f32; 2]) -> Option<crate::text::TextHit> {
+        unreachable!()
+    }
+
+    fn caret_rect(&self, _index: usize) -> Option<crate::dims::SimpleRect> {
+        unreachable!()
+    }
+
+    fn selection_rects(
+        &self,
+        _range: std::ops::Range<usize>,
+    ) -> Vec<crate::dims::SimpleRect> {
+        unreachable!()
+    }
+
+    fn line_metrics(&self) -> &[crate::text::LineMetrics] {
+        unreachable!()
+    }
+
+    fn is_truncated(&self) -> bool {
+        unreachable!()
+    }
+
+    fn bounds(&self) -> Option<crate::dims::SimpleRect> {
+        unreachable!()
+    }
+
+    fn measure<'a, I>(
+        &self,
+        _layout: crate::text::Layout,
+        _spans: I,
+    ) -> [f32; 2]
+    where
+        I: IntoIterator<Item = (TextStyle, &'a str)>,
+    {
+        unreachable!()
+    }
 }
@@ -23,9 +23,18 @@ pub use context::bindings as opengl_bindings;
 pub use context::{OpenGlBindings, OpenGlContext, DEBUG};
 pub use graphics::*;
 pub use matrix::Mat4;
-pub use text::{Font, Text};
+pub use text::{
+    AtlasKind, Font, FontLoadError, FontRegistry, FontWeight, Text, TextStyle,
+};
+#[cfg(any(
+    feature = "image-png",
+    feature = "image-jpeg",
+    feature = "image-qoi"
+))]
+pub use texture::{ImageFormat, ImageLoadError, ImagePopulator};
 pub use texture::{
-    PopulateTexture, PopulateTextureUtil, Texture, TextureId, TextureSize,
+    PopulateTexture, PopulateTextureUtil, Texture, TextureId, TexturePixels,
+    TextureSize,
 };
 pub use window::Window;
 
@@ -37,6 +46,7 @@ impl crate::platform::RenderPlatform for OpenGlRenderPlatform {
     type DrawContextBuilder = fn(&mut ()) -> DrawContext<'_>;
 
     type SlicedImage = SlicedImage;
+    type Shape = Shape;
     type TextStyle = text::TextStyle;
     type Text = Text;
 }
@@ -5,7 +5,10 @@
 
 use std::rc::Rc;
 
-use super::{mask::Mask, stdshaders::Shaders, texture::TextureCache};
+use super::{
+    mask::MaskStack, stdshaders::Shaders, text::FontRegistry,
+    texture::TextureCache,
+};
 
 pub const DEBUG: bool = option_env!("SUZY_GL_DEBUG").is_some();
 
@@ -43,8 +46,9 @@ pub struct OpenGlContext {
     pub(super) bindings: Rc<OpenGlBindings>,
     pub(super) shaders: Shaders,
     pub(super) texture_cache: TextureCache,
-    pub(super) mask: Mask,
+    pub(super) masks: MaskStack,
     pub(super) buffers: Vec<bindings::types::GLuint>,
+    fonts: Rc<FontRegistry>,
 }
 
 impl OpenGlContext {
@@ -63,16 +67,25 @@ impl OpenGlContext {
             }
         }
         let shaders = Shaders::new(&ptr).expect("Failed to compile shaders");
-        let mask = Mask::new(&ptr);
+        let fonts = Rc::default();
+        FontRegistry::make_current(&fonts);
         Self {
             bindings: ptr,
             shaders,
             texture_cache: TextureCache::default(),
-            mask,
+            masks: MaskStack::default(),
             buffers: Vec::new(),
+            fonts,
         }
     }
 
+    /// Get the fonts registered with this context, which text styles select
+    /// by family.
+    #[must_use]
+    pub fn fonts(&self) -> &FontRegistry {
+        &self.fonts
+    }
+
     pub fn run_texture_populators(&mut self) {
         self.texture_cache.run_populators(&self.bindings);
     }
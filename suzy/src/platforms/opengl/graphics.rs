@@ -1,14 +1,21 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
+mod clip;
 mod image;
 mod mask;
+mod shape;
 mod transform;
 
-pub use {image::SlicedImage, mask::Mask, transform::Transform};
+pub use {
+    clip::ClipRect, image::SlicedImage, mask::Mask, shape::Shape,
+    transform::Transform,
+};
+
+use crate::dims::{Rect, SimpleRect};
 
 use super::{
-    renderer::{BatchMasking, BatchPool},
+    renderer::{BatchMasking, BatchPool, Scissor},
     Mat4,
 };
 
@@ -16,6 +23,7 @@ enum DrawPass<'a> {
     GatherTextures,
     Main {
         masking: BatchMasking,
+        scissor: Option<Scissor>,
         batch_pool: &'a mut BatchPool,
     },
 }
@@ -43,6 +51,7 @@ impl<'a> DrawContext<'a> {
             context,
             pass: DrawPass::Main {
                 masking: BatchMasking::Unmasked,
+                scissor: None,
                 batch_pool,
             },
         }
@@ -51,11 +60,17 @@ impl<'a> DrawContext<'a> {
     pub fn push_mask(&mut self) {
         match &mut self.pass {
             DrawPass::GatherTextures => {}
-            DrawPass::Main { masking, .. } => match masking {
-                BatchMasking::Unmasked => *masking = BatchMasking::NewMask,
-                _ => panic!(
-                    "attempting to push a mask while one was already pushed"
-                ),
+            DrawPass::Main { masking, .. } => match *masking {
+                BatchMasking::Unmasked => *masking = BatchMasking::NewMask(1),
+                BatchMasking::Masked(level) => {
+                    *masking = BatchMasking::NewMask(level + 1);
+                }
+                BatchMasking::NewMask(_) | BatchMasking::AddToMask(_) => {
+                    panic!(
+                        "attempting to push a mask while drawing the shape \
+                         of another mask"
+                    )
+                }
             },
         }
     }
@@ -63,10 +78,12 @@ impl<'a> DrawContext<'a> {
     pub fn start_masking(&mut self) {
         match &mut self.pass {
             DrawPass::GatherTextures => {}
-            DrawPass::Main { masking, .. } => match masking {
-                BatchMasking::NewMask
-                | BatchMasking::AddToMask
-                | BatchMasking::Masked => *masking = BatchMasking::Masked,
+            DrawPass::Main { masking, .. } => match *masking {
+                BatchMasking::NewMask(level)
+                | BatchMasking::AddToMask(level)
+                | BatchMasking::Masked(level) => {
+                    *masking = BatchMasking::Masked(level);
+                }
                 BatchMasking::Unmasked => panic!(
                     "attempted to start masking without first pushing a mask",
                 ),
@@ -80,12 +97,16 @@ impl<'a> DrawContext<'a> {
             DrawPass::Main {
                 masking,
                 batch_pool,
-            } => match masking {
-                BatchMasking::NewMask
-                | BatchMasking::AddToMask
-                | BatchMasking::Masked => {
-                    *masking = BatchMasking::Unmasked;
-                    batch_pool.pop_empty_mask();
+                ..
+            } => match *masking {
+                BatchMasking::NewMask(level)
+                | BatchMasking::AddToMask(level)
+                | BatchMasking::Masked(level) => {
+                    *masking = match level - 1 {
+                        0 => BatchMasking::Unmasked,
+                        parent => BatchMasking::Masked(parent),
+                    };
+                    batch_pool.pop_empty_mask(level);
                 }
                 BatchMasking::Unmasked => panic!(
                     "attempted to pop a mask without first pushing a mask",
@@ -107,17 +128,61 @@ impl<'a> DrawContext<'a> {
             }
             DrawPass::Main {
                 masking,
+                scissor,
                 batch_pool,
             } => batch_pool.find_batch(
                 &self.context.texture_cache,
                 tex,
                 *masking,
+                *scissor,
                 num_vertices,
                 draw_area,
             ),
         }
     }
 
+    /// Get the scissor rect covering a rectangle with the current matrix,
+    /// or `None` if the matrix doesn't keep the rectangle axis-aligned.
+    fn scissor_for(&self, rect: &SimpleRect) -> Option<Scissor> {
+        let DrawPass::Main { batch_pool, .. } = &self.pass else {
+            return None;
+        };
+        let matrix = &batch_pool.matrix;
+        let m = matrix.as_ref();
+        // only scaling and translation keep edges axis-aligned
+        if m[1] != 0.0 || m[3] != 0.0 || m[4] != 0.0 || m[7] != 0.0 {
+            return None;
+        }
+        let (left, bottom, _, w) =
+            matrix * (rect.left(), rect.bottom(), 0.0, 1.0);
+        let (right, top, _, _) = matrix * (rect.right(), rect.top(), 0.0, 1.0);
+        Some(Scissor {
+            left: left.min(right) / w,
+            bottom: bottom.min(top) / w,
+            right: left.max(right) / w,
+            top: bottom.max(top) / w,
+        })
+    }
+
+    /// Limit drawing to a scissor rect, inside the current one, returning
+    /// the current one to restore with `set_scissor`.
+    fn push_scissor(&mut self, new: Scissor) -> Option<Scissor> {
+        match &mut self.pass {
+            DrawPass::GatherTextures => None,
+            DrawPass::Main { scissor, .. } => {
+                let old = *scissor;
+                *scissor = Some(old.map_or(new, |old| old.intersect(new)));
+                old
+            }
+        }
+    }
+
+    fn set_scissor(&mut self, new: Option<Scissor>) {
+        if let DrawPass::Main { scissor, .. } = &mut self.pass {
+            *scissor = new;
+        }
+    }
+
     pub fn update_matrix<F>(&mut self, f: F)
     where
         F: FnOnce(Mat4) -> Mat4,
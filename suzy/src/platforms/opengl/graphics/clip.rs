@@ -0,0 +1,109 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use crate::{
+    dims::{Padding2d, Rect, SimpleRect},
+    graphics::{DrawContext, Graphic},
+    platforms::opengl::{self, renderer::Scissor},
+    widget::WidgetGraphic,
+};
+
+use opengl::{OpenGlRenderPlatform, SlicedImage};
+
+/// A widget graphic which clips the widget's children to a rectangle.
+///
+/// While the current transform keeps the rectangle axis-aligned, this uses
+/// the scissor test, which is much cheaper than a [`Mask`](super::Mask).
+/// Otherwise, it falls back to a mask in the shape of the rectangle.
+#[derive(Default)]
+pub struct ClipRect {
+    rect: SimpleRect,
+    shape: SlicedImage,
+    pushed: Clipping,
+}
+
+enum Clipping {
+    /// Clipped with the scissor test, storing the scissor rect to restore.
+    Scissor(Option<Scissor>),
+    Mask,
+}
+
+impl Default for Clipping {
+    fn default() -> Self {
+        Self::Scissor(None)
+    }
+}
+
+impl ClipRect {
+    /// Create a new [`ClipRect`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn proxy_rect<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&SimpleRect) -> R,
+    {
+        f(&self.rect)
+    }
+
+    fn proxy_rect_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut SimpleRect) -> R,
+    {
+        f(&mut self.rect)
+    }
+}
+
+impl Rect for ClipRect {
+    crate::dims::proxy_rect_impl! {
+        Self::proxy_rect; Self::proxy_rect_mut
+    }
+}
+
+pub struct Push<'a> {
+    clip: &'a mut ClipRect,
+}
+
+pub struct Pop<'a> {
+    clip: &'a ClipRect,
+}
+
+impl WidgetGraphic<OpenGlRenderPlatform> for ClipRect {
+    type BeforeGetter = fn(&mut ()) -> Push<'_>;
+    type AfterGetter = fn(&mut ()) -> Pop<'_>;
+
+    fn before_children(&mut self) -> Push<'_> {
+        Push { clip: self }
+    }
+
+    fn after_children(&mut self) -> Pop<'_> {
+        Pop { clip: self }
+    }
+}
+
+impl Graphic<OpenGlRenderPlatform> for Push<'_> {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, OpenGlRenderPlatform>) {
+        let clip = &mut *self.clip;
+        clip.pushed = match ctx.scissor_for(&clip.rect) {
+            Some(scissor) => Clipping::Scissor(ctx.push_scissor(scissor)),
+            None => {
+                clip.shape.set_fill(&clip.rect, &Padding2d::zero());
+                ctx.push_mask();
+                Graphic::<OpenGlRenderPlatform>::draw(&mut clip.shape, ctx);
+                ctx.start_masking();
+                Clipping::Mask
+            }
+        };
+    }
+}
+
+impl Graphic<OpenGlRenderPlatform> for Pop<'_> {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, OpenGlRenderPlatform>) {
+        match self.clip.pushed {
+            Clipping::Scissor(outer) => ctx.set_scissor(outer),
+            Clipping::Mask => ctx.pop_mask(),
+        }
+    }
+}
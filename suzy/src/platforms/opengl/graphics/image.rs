@@ -12,6 +12,7 @@ use crate::{
 use opengl::{
     renderer::{
         Batch, BatchRef, UvRect, UvRectValues, UvType, Vertex, VertexConfig,
+        VertexVec,
     },
     OpenGlRenderPlatform, Texture,
 };
@@ -76,6 +77,18 @@ impl SlicedImage {
         Self::default()
     }
 
+    /// Get the color the image is tinted with.
+    #[must_use]
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Get the style of the image's corners.
+    #[must_use]
+    pub fn corners(&self) -> CornerStyle {
+        self.corners
+    }
+
     fn proxy_rect<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&SimpleRect) -> R,
@@ -113,70 +126,87 @@ impl crate::platform::graphics::SlicedImage for SlicedImage {
 
 impl Graphic<OpenGlRenderPlatform> for SlicedImage {
     fn draw(&mut self, ctx: &mut DrawContext<'_, OpenGlRenderPlatform>) {
-        if let Some(BatchRef { batch, mut uv_rect }) =
+        if let Some(BatchRef { batch, uv_rect }) =
             ctx.find_batch(&self.texture, 16, &[(&self.rect).into()])
         {
-            loop {
-                match uv_rect {
-                    UvRect::SolidColor(u, v) => {
-                        let rect = UvRectValues {
-                            left: u,
-                            right: u,
-                            bottom: v,
-                            top: v,
-                        };
-                        self.push_vertices(batch, rect, rect);
-                        return;
-                    }
-                    UvRect::F32(uv_rect_f32) => {
-                        let inner_uv_rect = UvRectValues {
-                            left: uv_rect_f32.left + self.padding.left(),
-                            right: uv_rect_f32.right - self.padding.right(),
-                            bottom: uv_rect_f32.bottom + self.padding.bottom(),
-                            top: uv_rect_f32.top - self.padding.top(),
-                        };
-                        self.push_vertices(batch, uv_rect_f32, inner_uv_rect);
-                        return;
-                    }
-                    UvRect::U16(uv_rect_u16) => {
-                        match [
-                            self.padding.left(),
-                            self.padding.right(),
-                            self.padding.bottom(),
-                            self.padding.top(),
-                        ]
-                        .map(u16::try_from_f32)
-                        {
-                            [Some(left), Some(right), Some(bottom), Some(top)] =>
-                            {
-                                let inner_uv_rect = UvRectValues {
-                                    left: uv_rect_u16
-                                        .left
-                                        .saturating_add(left),
-                                    right: uv_rect_u16
-                                        .right
-                                        .saturating_sub(right),
-                                    bottom: uv_rect_u16
-                                        .bottom
-                                        .saturating_add(bottom),
-                                    top: uv_rect_u16.top.saturating_sub(top),
-                                };
-                                self.push_vertices(
-                                    batch,
-                                    uv_rect_u16,
-                                    inner_uv_rect,
-                                );
-                                return;
-                            }
-                            _ => {
-                                uv_rect = UvRect::F32(UvRectValues {
-                                    left: uv_rect_u16.left.to_f32(),
-                                    right: uv_rect_u16.right.to_f32(),
-                                    bottom: uv_rect_u16.bottom.to_f32(),
-                                    top: uv_rect_u16.top.to_f32(),
-                                });
-                                continue;
-                            }
+            let Batch {
+                vertices, indices, ..
+            } = batch;
+            self.push_geometry(uv_rect, vertices, indices);
+        }
+    }
+}
+
+impl SlicedImage {
+    /// Add the vertices and indices of the image, for a texture with the
+    /// given uv rect.
+    pub(in crate::platforms) fn push_geometry(
+        &self,
+        mut uv_rect: UvRect,
+        vertices: &mut VertexVec,
+        indices: &mut Vec<u16>,
+    ) {
+        loop {
+            match uv_rect {
+                UvRect::SolidColor(u, v) => {
+                    let rect = UvRectValues {
+                        left: u,
+                        right: u,
+                        bottom: v,
+                        top: v,
+                    };
+                    self.push_vertices(vertices, indices, rect, rect);
+                    return;
+                }
+                UvRect::F32(uv_rect_f32) => {
+                    let inner_uv_rect = UvRectValues {
+                        left: uv_rect_f32.left + self.padding.left(),
+                        right: uv_rect_f32.right - self.padding.right(),
+                        bottom: uv_rect_f32.bottom + self.padding.bottom(),
+                        top: uv_rect_f32.top - self.padding.top(),
+                    };
+                    self.push_vertices(
+                        vertices,
+                        indices,
+                        uv_rect_f32,
+                        inner_uv_rect,
+                    );
+                    return;
+                }
+                UvRect::U16(uv_rect_u16) => {
+                    match [
+                        self.padding.left(),
+                        self.padding.right(),
+                        self.padding.bottom(),
+                        self.padding.top(),
+                    ]
+                    .map(u16::try_from_f32)
+                    {
+                        [Some(left), Some(right), Some(bottom), Some(top)] => {
+                            let inner_uv_rect = UvRectValues {
+                                left: uv_rect_u16.left.saturating_add(left),
+                                right: uv_rect_u16.right.saturating_sub(right),
+                                bottom: uv_rect_u16
+                                    .bottom
+                                    .saturating_add(bottom),
+                                top: uv_rect_u16.top.saturating_sub(top),
+                            };
+                            self.push_vertices(
+                                vertices,
+                                indices,
+                                uv_rect_u16,
+                                inner_uv_rect,
+                            );
+                            return;
+                        }
+                        _ => {
+                            uv_rect = UvRect::F32(UvRectValues {
+                                left: uv_rect_u16.left.to_f32(),
+                                right: uv_rect_u16.right.to_f32(),
+                                bottom: uv_rect_u16.bottom.to_f32(),
+                                top: uv_rect_u16.top.to_f32(),
+                            });
+                            continue;
                         }
                     }
                 }
@@ -188,14 +218,15 @@ impl Graphic<OpenGlRenderPlatform> for SlicedImage {
 impl SlicedImage {
     fn push_vertices<Uv>(
         &self,
-        batch: &mut Batch,
+        vertices: &mut VertexVec,
+        indices: &mut Vec<u16>,
         uv_rect: UvRectValues<Uv>,
         inner_uv_rect: UvRectValues<Uv>,
     ) where
         Uv: UvType,
     {
         let rect = &self.rect;
-        let index_offset: u16 = batch.vertices.len().try_into().expect(
+        let index_offset: u16 = vertices.len().try_into().expect(
             "the number of vertices in a batch should be less than 2^16",
         );
         let mut inner = SimpleRect::default();
@@ -238,7 +269,7 @@ impl SlicedImage {
                 let config = VertexConfig::new()
                     .alpha_base(0.0)
                     .vector(x_inside, y_inside);
-                batch.vertices.push(Vertex {
+                vertices.push(Vertex {
                     xy: [x, y],
                     uv: [u, v],
                     color,
@@ -249,7 +280,7 @@ impl SlicedImage {
             }
             odd = !odd;
         }
-        batch.indices.extend(
+        indices.extend(
             SLICED_INDICES.iter().map(|&i| u16::from(i) + index_offset),
         );
     }
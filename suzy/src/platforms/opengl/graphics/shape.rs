@@ -0,0 +1,361 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use std::convert::TryInto;
+
+use crate::{
+    dims::{Rect, SimpleRect},
+    graphics::{Color, DrawContext, Graphic, ShapeOutline},
+    platforms::opengl,
+};
+
+use opengl::{
+    renderer::{Batch, BatchRef, UvRect, Vertex, VertexConfig, VertexVec},
+    OpenGlRenderPlatform, Texture,
+};
+
+/// The most vertices one layer of a shape can use: each corner has up to
+/// three quads, and each side one more.
+const MAX_LAYER_VERTICES: u16 = 4 * (3 * 4 + 4);
+
+/// A vector shape graphic: a rectangle with rounded corners or an ellipse,
+/// with a fill and a border.
+///
+/// Edges are anti-aliased by the shader, so shapes stay smooth at any size
+/// without needing a texture.  The border is drawn inside the outline, and
+/// the fill is drawn under the inner half of the border.  The width of the
+/// border around elliptical corners is approximate, and gets less accurate
+/// the more stretched the ellipse is.
+pub struct Shape {
+    outline: ShapeOutline,
+    fill_color: Color,
+    stroke_color: Color,
+    stroke_width: f32,
+    rect: SimpleRect,
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self {
+            outline: ShapeOutline::default(),
+            fill_color: Color::WHITE,
+            stroke_color: Color::BLACK,
+            stroke_width: 0.0,
+            rect: SimpleRect::default(),
+        }
+    }
+}
+
+impl Shape {
+    /// Create a new [`Shape`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the outline of the shape.
+    #[must_use]
+    pub fn outline(&self) -> ShapeOutline {
+        self.outline
+    }
+
+    /// Get the color inside the outline.
+    #[must_use]
+    pub fn fill_color(&self) -> Color {
+        self.fill_color
+    }
+
+    /// Get the color of the border.
+    #[must_use]
+    pub fn stroke_color(&self) -> Color {
+        self.stroke_color
+    }
+
+    /// Get the width of the border.
+    #[must_use]
+    pub fn stroke_width(&self) -> f32 {
+        self.stroke_width
+    }
+
+    fn proxy_rect<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&SimpleRect) -> R,
+    {
+        f(&self.rect)
+    }
+
+    fn proxy_rect_mut<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut SimpleRect) -> R,
+    {
+        f(&mut self.rect)
+    }
+}
+
+impl Rect for Shape {
+    crate::dims::proxy_rect_impl! {
+        Self::proxy_rect; Self::proxy_rect_mut
+    }
+}
+
+impl crate::platform::graphics::Shape for Shape {
+    fn set_outline(&mut self, outline: ShapeOutline) {
+        self.outline = outline;
+    }
+
+    fn set_fill_color(&mut self, color: Color) {
+        self.fill_color = color;
+    }
+
+    fn set_stroke(&mut self, color: Color, width: f32) {
+        self.stroke_color = color;
+        self.stroke_width = width;
+    }
+}
+
+impl Graphic<OpenGlRenderPlatform> for Shape {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, OpenGlRenderPlatform>) {
+        if let Some(BatchRef { batch, uv_rect }) = ctx.find_batch(
+            &Texture::solid_color(),
+            2 * MAX_LAYER_VERTICES,
+            &[(&self.rect).into()],
+        ) {
+            let Batch {
+                vertices, indices, ..
+            } = batch;
+            self.push_geometry(uv_rect, vertices, indices);
+        }
+    }
+}
+
+impl Shape {
+    /// Add the vertices and indices of the shape, for the uv rect of the
+    /// solid color texture.
+    pub(in crate::platforms) fn push_geometry(
+        &self,
+        uv_rect: UvRect,
+        vertices: &mut VertexVec,
+        indices: &mut Vec<u16>,
+    ) {
+        let UvRect::SolidColor(u, v) = uv_rect else {
+            return;
+        };
+        let rect = &self.rect;
+        let half = rect.width().min(rect.height()) / 2.0;
+        let stroke_width = self.stroke_width.min(half);
+        let has_stroke = stroke_width > 0.0 && self.stroke_color.a > 0.0;
+        if self.fill_color.a > 0.0 {
+            let inset = if has_stroke { stroke_width / 2.0 } else { 0.0 };
+            let mut mesh = Mesh {
+                vertices: &mut *vertices,
+                indices: &mut *indices,
+                uv: [u, v],
+                color: self.fill_color.rgba8(),
+            };
+            mesh.push_layer(rect, self.outline, inset, None);
+        }
+        if has_stroke {
+            let mut mesh = Mesh {
+                vertices,
+                indices,
+                uv: [u, v],
+                color: self.stroke_color.rgba8(),
+            };
+            mesh.push_layer(rect, self.outline, 0.0, Some(stroke_width));
+        }
+    }
+}
+
+/// The extent of a layer along one axis.
+#[derive(Clone, Copy)]
+struct Span {
+    start: f32,
+    end: f32,
+    half: f32,
+    mid: f32,
+}
+
+impl Span {
+    fn new(start: f32, end: f32) -> Self {
+        Self {
+            start,
+            end,
+            half: (end - start) / 2.0,
+            mid: (start + end) / 2.0,
+        }
+    }
+
+    /// Get the coordinate `dist` in from one end, stopping at the middle.
+    ///
+    /// Pieces which meet in the middle get exactly the same coordinate this
+    /// way, so there are no gaps between them.
+    fn inward(self, from_end: bool, dist: f32) -> f32 {
+        if dist >= self.half {
+            self.mid
+        } else if from_end {
+            self.end - dist
+        } else {
+            self.start + dist
+        }
+    }
+
+    fn edge(self, at_end: bool) -> f32 {
+        if at_end {
+            self.end
+        } else {
+            self.start
+        }
+    }
+}
+
+struct Mesh<'a> {
+    vertices: &'a mut VertexVec,
+    indices: &'a mut Vec<u16>,
+    uv: [u16; 2],
+    color: [u8; 4],
+}
+
+impl Mesh<'_> {
+    /// Add a band of the shape along the inside of the outline, `band` wide,
+    /// or the whole shape if `band` is `None`.  The outline is first inset
+    /// by `inset` on every side.
+    fn push_layer(
+        &mut self,
+        rect: &SimpleRect,
+        outline: ShapeOutline,
+        inset: f32,
+        band: Option<f32>,
+    ) {
+        let x = Span::new(rect.left() + inset, rect.right() - inset);
+        let y = Span::new(rect.bottom() + inset, rect.top() - inset);
+        let half = x.half.min(y.half);
+        if half.is_nan() || half <= 0.0 {
+            return;
+        }
+        let width = band.map_or(half, |width| width.min(half));
+        // corners in the order bottom left, bottom right, top right, top
+        // left, with their radii
+        let radii = match outline {
+            ShapeOutline::Rect(radii) => [
+                radii.bottom_left,
+                radii.bottom_right,
+                radii.top_right,
+                radii.top_left,
+            ]
+            .map(|radius| {
+                let radius = (radius - inset).clamp(0.0, half);
+                [radius, radius]
+            }),
+            ShapeOutline::Ellipse => [[x.half, y.half]; 4],
+        };
+        let corners =
+            [[false, false], [true, false], [true, true], [false, true]];
+        let mut blocks = [[0.0; 2]; 4];
+        for ((&[at_right, at_top], &[rx, ry]), block) in
+            corners.iter().zip(&radii).zip(&mut blocks)
+        {
+            let outer = [x.edge(at_right), y.edge(at_top)];
+            let inner = [x.inward(at_right, rx), y.inward(at_top, ry)];
+            if rx > 0.0 && ry > 0.0 {
+                let peak = if width >= rx.min(ry) {
+                    1.0
+                } else {
+                    ((width + 1.0) / (rx + ry)).min(1.0)
+                };
+                self.push_corner(outer, inner, peak, (rx + ry) / 2.0);
+            }
+            let cx = rx.max(width);
+            let cy = ry.max(width);
+            if rx < width {
+                self.push_solid(
+                    [inner[0], x.inward(at_right, width)],
+                    [outer[1], y.inward(at_top, cy)],
+                );
+            }
+            if ry < width {
+                self.push_solid(
+                    [outer[0], inner[0]],
+                    [inner[1], y.inward(at_top, width)],
+                );
+            }
+            *block = [cx, cy];
+        }
+        let [bottom_left, bottom_right, top_right, top_left] = blocks;
+        self.push_solid(
+            [x.start, x.inward(false, width)],
+            [y.inward(false, bottom_left[1]), y.inward(true, top_left[1])],
+        );
+        self.push_solid(
+            [x.inward(true, width), x.end],
+            [
+                y.inward(false, bottom_right[1]),
+                y.inward(true, top_right[1]),
+            ],
+        );
+        self.push_solid(
+            [
+                x.inward(false, bottom_left[0]),
+                x.inward(true, bottom_right[0]),
+            ],
+            [y.start, y.inward(false, width)],
+        );
+        self.push_solid(
+            [x.inward(false, top_left[0]), x.inward(true, top_right[0])],
+            [y.inward(true, width), y.end],
+        );
+    }
+
+    /// Add a quad with an elliptical edge, from the outside corner of the
+    /// outline to the center of the ellipse.
+    fn push_corner(
+        &mut self,
+        [x0, y0]: [f32; 2],
+        [x1, y1]: [f32; 2],
+        peak: f32,
+        smoothing: f32,
+    ) {
+        let config = VertexConfig::new().alpha_base(0.0).alpha_peak(peak);
+        self.push_quad(
+            [x0, x1],
+            [y0, y1],
+            [
+                config.vector(false, false),
+                config.vector(true, false),
+                config.vector(false, true),
+                config.vector(true, true),
+            ],
+            smoothing,
+        );
+    }
+
+    fn push_solid(&mut self, xs: [f32; 2], ys: [f32; 2]) {
+        self.push_quad(xs, ys, [VertexConfig::new(); 4], 1.0);
+    }
+
+    fn push_quad(
+        &mut self,
+        [x0, x1]: [f32; 2],
+        [y0, y1]: [f32; 2],
+        configs: [VertexConfig; 4],
+        smoothing: f32,
+    ) {
+        if x0 == x1 || y0 == y1 {
+            return;
+        }
+        let index_offset: u16 = self.vertices.len().try_into().expect(
+            "the number of vertices in a batch should be less than 2^16",
+        );
+        let points = [[x0, y0], [x1, y0], [x0, y1], [x1, y1]];
+        for (xy, config) in points.iter().zip(configs) {
+            self.vertices.push(Vertex {
+                xy: *xy,
+                uv: self.uv,
+                color: self.color,
+                config,
+                smoothing,
+            });
+        }
+        self.indices
+            .extend([0, 1, 2, 1, 3, 2].iter().map(|i| i + index_offset));
+    }
+}
@@ -12,7 +12,7 @@ use opengl::{Mat4, OpenGlRenderPlatform};
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Transform {
     pub matrix: Mat4,
-    original: Mat4,
+    pub(in crate::platforms) original: Mat4,
 }
 
 pub struct Push<'a> {
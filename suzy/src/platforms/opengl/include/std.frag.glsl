@@ -9,6 +9,7 @@
 uniform sampler2D TEX_ID;
 uniform mediump vec2 TEX_SIZE;
 uniform lowp float TEX_SDF;
+uniform lowp float TEX_MSDF;
 uniform mediump float TEX_COLOR_POW;
 uniform sampler2D MASK_ID;
 uniform mediump vec2 MASK_SIZE;
@@ -21,7 +22,14 @@ varying mediump float pass_smoothing;
 
 void main() {
     lowp vec4 tex_color = texture2D(TEX_ID, pass_uv / TEX_SIZE);
-    mediump float sdf_value = max(tex_color.a, 1.0 - TEX_SDF);
+    // multi-channel distance fields store the distance as the median of
+    // the color channels
+    mediump float median = max(
+        min(tex_color.r, tex_color.g),
+        min(max(tex_color.r, tex_color.g), tex_color.b)
+    );
+    mediump float sdf_value = mix(tex_color.a, median, TEX_MSDF);
+    sdf_value = max(sdf_value, 1.0 - TEX_SDF);
     sdf_value *= 1.0 - min(length(pass_distance), 1.0);
     tex_color = max(tex_color, TEX_SDF);
     tex_color.rgb = pow(tex_color.rgb, vec3(TEX_COLOR_POW));
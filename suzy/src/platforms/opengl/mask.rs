@@ -103,3 +103,48 @@ impl Mask {
         self.height = height.into();
     }
 }
+
+/// The masks used while drawing, one for each level of nesting.
+///
+/// The shape of a nested mask is clipped by the mask containing it, so the
+/// texture of the innermost mask is the intersection of all the active
+/// masks.
+#[derive(Default)]
+pub struct MaskStack {
+    levels: Vec<Mask>,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl MaskStack {
+    pub fn configure_for_size(
+        &mut self,
+        gl: &Rc<OpenGlBindings>,
+        width: u16,
+        height: u16,
+    ) {
+        for mask in &mut self.levels {
+            mask.configure_for_size(gl, width, height);
+        }
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Create and size the masks for nesting up to `depth` levels deep.
+    pub fn reserve_depth(&mut self, gl: &Rc<OpenGlBindings>, depth: usize) {
+        while self.levels.len() < depth {
+            let mut mask = Mask::new(gl);
+            mask.configure_for_size(gl, self.width, self.height);
+            self.levels.push(mask);
+        }
+    }
+
+    /// Get the mask for a level of nesting, starting from one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the masks were not reserved up to this depth.
+    pub fn level(&self, level: usize) -> &Mask {
+        &self.levels[level - 1]
+    }
+}
@@ -12,8 +12,8 @@ use super::{
         types::{GLint, GLsizei, GLsizeiptr, GLuint},
         ARRAY_BUFFER, COLOR_BUFFER_BIT, COLOR_CLEAR_VALUE,
         ELEMENT_ARRAY_BUFFER, FALSE, FLOAT, FRAMEBUFFER, FRAMEBUFFER_BINDING,
-        STREAM_DRAW, TEXTURE0, TEXTURE1, TEXTURE_2D, TRIANGLES, TRUE,
-        UNSIGNED_BYTE, UNSIGNED_SHORT,
+        SCISSOR_TEST, STREAM_DRAW, TEXTURE0, TEXTURE1, TEXTURE_2D, TRIANGLES,
+        TRUE, UNSIGNED_BYTE, UNSIGNED_SHORT, VIEWPORT,
     },
     shader::ShaderProgram,
     Texture,
@@ -25,10 +25,17 @@ pub use vertex::{
     UvRect, UvRectValues, UvType, Vertex, VertexConfig, VertexVec,
 };
 
-pub(super) use batch::{BatchMasking, BatchPool};
+pub(super) use batch::{BatchMasking, BatchPool, Scissor};
 
 pub(super) fn render(ctx: &mut super::OpenGlContext, mut batches: BatchPool) {
     batches.reduce_mask_clears();
+    let depth = batches
+        .batches
+        .iter()
+        .map(|batch| batch.masking.level())
+        .max()
+        .unwrap_or(0);
+    ctx.masks.reserve_depth(&ctx.bindings, depth);
 
     let want_buffers =
         u16::try_from(2 * batches.batches.len()).unwrap_or(u16::MAX);
@@ -53,8 +60,8 @@ pub(super) fn render(ctx: &mut super::OpenGlContext, mut batches: BatchPool) {
     ShaderProgram::set_vec2(
         &ctx.bindings,
         ctx.shaders.uniforms.mask_size,
-        ctx.mask.width,
-        ctx.mask.height,
+        ctx.masks.width.into(),
+        ctx.masks.height.into(),
     );
 
     let Some((solid_color_tex_id, _)) =
@@ -63,6 +70,12 @@ pub(super) fn render(ctx: &mut super::OpenGlContext, mut batches: BatchPool) {
         return;
     };
     let mut main_fbo = None;
+    let mut bound_mask = None;
+    let mut current_scissor = None;
+    let mut viewport: [GLint; 4] = [0; 4];
+    unsafe {
+        ctx.bindings.GetIntegerv(VIEWPORT, viewport.as_mut_ptr());
+    }
 
     let mut main_clear_color = [0f32; 4];
     unsafe {
@@ -78,26 +91,37 @@ pub(super) fn render(ctx: &mut super::OpenGlContext, mut batches: BatchPool) {
         else {
             continue;
         };
-        let tex_id_for_mask_uniform = match batch.masking {
-            BatchMasking::Masked => ctx.mask.texture,
-            _ => solid_color_tex_id,
+        let tex_id_for_mask_uniform = match batch.masking.clip() {
+            Some(level) => ctx.masks.level(level).texture,
+            None => solid_color_tex_id,
         };
         unsafe {
-            match (main_fbo, batch.masking) {
-                (None, BatchMasking::NewMask | BatchMasking::AddToMask) => {
+            let target = batch.masking.target();
+            if target != bound_mask {
+                let main_fbo = *main_fbo.get_or_insert_with(|| {
                     let mut current_fbo: GLint = 0;
                     ctx.bindings
                         .GetIntegerv(FRAMEBUFFER_BINDING, &mut current_fbo);
-                    ctx.bindings.BindFramebuffer(FRAMEBUFFER, ctx.mask.fbo);
-                    main_fbo = Some(current_fbo as GLuint);
-                }
-                (Some(fbo), BatchMasking::Unmasked | BatchMasking::Masked) => {
-                    ctx.bindings.BindFramebuffer(FRAMEBUFFER, fbo);
-                    main_fbo = None;
+                    current_fbo as GLuint
+                });
+                let fbo = target
+                    .map_or(main_fbo, |level| ctx.masks.level(level).fbo);
+                ctx.bindings.BindFramebuffer(FRAMEBUFFER, fbo);
+                bound_mask = target;
+            }
+            if batch.scissor != current_scissor {
+                match batch.scissor {
+                    Some(scissor) => {
+                        let [x, y, width, height] =
+                            scissor_box(viewport, scissor);
+                        ctx.bindings.Enable(SCISSOR_TEST);
+                        ctx.bindings.Scissor(x, y, width, height);
+                    }
+                    None => ctx.bindings.Disable(SCISSOR_TEST),
                 }
-                _ => (),
+                current_scissor = batch.scissor;
             }
-            if batch.masking == BatchMasking::NewMask {
+            if let BatchMasking::NewMask(_) = batch.masking {
                 ctx.bindings.Clear(COLOR_BUFFER_BIT);
             }
             ctx.bindings.ActiveTexture(TEXTURE1);
@@ -126,6 +150,11 @@ pub(super) fn render(ctx: &mut super::OpenGlContext, mut batches: BatchPool) {
                 ctx.shaders.uniforms.tex_sdf,
                 if tex_size.is_sdf { 1.0 } else { 0.0 },
             );
+            ShaderProgram::set_float(
+                &ctx.bindings,
+                ctx.shaders.uniforms.tex_msdf,
+                if tex_size.is_msdf { 1.0 } else { 0.0 },
+            );
             ShaderProgram::set_float(
                 &ctx.bindings,
                 ctx.shaders.uniforms.tex_color_pow,
@@ -209,7 +238,28 @@ pub(super) fn render(ctx: &mut super::OpenGlContext, mut batches: BatchPool) {
     }
 
     unsafe {
+        if let (Some(_), Some(fbo)) = (bound_mask, main_fbo) {
+            ctx.bindings.BindFramebuffer(FRAMEBUFFER, fbo);
+        }
+        if current_scissor.is_some() {
+            ctx.bindings.Disable(SCISSOR_TEST);
+        }
         let [r, g, b, a] = main_clear_color;
         ctx.bindings.ClearColor(r, g, b, a);
     }
 }
+
+/// Convert a scissor rect to the pixels of the viewport it covers, as the
+/// x, y, width and height to pass to `glScissor`.
+fn scissor_box(viewport: [GLint; 4], scissor: Scissor) -> [GLint; 4] {
+    let [x, y, width, height] = viewport;
+    let to_pixels = |ndc: f32, size: GLint| {
+        let size = size as f32;
+        ((ndc + 1.0) * 0.5 * size).round().clamp(0.0, size) as GLint
+    };
+    let left = to_pixels(scissor.left, width);
+    let right = to_pixels(scissor.right, width).max(left);
+    let bottom = to_pixels(scissor.bottom, height);
+    let top = to_pixels(scissor.top, height).max(bottom);
+    [x + left, y + bottom, right - left, top - bottom]
+}
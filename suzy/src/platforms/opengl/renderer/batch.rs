@@ -13,6 +13,7 @@ pub struct Batch {
     pub vertices: VertexVec,
     pub indices: Vec<u16>,
     pub(super) masking: BatchMasking,
+    pub(super) scissor: Option<Scissor>,
 }
 
 pub struct BatchRef<'a> {
@@ -25,12 +26,75 @@ pub struct BatchPool {
     pub(super) batches: Vec<Batch>,
 }
 
+/// How a batch interacts with masks.  Mask levels count the masks active,
+/// starting from one for the outermost mask.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BatchMasking {
     Unmasked,
-    NewMask,
-    AddToMask,
-    Masked,
+    /// Clear the mask at a level, and draw its shape, clipped by the level
+    /// below.
+    NewMask(usize),
+    /// Draw more of the shape of the mask at a level, clipped by the level
+    /// below.
+    AddToMask(usize),
+    /// Draw to the screen, clipped by the mask at a level.
+    Masked(usize),
+}
+
+impl BatchMasking {
+    /// The number of masks active.
+    pub fn level(self) -> usize {
+        match self {
+            Self::Unmasked => 0,
+            Self::NewMask(level)
+            | Self::AddToMask(level)
+            | Self::Masked(level) => level,
+        }
+    }
+
+    /// The level of the mask the batch is drawn into, or `None` if it is
+    /// drawn to the screen.
+    pub fn target(self) -> Option<usize> {
+        match self {
+            Self::NewMask(level) | Self::AddToMask(level) => Some(level),
+            Self::Unmasked | Self::Masked(_) => None,
+        }
+    }
+
+    /// The level of the mask the batch is clipped by, or `None` if it is not
+    /// clipped.
+    pub fn clip(self) -> Option<usize> {
+        match self {
+            Self::Unmasked => None,
+            Self::NewMask(level) | Self::AddToMask(level) => {
+                Some(level - 1).filter(|&clip| clip > 0)
+            }
+            Self::Masked(level) => Some(level),
+        }
+    }
+}
+
+/// A rectangle drawing is limited to with the scissor test, in normalized
+/// device coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Scissor {
+    pub left: f32,
+    pub bottom: f32,
+    pub right: f32,
+    pub top: f32,
+}
+
+impl Scissor {
+    /// Get the area inside both scissor rects.
+    #[must_use]
+    pub fn intersect(self, other: Self) -> Self {
+        Self {
+            left: self.left.max(other.left),
+            bottom: self.bottom.max(other.bottom),
+            right: self.right.min(other.right),
+            top: self.top.min(other.top),
+        }
+    }
 }
 
 impl BatchPool {
@@ -60,47 +124,56 @@ impl BatchPool {
         texture_cache: &TextureCache,
         tex: &Texture,
         masking: BatchMasking,
+        scissor: Option<Scissor>,
         num_vertices: u16,
         draw_area: &[BoundingBox],
     ) -> Option<BatchRef<'_>> {
         let mut found: Option<(usize, UvRect)> = None;
         for (i, batch) in self.batches.iter_mut().enumerate().rev() {
-            match (batch.masking, masking) {
-                (_, BatchMasking::AddToMask) => {
+            let can_merge = match (batch.masking, masking) {
+                (_, BatchMasking::AddToMask(_)) => {
                     panic!("should not create a batch using AddToMask");
                 }
-                // can render un-masked content before rendering to a mask
-                (BatchMasking::AddToMask, BatchMasking::Unmasked)
-                | (BatchMasking::NewMask, BatchMasking::Unmasked) => {
+                // can render content before rendering to a mask, unless the
+                // content is clipped by that mask
+                (
+                    BatchMasking::AddToMask(target)
+                    | BatchMasking::NewMask(target),
+                    BatchMasking::Unmasked | BatchMasking::Masked(_),
+                ) if target > masking.level() => {
                     continue;
                 }
+                // drawing the same mask, can be merged
+                (
+                    BatchMasking::AddToMask(target)
+                    | BatchMasking::NewMask(target),
+                    BatchMasking::NewMask(level),
+                ) if target == level => true,
                 // generally, a new mask should be a new batch
-                (BatchMasking::Unmasked, BatchMasking::NewMask)
-                | (BatchMasking::Masked, BatchMasking::NewMask)
-                // must not render masked content before rendering to a mask
-                | (BatchMasking::AddToMask, BatchMasking::Masked)
-                | (BatchMasking::NewMask, BatchMasking::Masked) => {
+                (_, BatchMasking::NewMask(_))
+                // must not render masked content before rendering to its
+                // mask
+                | (
+                    BatchMasking::AddToMask(_) | BatchMasking::NewMask(_),
+                    BatchMasking::Unmasked | BatchMasking::Masked(_),
+                ) => {
                     break;
                 }
-                // regular drawing but unmatched masking, can't be merged
-                // but can draw past
-                (BatchMasking::Unmasked, BatchMasking::Masked)
-                | (BatchMasking::Masked, BatchMasking::Unmasked) => {}
-                // drawing with matched masking, can be merged
-                (BatchMasking::Unmasked, BatchMasking::Unmasked)
-                | (BatchMasking::Masked, BatchMasking::Masked)
-                | (BatchMasking::NewMask, BatchMasking::NewMask)
-                | (BatchMasking::AddToMask, BatchMasking::NewMask) => {
-                    if let Some(uv_rect) = Self::can_use_texture(
-                        texture_cache,
-                        &batch.texture,
-                        tex,
-                    ) {
-                        if batch.vertices.can_add(num_vertices) {
-                            batch.vertices.reserve(num_vertices.into());
-                            found = Some((i, uv_rect));
-                            break;
-                        }
+                // regular drawing, can be merged if the masking matches,
+                // otherwise can draw past
+                (
+                    BatchMasking::Unmasked | BatchMasking::Masked(_),
+                    BatchMasking::Unmasked | BatchMasking::Masked(_),
+                ) => batch.masking == masking,
+            } && batch.scissor == scissor;
+            if can_merge {
+                if let Some(uv_rect) =
+                    Self::can_use_texture(texture_cache, &batch.texture, tex)
+                {
+                    if batch.vertices.can_add(num_vertices) {
+                        batch.vertices.reserve(num_vertices.into());
+                        found = Some((i, uv_rect));
+                        break;
                     }
                 }
             }
@@ -114,6 +187,7 @@ impl BatchPool {
                 texture: tex.id(),
                 covered_area: CoveredArea::default(),
                 masking,
+                scissor,
                 vertices: VertexVec::default(),
                 indices: Vec::new(),
             });
@@ -133,15 +207,18 @@ impl BatchPool {
         }
     }
 
-    pub(in crate::platforms::opengl) fn pop_empty_mask(&mut self) {
+    /// Remove the batches drawing the shape of the mask at a level, if
+    /// nothing was drawn clipped by it.
+    pub(in crate::platforms::opengl) fn pop_empty_mask(
+        &mut self,
+        level: usize,
+    ) {
         let mut remove_after = self.batches.len();
         for (i, batch) in self.batches.iter().enumerate().rev() {
-            match batch.masking {
-                BatchMasking::Unmasked | BatchMasking::Masked => break,
-                BatchMasking::NewMask | BatchMasking::AddToMask => {
-                    remove_after = i;
-                }
+            if batch.masking.target() != Some(level) {
+                break;
             }
+            remove_after = i;
         }
         self.batches.drain(remove_after..);
     }
@@ -149,12 +226,12 @@ impl BatchPool {
     pub(in crate::platforms::opengl) fn reduce_mask_clears(&mut self) {
         let mut iter = self.batches.iter_mut();
         while let Some(batch) = iter.next_back() {
-            if batch.masking != BatchMasking::NewMask {
+            let BatchMasking::NewMask(level) = batch.masking else {
                 continue;
-            }
+            };
             if let Some(earlier) = iter.as_slice().last() {
-                if earlier.masking == BatchMasking::NewMask {
-                    batch.masking = BatchMasking::AddToMask;
+                if earlier.masking == BatchMasking::NewMask(level) {
+                    batch.masking = BatchMasking::AddToMask(level);
                 }
             }
         }
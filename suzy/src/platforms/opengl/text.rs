@@ -1,9 +1,10 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
-use std::{convert::TryInto, rc::Rc};
+use std::{collections::HashMap, convert::TryInto, ops::Range, rc::Rc};
 
 use crate::{
+    dims::{Rect, SimpleRect},
     graphics::{Color, DrawContext, Graphic},
     text,
     watch::WatchedMeta,
@@ -15,11 +16,17 @@ use super::{
     OpenGlRenderPlatform,
 };
 
+mod asset;
 mod calc;
+#[cfg(feature = "dynamic-font")]
+mod dynamic;
 mod font;
+mod registry;
 
-use calc::{CalcParams, FontCharCalc};
-pub use font::Font;
+pub use asset::FontLoadError;
+use calc::FontCharCalc;
+pub use font::{AtlasKind, Font};
+pub use registry::{FontRegistry, FontWeight};
 
 #[cfg(feature = "default-font")]
 mod default_font;
@@ -29,13 +36,23 @@ mod default_font {
     #[track_caller]
     pub fn default_font() -> std::rc::Rc<super::font::Font> {
         panic!(
-            "invalid font index specified, and no default font is available"
+            "no matching font registered, and no default font is available"
         );
     }
+
+    #[track_caller]
+    pub fn default_bold_font() -> std::rc::Rc<super::font::Font> {
+        default_font()
+    }
 }
 
+/// Layers are drawn in the order they are declared, so that effects like
+/// shadows end up beneath the text itself.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 enum Layer {
+    Shadow,
+    Glow,
+    Outline,
     #[default]
     Primary,
 }
@@ -47,27 +64,186 @@ struct Draw {
     midpoint: f32,
     peak: f32,
     smoothing: f32,
+    /// How far, in pixels, to grow the glyph edges outwards.
+    dilate: f32,
+    /// The width, in pixels, of the transition at the glyph edges.  Zero
+    /// means an antialiased edge.
+    softness: f32,
+    offset: [f32; 2],
 }
 
+impl Draw {
+    fn new(layer: Layer, color: Color) -> Self {
+        Self {
+            layer,
+            color,
+            midpoint: 0.5,
+            peak: 1.0,
+            smoothing: f32::NAN,
+            dilate: 0.0,
+            softness: 0.0,
+            offset: [0.0, 0.0],
+        }
+    }
+}
+
+/// A draw resolved for a particular face: its color, vertex config, and
+/// smoothing.
+type FaceDraw<'a> = (&'a Draw, [u8; 4], VertexConfig, f32);
+
+/// How much the distance field threshold is lowered to imitate bold text
+/// when no bold face is available.
+const BOLD_MIDPOINT_SHIFT: f32 = 0.1;
+
+/// The horizontal shear applied to imitate italic text when no italic face
+/// is available, per unit of height.
+const ITALIC_SKEW: f32 = 0.2;
+
+/// The width of the lines of the replacement glyph's box, relative to the
+/// font size.
+const TOFU_STROKE: f32 = 0.06;
+
 #[derive(Clone, Debug)]
 pub struct TextStyle {
     pub font_size: f32,
+    /// The font family to use, as registered with a [`FontRegistry`].  If
+    /// this is `None`, or the family has no fonts registered, the default
+    /// font is used.
+    pub family: Option<Rc<str>>,
+    pub weight: FontWeight,
+    pub italic: bool,
+    /// Font families to find glyphs in when the primary font doesn't have
+    /// them, in order of preference.  Families with nothing registered are
+    /// skipped.
+    pub fallback: Vec<Rc<str>>,
+    /// The index of a font in the text's fonts, which were never
+    /// populated, so this always selected the default font.
+    #[deprecated(note = "fonts are selected by `family`, `weight` and \
+                         `italic`, and this field is ignored")]
     pub font: usize,
     draws: Vec<Draw>,
 }
 
+impl TextStyle {
+    /// Add a font family to find glyphs in when the fonts before it don't
+    /// have them.
+    #[must_use]
+    pub fn with_fallback(mut self, family: &str) -> Self {
+        self.fallback.push(family.into());
+        self
+    }
+
+    /// Add an outline around the text.
+    ///
+    /// `width` is in pixels, and is limited by the padding of the font's
+    /// signed distance field.
+    #[must_use]
+    pub fn with_outline(mut self, color: Color, width: f32) -> Self {
+        let mut draw = Draw::new(Layer::Outline, color);
+        draw.dilate = width;
+        self.draws.push(draw);
+        self
+    }
+
+    /// Add a soft glow around the text, fading out over `radius` pixels.
+    #[must_use]
+    pub fn with_glow(mut self, color: Color, radius: f32) -> Self {
+        let mut draw = Draw::new(Layer::Glow, color);
+        draw.dilate = radius * 0.5;
+        draw.softness = radius;
+        self.draws.push(draw);
+        self
+    }
+
+    /// Add a drop shadow beneath the text.
+    ///
+    /// `offset` is in pixels, and `softness` is the width in pixels of the
+    /// shadow's blurred edge; zero gives a sharp shadow.
+    #[must_use]
+    pub fn with_shadow(
+        mut self,
+        color: Color,
+        offset: [f32; 2],
+        softness: f32,
+    ) -> Self {
+        let mut draw = Draw::new(Layer::Shadow, color);
+        draw.dilate = softness * 0.5;
+        draw.softness = softness;
+        draw.offset = offset;
+        self.draws.push(draw);
+        self
+    }
+}
+
+impl TextStyle {
+    /// Get the color of the text itself, not including any outline, glow or
+    /// shadow.
+    #[must_use]
+    pub fn color(&self) -> Color {
+        self.draws
+            .iter()
+            .find(|draw| draw.layer == Layer::Primary)
+            .map_or(Color::WHITE, |draw| draw.color)
+    }
+
+    /// Select the primary face for this style, followed by its fallbacks.
+    fn faces(&self) -> Vec<registry::Face> {
+        let mut faces = vec![registry::resolve(
+            self.family.as_deref(),
+            self.weight,
+            self.italic,
+        )];
+        for family in &self.fallback {
+            if let Some(face) =
+                registry::find_face(family, self.weight, self.italic)
+            {
+                if faces.iter().all(|f| !Rc::ptr_eq(&f.font, &face.font)) {
+                    faces.push(face);
+                }
+            }
+        }
+        faces
+    }
+
+    /// Resolve the rendering parameters of each draw for a face.
+    fn face_draws(&self, face: &registry::Face) -> Vec<FaceDraw<'_>> {
+        let aa_smoothing =
+            self.font_size * (2.0 * face.font.data.padding_ratio);
+        self.draws
+            .iter()
+            .map(|draw| {
+                let smoothing = if !draw.smoothing.is_nan() {
+                    draw.smoothing
+                } else if draw.softness > 0.0 {
+                    aa_smoothing / draw.softness
+                } else {
+                    aa_smoothing
+                };
+                let mut midpoint = draw.midpoint - draw.dilate / aa_smoothing;
+                if self.weight.is_bold() && !face.weight.is_bold() {
+                    midpoint -= BOLD_MIDPOINT_SHIFT;
+                }
+                let midpoint = midpoint.max(0.0);
+                let config = VertexConfig::new()
+                    .alpha_base(midpoint - 0.5 / smoothing)
+                    .alpha_peak(draw.peak);
+                (draw, draw.color.rgba8(), config, smoothing)
+            })
+            .collect()
+    }
+}
+
 impl crate::platform::graphics::TextStyle for TextStyle {
     fn with_size_and_color(size: f32, color: Color) -> Self {
+        #[allow(deprecated)]
         Self {
             font_size: size,
+            family: None,
+            weight: FontWeight::REGULAR,
+            italic: false,
+            fallback: Vec::new(),
             font: 0,
-            draws: vec![Draw {
-                layer: Layer::Primary,
-                color,
-                midpoint: 0.5,
-                peak: 1.0,
-                smoothing: f32::NAN,
-            }],
+            draws: vec![Draw::new(Layer::Primary, color)],
         }
     }
 
@@ -75,36 +251,158 @@ impl crate::platform::graphics::TextStyle for TextStyle {
         &self,
         tag: &mut &str,
     ) -> Result<Self, text::RichTextTagParseError> {
-        Err(text::RichTextTagParseError {
-            msg: format!("unknown tag: {tag}"),
+        parse_tag(self, tag).map_err(|(msg, at)| {
+            *tag = at;
+            text::RichTextTagParseError { msg, position: 0 }
         })
     }
 }
 
+fn parse_tag<'a>(
+    base: &TextStyle,
+    source: &'a str,
+) -> Result<TextStyle, (String, &'a str)> {
+    let (name, value) = match source.split_once('=') {
+        Some((name, value)) => (name.trim(), Some(value.trim())),
+        None => (source.trim(), None),
+    };
+    let mut style = base.clone();
+    match (name, value) {
+        ("b", None) => {
+            style.weight = FontWeight::BOLD;
+        }
+        ("i", None) => {
+            style.italic = true;
+        }
+        ("color", Some(value)) => {
+            let color: Color = value
+                .parse()
+                .map_err(|_| (format!("invalid color: {value}"), value))?;
+            for draw in &mut style.draws {
+                if draw.layer == Layer::Primary {
+                    draw.color = color;
+                }
+            }
+        }
+        ("size", Some(value)) => {
+            style.font_size = value
+                .parse()
+                .ok()
+                .filter(|size: &f32| size.is_finite() && *size > 0.0)
+                .ok_or_else(|| {
+                    (format!("invalid font size: {value}"), value)
+                })?;
+        }
+        ("font", Some(value)) => {
+            if value.is_empty() {
+                return Err(("missing font family".to_string(), value));
+            }
+            style.family = Some(value.into());
+        }
+        _ => {
+            return Err((format!("unknown tag: {source}"), source));
+        }
+    }
+    Ok(style)
+}
+
 /// Default Graphic for displaying Text.
 ///
 /// This implementation is based on a signed distance field font atlas, these
 /// fonts can be generated using the crate `suzy_build_tools`.
 #[derive(Default)]
 pub struct Text {
-    fonts: Vec<Rc<font::Font>>,
     vertices: Vec<VertexSet>,
     layout_changed: WatchedMeta<'static>,
     calc: FontCharCalc,
+    /// The index each span starts at, and its style.
+    spans: Vec<(usize, TextStyle)>,
 }
 
 impl Text {
-    fn finish_line(&mut self) {
+    /// Get the text of all the spans pushed since the last call to
+    /// `clear`.
+    #[must_use]
+    pub fn content(&self) -> &str {
+        self.calc.text()
+    }
+
+    /// Get the layout the text is arranged with.
+    #[must_use]
+    pub fn layout(&self) -> text::Layout {
+        self.calc.layout
+    }
+
+    /// Get the byte index each span starts at in [`content`](Self::content),
+    /// and its style.
+    pub fn spans(&self) -> impl Iterator<Item = (usize, &TextStyle)> {
+        self.spans.iter().map(|(start, style)| (*start, style))
+    }
+
+    /// Hide the text after a line, as described by the layout's overflow
+    /// mode.
+    fn truncate(&mut self, last_line: usize) {
+        let style =
+            calc::line_end_span(&self.spans, &self.calc.lines[last_line]);
+        let faces = style.faces();
+        let fonts: Vec<_> = faces.iter().map(|face| &face.font.data).collect();
+        let (glyphs, width) = FontCharCalc::ellipsis(&fonts, style.font_size);
+        let calc::Truncation {
+            hidden,
+            shift: [dx, dy],
+            ellipsis,
+        } = self.calc.truncate(last_line, width);
         for vs in &mut self.vertices {
-            self.calc.align_line(
-                vs.vertices
-                    .iter_mut()
-                    .skip(vs.line_start_index)
-                    .map(|v| &mut v.xy),
-            );
-            vs.line_start_index = vs.vertices.len();
+            let indices = vs.indices.unsorted();
+            let mut kept = 0;
+            for quad in 0..vs.clusters.len() {
+                let cluster = vs.clusters[quad];
+                if hidden.contains(&cluster) {
+                    continue;
+                }
+                let vertices = quad * 4..quad * 4 + 4;
+                if cluster >= hidden.end {
+                    for vertex in &mut vs.vertices[vertices.clone()] {
+                        vertex.xy[0] += dx;
+                        vertex.xy[1] += dy;
+                    }
+                }
+                vs.vertices.copy_within(vertices, kept * 4);
+                vs.clusters[kept] = cluster;
+                indices[kept] = indices[quad];
+                kept += 1;
+            }
+            vs.vertices.truncate(kept * 4);
+            vs.clusters.truncate(kept);
+            indices.truncate(kept);
+        }
+        if let Some(position) = ellipsis {
+            let mut renderer = SpanRenderer::new(style, &faces);
+            for mut glyph in glyphs {
+                glyph.translate(position);
+                glyph.index = hidden.start;
+                renderer.push(&mut self.vertices, glyph);
+            }
         }
-        self.calc.reset_line();
+    }
+}
+
+#[cfg(feature = "platform-software")]
+impl Text {
+    /// The texture, vertices and indices of each set of glyphs, for render
+    /// platforms which don't batch draws.
+    pub(in crate::platforms) fn meshes(
+        &mut self,
+    ) -> impl Iterator<Item = (&Texture, &[Vertex<u16>], &[u16])> {
+        self.vertices.iter_mut().map(|vs| {
+            let VertexSet {
+                texture,
+                vertices,
+                indices,
+                ..
+            } = vs;
+            (&*texture, &vertices[..], &indices.make_final()[..])
+        })
     }
 }
 
@@ -155,93 +453,320 @@ impl crate::platform::graphics::Text<TextStyle> for Text {
         self.layout_changed.watched_auto();
         self.calc.reset();
         self.vertices.clear();
+        self.spans.clear();
     }
 
     fn push_span(&mut self, style: TextStyle, text: &str) {
-        self.calc.cursor.font_size = style.font_size;
-        let font_clone = self
-            .fonts
-            .get(style.font)
-            .cloned()
-            .unwrap_or_else(default_font::default_font);
-        let font = font_clone.as_ref();
-        let aa_smoothing = style.font_size * (2.0 * font.data.padding_ratio);
-        let draws = &style.draws;
-        let texture = font.data.texture.clone();
-        let vertex_set_index = self
-            .vertices
-            .iter_mut()
-            .enumerate()
-            .find_map(|(idx, vs)| {
-                (vs.texture.id() == texture.id()).then_some(idx)
-            })
-            .unwrap_or_else(|| {
-                let idx = self.vertices.len();
-                let vs = VertexSet {
-                    texture,
-                    vertices: Vec::new(),
-                    indices: IndicesState::Unsorted(Vec::new()),
-                    line_start_index: 0,
-                    bounding_box: None,
-                };
-                self.vertices.push(vs);
-                idx
-            });
-        let mut remaining = text;
-        while !remaining.is_empty() {
-            let vertex_set = &mut self.vertices[vertex_set_index];
-            let vertices = &mut vertex_set.vertices;
-            let indices = vertex_set.indices.unsorted();
-            let params = CalcParams {
-                font: &font.data,
-                handle_glyph: |glyph: calc::GlyphMetrics| {
-                    for draw in draws {
-                        let color = draw.color.rgba8();
-                        let smoothing = if draw.smoothing.is_nan() {
-                            aa_smoothing
-                        } else {
-                            draw.smoothing
-                        };
-                        let base = draw.midpoint - 0.5 / smoothing;
-                        let config = VertexConfig::new()
-                            .alpha_base(base)
-                            .alpha_peak(draw.peak);
-                        let left = (glyph.bb_left, glyph.tex_left);
-                        let right = (glyph.bb_right, glyph.tex_right);
-                        let bottom = (glyph.bb_bottom, glyph.tex_bottom);
-                        let top = (glyph.bb_top, glyph.tex_top);
-                        indices.push(draw.layer);
-                        for (y, v) in [bottom, top] {
-                            for (x, u) in [left, right] {
-                                vertices.push(Vertex {
-                                    xy: [x, y],
-                                    uv: [u, v],
-                                    color,
-                                    config,
-                                    smoothing,
-                                });
-                            }
-                        }
-                    }
-                },
-            };
-            let (consumed, line_break) =
-                self.calc.push_span(params, remaining);
-            remaining = &remaining[consumed..];
-            if line_break {
-                self.finish_line();
-            }
-        }
+        let faces = style.faces();
+        let fonts: Vec<_> = faces.iter().map(|face| &face.font.data).collect();
+        self.spans.push((self.calc.text_len(), style.clone()));
+        let mut sink = VertexSink {
+            vertex_sets: &mut self.vertices,
+            renderer: Some(SpanRenderer::new(&style, &faces)),
+        };
+        self.calc
+            .lay_out_span(&fonts, style.font_size, text, &mut sink);
     }
 
     fn finish(&mut self) {
-        self.finish_line();
+        self.calc.end_text(&mut VertexSink {
+            vertex_sets: &mut self.vertices,
+            renderer: None,
+        });
+        if let Some(last_line) = self.calc.overflow_line() {
+            self.truncate(last_line);
+        }
+        let shifts = self.calc.resolve_lines();
         for vs in &mut self.vertices {
+            let quads = vs.vertices.chunks_mut(4).zip(&vs.clusters);
+            for (quad, index) in quads {
+                let dx = shifts
+                    .binary_search_by_key(index, |&(i, _)| i)
+                    .map_or(0.0, |pos| shifts[pos].1);
+                for vertex in quad {
+                    vertex.xy[0] += dx;
+                }
+            }
             self.calc
                 .align_block(vs.vertices.iter_mut().map(|v| &mut v.xy));
             vs.indices.make_final();
         }
+        self.calc.align_block_stops();
+    }
+
+    fn hit_test(&self, point: [f32; 2]) -> Option<text::TextHit> {
+        self.calc.hit_test(point)
     }
+
+    fn caret_rect(&self, index: usize) -> Option<SimpleRect> {
+        let (x, line) = self.calc.caret(index)?;
+        Some(line_rect(x, x, line))
+    }
+
+    fn selection_rects(&self, range: Range<usize>) -> Vec<SimpleRect> {
+        self.calc
+            .selection(range)
+            .into_iter()
+            .map(|(left, right, line)| line_rect(left, right, line))
+            .collect()
+    }
+
+    fn line_metrics(&self) -> &[text::LineMetrics] {
+        &self.calc.lines
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.calc.truncated
+    }
+
+    fn bounds(&self) -> Option<SimpleRect> {
+        lines_bounds(&self.calc.lines)
+    }
+
+    fn measure<'a, I>(&self, layout: text::Layout, spans: I) -> [f32; 2]
+    where
+        I: IntoIterator<Item = (TextStyle, &'a str)>,
+    {
+        let spans: Vec<_> = spans.into_iter().collect();
+        let faces: Vec<_> =
+            spans.iter().map(|(style, _)| style.faces()).collect();
+        let fonts: Vec<Vec<_>> = faces
+            .iter()
+            .map(|faces| faces.iter().map(|face| &face.font.data).collect())
+            .collect();
+        let spans: Vec<_> = spans
+            .iter()
+            .zip(&fonts)
+            .map(|((style, text), fonts)| (&fonts[..], style.font_size, *text))
+            .collect();
+        let calc = FontCharCalc::lay_out_spans(layout, &spans);
+        lines_bounds(&calc.lines)
+            .map_or([0.0, 0.0], |rect| [rect.width(), rect.height()])
+    }
+}
+
+/// The resolved fonts and draws of a style, used to add its glyphs to the
+/// vertex sets.
+struct SpanRenderer<'a> {
+    faces: &'a [registry::Face],
+    face_draws: Vec<Vec<FaceDraw<'a>>>,
+    skews: Vec<f32>,
+    tofu_colors: Vec<[u8; 4]>,
+    tofu_stroke: f32,
+    /// The vertex set for each face and texture page, found as glyphs use
+    /// them, so textures which aren't needed aren't loaded.  Faces are
+    /// numbered by their index, with one more for the replacement glyph.
+    sets: HashMap<(usize, usize), usize>,
+}
+
+impl<'a> SpanRenderer<'a> {
+    fn new(style: &'a TextStyle, faces: &'a [registry::Face]) -> Self {
+        Self {
+            faces,
+            face_draws: faces
+                .iter()
+                .map(|face| style.face_draws(face))
+                .collect(),
+            skews: faces
+                .iter()
+                .map(|face| {
+                    if style.italic && !face.italic {
+                        ITALIC_SKEW
+                    } else {
+                        0.0
+                    }
+                })
+                .collect(),
+            tofu_colors: style
+                .draws
+                .iter()
+                .filter(|draw| draw.layer == Layer::Primary)
+                .map(|draw| draw.color.rgba8())
+                .collect(),
+            tofu_stroke: style.font_size * TOFU_STROKE,
+            sets: HashMap::new(),
+        }
+    }
+
+    fn push(
+        &mut self,
+        vertex_sets: &mut Vec<VertexSet>,
+        glyph: calc::GlyphMetrics,
+    ) {
+        let slot = glyph.face.unwrap_or(self.faces.len());
+        let faces = self.faces;
+        let set = *self.sets.entry((slot, glyph.page)).or_insert_with(|| {
+            let texture = match faces.get(slot) {
+                Some(face) => face
+                    .font
+                    .data
+                    .page_texture(glyph.page)
+                    .unwrap_or_else(|| face.font.data.texture.clone()),
+                None => Texture::solid_color(),
+            };
+            vertex_set_index(vertex_sets, &texture)
+        });
+        let vertex_set = &mut vertex_sets[set];
+        let vertices = &mut vertex_set.vertices;
+        let indices = vertex_set.indices.unsorted();
+        if let Some(face) = glyph.face {
+            push_glyph(
+                vertices,
+                indices,
+                &glyph,
+                self.skews[face],
+                &self.face_draws[face],
+            );
+        } else {
+            push_tofu(
+                vertices,
+                indices,
+                &glyph,
+                self.tofu_stroke,
+                &self.tofu_colors,
+            );
+        }
+        let quads = vertices.len() / 4;
+        vertex_set.clusters.resize(quads, glyph.index);
+    }
+}
+
+/// Adds the glyphs of a span to the vertex sets as they are laid out, and
+/// moves each line down to its baseline as it ends.
+struct VertexSink<'a, 'b> {
+    vertex_sets: &'a mut Vec<VertexSet>,
+    renderer: Option<SpanRenderer<'b>>,
+}
+
+impl calc::LayoutSink for VertexSink<'_, '_> {
+    fn glyph(&mut self, glyph: calc::GlyphMetrics) {
+        if let Some(renderer) = &mut self.renderer {
+            renderer.push(self.vertex_sets, glyph);
+        }
+    }
+
+    fn end_line(&mut self, calc: &FontCharCalc) {
+        for vs in self.vertex_sets.iter_mut() {
+            calc.align_line(
+                vs.vertices
+                    .iter_mut()
+                    .skip(vs.line_start_index)
+                    .map(|v| &mut v.xy),
+            );
+            vs.line_start_index = vs.vertices.len();
+        }
+    }
+}
+
+/// Find the vertex set drawing with a texture, adding one if there isn't
+/// one yet.
+fn vertex_set_index(sets: &mut Vec<VertexSet>, texture: &Texture) -> usize {
+    let id = texture.id();
+    sets.iter()
+        .position(|vs| vs.texture.id() == id)
+        .unwrap_or_else(|| {
+            sets.push(VertexSet {
+                texture: texture.clone(),
+                vertices: Vec::new(),
+                indices: IndicesState::Unsorted(Vec::new()),
+                clusters: Vec::new(),
+                line_start_index: 0,
+                bounding_box: None,
+            });
+            sets.len() - 1
+        })
+}
+
+/// Draw a glyph once for each of a style's draws.
+fn push_glyph(
+    vertices: &mut Vec<Vertex<u16>>,
+    indices: &mut Vec<Layer>,
+    glyph: &calc::GlyphMetrics,
+    skew: f32,
+    draws: &[FaceDraw<'_>],
+) {
+    for &(draw, color, config, smoothing) in draws {
+        let [dx, dy] = draw.offset;
+        let left = (glyph.bb_left, glyph.tex_left);
+        let right = (glyph.bb_right, glyph.tex_right);
+        let bottom = (glyph.bb_bottom, glyph.tex_bottom);
+        let top = (glyph.bb_top, glyph.tex_top);
+        indices.push(draw.layer);
+        for (y, v) in [bottom, top] {
+            let shear = (y - glyph.baseline) * skew;
+            for (x, u) in [left, right] {
+                vertices.push(Vertex {
+                    xy: [x + shear + dx, y + dy],
+                    uv: [u, v],
+                    color,
+                    config,
+                    smoothing,
+                });
+            }
+        }
+    }
+}
+
+/// Draw the replacement glyph for a character no font has: a hollow box,
+/// drawn with the solid color texture.
+fn push_tofu(
+    vertices: &mut Vec<Vertex<u16>>,
+    indices: &mut Vec<Layer>,
+    glyph: &calc::GlyphMetrics,
+    stroke: f32,
+    colors: &[[u8; 4]],
+) {
+    let calc::GlyphMetrics {
+        bb_left: left,
+        bb_right: right,
+        bb_bottom: bottom,
+        bb_top: top,
+        ..
+    } = *glyph;
+    let edges = [
+        [left, left + stroke, bottom, top],
+        [right - stroke, right, bottom, top],
+        [left, right, bottom, bottom + stroke],
+        [left, right, top - stroke, top],
+    ];
+    let config = VertexConfig::new().alpha_base(0.0);
+    for &color in colors {
+        for [l, r, b, t] in edges {
+            indices.push(Layer::Primary);
+            for y in [b, t] {
+                for x in [l, r] {
+                    vertices.push(Vertex {
+                        xy: [x, y],
+                        uv: [1, 1],
+                        color,
+                        config,
+                        smoothing: 1.0,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn lines_bounds(lines: &[text::LineMetrics]) -> Option<SimpleRect> {
+    let first = lines.first()?;
+    let last = lines.last()?;
+    let (left, right) = lines
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(l, r), line| {
+            (l.min(line.left), r.max(line.right))
+        });
+    let mut rect = SimpleRect::default();
+    rect.set_horizontal_stretch(left, right);
+    rect.set_vertical_stretch(last.bottom(), first.top());
+    Some(rect)
+}
+
+fn line_rect(left: f32, right: f32, line: &text::LineMetrics) -> SimpleRect {
+    let mut rect = SimpleRect::default();
+    rect.set_horizontal_stretch(left, right);
+    rect.set_vertical_stretch(line.bottom(), line.top());
+    rect
 }
 
 #[derive(Clone, Debug)]
@@ -292,6 +817,8 @@ struct VertexSet {
     texture: Texture,
     vertices: Vec<Vertex<u16>>,
     indices: IndicesState,
+    /// The index of the grapheme cluster each quad is part of.
+    clusters: Vec<usize>,
     line_start_index: usize,
     bounding_box: Option<BoundingBox>,
 }
@@ -307,3 +834,183 @@ impl<T> ExpectU16Len for Vec<T> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::OnceCell;
+
+    use super::*;
+    use crate::platform::graphics::{Text as _, TextStyle as _};
+
+    const FAMILY: &str = "text-effects-test";
+
+    /// Register a font with a single glyph, `a`, which is one em square, in
+    /// a registry made current for as long as it is kept.
+    fn register_test_font() -> Rc<FontRegistry> {
+        let glyph = font::Glyph {
+            ch: 'a',
+            advance: 1.0,
+            bb_left: 0.0,
+            bb_right: 1.0,
+            bb_bottom: 0.0,
+            bb_top: 1.0,
+            tex_left: 0,
+            tex_right: 0,
+            tex_bottom: 0,
+            tex_top: 0,
+        };
+        let data = font::FontData {
+            texture: Texture::solid_color(),
+            padding_ratio: 0.1,
+            glyphs: Box::new([glyph]),
+            kerning: Box::new([]),
+            line_spacing: 1.2,
+            ascent: 0.8,
+            capline: 0.7,
+            descent: -0.2,
+            atlas_kind: AtlasKind::Sdf,
+            source: None,
+        };
+        let font = Font {
+            data,
+            bold: OnceCell::new(),
+            italic: OnceCell::new(),
+        };
+        let registry = Rc::new(FontRegistry::default());
+        registry.register_font(
+            FAMILY,
+            FontWeight::REGULAR,
+            false,
+            Rc::new(font),
+        );
+        FontRegistry::make_current(&registry);
+        registry
+    }
+
+    fn style() -> TextStyle {
+        let mut style = TextStyle::with_size_and_color(10.0, Color::WHITE);
+        style.family = Some(FAMILY.into());
+        style
+    }
+
+    /// Lay out the glyph `a`, and get the vertices of each quad, in the
+    /// order they are drawn.
+    fn quads(style: TextStyle) -> Vec<[Vertex<u16>; 4]> {
+        let _fonts = register_test_font();
+        let mut text = Text::default();
+        text.push_span(style, "a");
+        text.finish();
+        let vs = text.vertices.first_mut().expect("text should have a mesh");
+        let vertices = &vs.vertices;
+        vs.indices
+            .make_final()
+            .chunks_exact(6)
+            .map(|quad| {
+                let bl = usize::from(quad[0]);
+                [
+                    vertices[bl],
+                    vertices[bl + 1],
+                    vertices[bl + 2],
+                    vertices[bl + 3],
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn shadow_is_drawn_before_text() {
+        let style = style().with_shadow(Color::BLACK, [3.0, -2.0], 0.0);
+        let quads = quads(style);
+        let colors: Vec<_> = quads.iter().map(|quad| quad[0].color).collect();
+        assert_eq!(colors, [Color::BLACK.rgba8(), Color::WHITE.rgba8()]);
+    }
+
+    #[test]
+    fn shadow_offset_moves_quads() {
+        let style = style().with_shadow(Color::BLACK, [3.0, -2.0], 0.0);
+        let [shadow, text] = quads(style)[..] else {
+            panic!("expected a shadow quad and a text quad");
+        };
+        for (shadow, text) in shadow.iter().zip(&text) {
+            let [x, y] = text.xy;
+            assert_eq!(shadow.xy, [x + 3.0, y - 2.0]);
+        }
+    }
+
+    #[test]
+    fn outline_lowers_alpha_base() {
+        let style = style().with_outline(Color::BLACK, 2.0);
+        let [outline, text] = quads(style)[..] else {
+            panic!("expected an outline quad and a text quad");
+        };
+        assert_eq!(outline[0].color, Color::BLACK.rgba8());
+        // the base is stored inverted, so a lower base is a larger value
+        let [outline_base, ..] = outline[0].config.0;
+        let [text_base, ..] = text[0].config.0;
+        assert!(outline_base > text_base);
+    }
+
+    /// Parse markup with a single tag around `x`, and get the style of `x`.
+    fn tagged(markup: &str) -> TextStyle {
+        let spans =
+            text::parse_rich(style(), markup).expect("markup should parse");
+        let [(style, "x")] = &spans[..] else {
+            panic!("expected a single span of `x`, found {:?}", spans);
+        };
+        style.clone()
+    }
+
+    /// Parse markup which should fail, and get the error's position.
+    fn tag_error(markup: &str) -> usize {
+        text::parse_rich(style(), markup)
+            .expect_err("markup should not parse")
+            .position
+    }
+
+    #[test]
+    fn parse_tag_bold_and_italic() {
+        let bold = tagged("<b>x</b>");
+        assert_eq!(bold.weight, FontWeight::BOLD);
+        assert!(!bold.italic);
+        let italic = tagged("<i>x</i>");
+        assert_eq!(italic.weight, FontWeight::REGULAR);
+        assert!(italic.italic);
+    }
+
+    #[test]
+    fn parse_tag_color_keeps_effect_colors() {
+        let base = style().with_shadow(Color::BLACK, [1.0, -1.0], 0.0);
+        let spans = text::parse_rich(base, "<color=#ff0000>x</color>")
+            .expect("markup should parse");
+        let style = &spans[0].0;
+        assert_eq!(style.color(), Color::RED);
+        let shadow = style.draws.iter().find(|d| d.layer != Layer::Primary);
+        assert_eq!(shadow.map(|d| d.color), Some(Color::BLACK));
+        assert_eq!(tagged("<color = blue>x</>").color(), Color::BLUE);
+    }
+
+    #[test]
+    fn parse_tag_size_and_font() {
+        let sized = tagged("<size=24.5>x</size>");
+        assert_eq!(sized.font_size, 24.5);
+        let family = tagged("<font=Roboto>x</font>");
+        assert_eq!(family.family.as_deref(), Some("Roboto"));
+        assert_eq!(family.font_size, style().font_size);
+    }
+
+    #[test]
+    fn parse_tag_rejects_malformed_values() {
+        // errors point at the value when there is one
+        assert_eq!(tag_error("<color=nope>x</color>"), 7);
+        assert_eq!(tag_error("<color=#ff00>x</color>"), 7);
+        assert_eq!(tag_error("<size=big>x</size>"), 6);
+        assert_eq!(tag_error("<size=-3>x</size>"), 6);
+        assert_eq!(tag_error("<size=NaN>x</size>"), 6);
+        assert_eq!(tag_error("<size=0>x</size>"), 6);
+        assert_eq!(tag_error("a<font=>x</font>"), 7);
+        // and otherwise at the tag
+        assert_eq!(tag_error("a<u>x</u>"), 2);
+        assert_eq!(tag_error("<b=1>x</b>"), 1);
+        assert_eq!(tag_error("<color>x</color>"), 1);
+    }
+}
@@ -0,0 +1,478 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Loading for the binary font asset format written by
+//! `suzy_build_tools::fontasset::FontAtlas::write_asset`.
+
+use std::{
+    cell::OnceCell,
+    collections::hash_map::DefaultHasher,
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    io::{self, Read},
+    rc::Rc,
+};
+
+use crate::platforms::opengl::{self, PopulateTexture, Texture};
+
+use super::font::{AtlasKind, Font, FontData, Glyph, KerningPair};
+
+const MAGIC: &[u8; 8] = b"SUZYFONT";
+const VERSION: u16 = 2;
+
+// sizes of the fixed-length records, used to reject impossible counts
+// before allocating
+const GLYPH_RECORD_LEN: usize = 4 + 5 * 4 + 4 * 2;
+const KERNING_RECORD_LEN: usize = 4 + 4 + 4;
+
+/// An error encountered while loading a binary font asset.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FontLoadError {
+    /// Reading the asset failed.
+    Io(io::Error),
+    /// The data does not start with the font asset header.
+    NotAFontAsset,
+    /// The asset was written with a format version this version of Suzy
+    /// does not understand.
+    UnsupportedVersion(u16),
+    /// The asset ended before all of its data was read.
+    Truncated,
+    /// The asset contains an invalid value.
+    Invalid(&'static str),
+}
+
+impl fmt::Display for FontLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read font asset: {err}"),
+            Self::NotAFontAsset => f.write_str("data is not a font asset"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported font asset version: {version}")
+            }
+            Self::Truncated => f.write_str("font asset is truncated"),
+            Self::Invalid(msg) => write!(f, "invalid font asset: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FontLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FontLoadError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Font {
+    /// Load the first font from a binary font asset.
+    ///
+    /// Font assets can be generated using the crate `suzy_build_tools`.
+    pub fn load_from_bytes(data: &[u8]) -> Result<Rc<Self>, FontLoadError> {
+        let mut faces = Self::load_faces_from_bytes(data)?;
+        // load_faces_from_bytes ensures there is at least one face
+        Ok(faces.swap_remove(0).1)
+    }
+
+    /// Load the first font from a binary font asset, read to the end of
+    /// `reader`.
+    pub fn from_reader(
+        mut reader: impl Read,
+    ) -> Result<Rc<Self>, FontLoadError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Self::load_from_bytes(&data)
+    }
+
+    /// Load every font from a binary font asset, along with the names they
+    /// were given when the asset was built.
+    ///
+    /// All the fonts in an asset share a single texture.
+    pub fn load_faces_from_bytes(
+        data: &[u8],
+    ) -> Result<Vec<(String, Rc<Self>)>, FontLoadError> {
+        let mut reader = AssetReader { data };
+        if reader.bytes(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+            return Err(FontLoadError::NotAFontAsset);
+        }
+        let version = reader.u16()?;
+        if version == 0 || version > VERSION {
+            return Err(FontLoadError::UnsupportedVersion(version));
+        }
+        let width = reader.u16()?;
+        let height = reader.u16()?;
+        if width == 0 || height == 0 {
+            return Err(FontLoadError::Invalid("empty texture"));
+        }
+        // version 1 assets are always single-channel
+        let channels = if version >= 2 { reader.u16()? } else { 1 };
+        let atlas_kind = match channels {
+            1 => AtlasKind::Sdf,
+            3 => AtlasKind::Msdf,
+            _ => {
+                return Err(FontLoadError::Invalid(
+                    "unsupported texture channel count",
+                ))
+            }
+        };
+        let face_count = reader.u16()?;
+        if face_count == 0 {
+            return Err(FontLoadError::Invalid("no fonts in asset"));
+        }
+        let faces = (0..face_count)
+            .map(|_| reader.face(width, height, atlas_kind))
+            .collect::<Result<Vec<_>, _>>()?;
+        let data_len = reader.len_u32()?;
+        let pixels = usize::from(width) * usize::from(height);
+        if data_len != pixels * usize::from(channels) {
+            return Err(FontLoadError::Invalid(
+                "texture data does not match the texture size",
+            ));
+        }
+        let texture_data = reader.bytes(data_len)?;
+        if !reader.data.is_empty() {
+            return Err(FontLoadError::Invalid(
+                "unexpected data after texture",
+            ));
+        }
+        let texture = Texture::new(Rc::new(AssetPopulator::new(
+            width,
+            height,
+            atlas_kind,
+            texture_data,
+        )));
+        Ok(faces
+            .into_iter()
+            .map(|(name, mut data)| {
+                data.texture = texture.clone();
+                let font = Font {
+                    data,
+                    bold: OnceCell::new(),
+                    italic: OnceCell::new(),
+                };
+                (name, Rc::new(font))
+            })
+            .collect())
+    }
+}
+
+struct AssetReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> AssetReader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], FontLoadError> {
+        if self.data.len() < len {
+            return Err(FontLoadError::Truncated);
+        }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], FontLoadError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    fn u16(&mut self) -> Result<u16, FontLoadError> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, FontLoadError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    fn len_u32(&mut self) -> Result<usize, FontLoadError> {
+        usize::try_from(self.u32()?).map_err(|_| FontLoadError::Truncated)
+    }
+
+    /// Read a count of records, checking that enough data remains for them.
+    fn count(&mut self, record_len: usize) -> Result<usize, FontLoadError> {
+        let count = self.len_u32()?;
+        match count.checked_mul(record_len) {
+            Some(len) if len <= self.data.len() => Ok(count),
+            _ => Err(FontLoadError::Truncated),
+        }
+    }
+
+    fn f32(&mut self) -> Result<f32, FontLoadError> {
+        let value = f32::from_le_bytes(self.array()?);
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(FontLoadError::Invalid("non-finite metric"))
+        }
+    }
+
+    fn char(&mut self) -> Result<char, FontLoadError> {
+        char::from_u32(self.u32()?)
+            .ok_or(FontLoadError::Invalid("invalid character"))
+    }
+
+    fn face(
+        &mut self,
+        width: u16,
+        height: u16,
+        atlas_kind: AtlasKind,
+    ) -> Result<(String, FontData), FontLoadError> {
+        let name_len = self.u16()?;
+        let name = std::str::from_utf8(self.bytes(name_len.into())?)
+            .map_err(|_| FontLoadError::Invalid("font name is not UTF-8"))?
+            .to_owned();
+        let padding_ratio = self.f32()?;
+        let line_spacing = self.f32()?;
+        let ascent = self.f32()?;
+        let descent = self.f32()?;
+        let capline = self.f32()?;
+        let _x_height = self.f32()?;
+        let glyph_count = self.count(GLYPH_RECORD_LEN)?;
+        let glyphs = (0..glyph_count)
+            .map(|_| self.glyph(width, height))
+            .collect::<Result<Box<[_]>, _>>()?;
+        if glyphs.windows(2).any(|pair| pair[0].ch >= pair[1].ch) {
+            return Err(FontLoadError::Invalid("glyphs are not sorted"));
+        }
+        let pair_count = self.count(KERNING_RECORD_LEN)?;
+        let kerning = (0..pair_count)
+            .map(|_| {
+                Ok(KerningPair {
+                    left: self.char()?,
+                    right: self.char()?,
+                    kerning: self.f32()?,
+                })
+            })
+            .collect::<Result<Box<[_]>, FontLoadError>>()?;
+        let key = |pair: &KerningPair| (pair.left, pair.right);
+        if kerning
+            .windows(2)
+            .any(|pair| key(&pair[0]) >= key(&pair[1]))
+        {
+            return Err(FontLoadError::Invalid("kerning is not sorted"));
+        }
+        let data = FontData {
+            // replaced once the texture data is read
+            texture: Texture::default(),
+            padding_ratio,
+            glyphs,
+            kerning,
+            line_spacing,
+            ascent,
+            capline,
+            descent,
+            atlas_kind,
+            source: None,
+        };
+        Ok((name, data))
+    }
+
+    fn glyph(
+        &mut self,
+        width: u16,
+        height: u16,
+    ) -> Result<Glyph, FontLoadError> {
+        let ch = self.char()?;
+        let advance = self.f32()?;
+        let bb_left = self.f32()?;
+        let bb_right = self.f32()?;
+        let bb_bottom = self.f32()?;
+        let bb_top = self.f32()?;
+        let tex_left = self.u16()?;
+        let tex_right = self.u16()?;
+        let tex_bottom = self.u16()?;
+        let tex_top = self.u16()?;
+        if tex_left > tex_right
+            || tex_right > width
+            || tex_bottom > tex_top
+            || tex_top > height
+        {
+            return Err(FontLoadError::Invalid("glyph outside the texture"));
+        }
+        Ok(Glyph {
+            ch,
+            advance,
+            bb_left,
+            bb_right,
+            bb_bottom,
+            bb_top,
+            tex_left,
+            tex_right,
+            tex_bottom,
+            tex_top,
+        })
+    }
+}
+
+struct AssetPopulator {
+    key: Vec<u8>,
+    width: u16,
+    height: u16,
+    kind: AtlasKind,
+    data: Box<[u8]>,
+}
+
+impl AssetPopulator {
+    fn new(width: u16, height: u16, kind: AtlasKind, data: &[u8]) -> Self {
+        let mut hasher = DefaultHasher::new();
+        (width, height, kind == AtlasKind::Msdf, data).hash(&mut hasher);
+        let mut key = b"suzy-font-asset:".to_vec();
+        key.extend_from_slice(&hasher.finish().to_le_bytes());
+        Self {
+            key,
+            width,
+            height,
+            kind,
+            data: data.into(),
+        }
+    }
+}
+
+impl PopulateTexture for AssetPopulator {
+    fn populate(
+        &self,
+        gl: &opengl::OpenGlBindings,
+        target: opengl::opengl_bindings::types::GLenum,
+    ) -> Result<opengl::TextureSize, String> {
+        Ok(Font::populate_atlas(
+            gl,
+            target,
+            [self.width, self.height],
+            &self.data,
+            self.kind,
+        ))
+    }
+
+    fn populate_pixels(&self) -> Result<opengl::TexturePixels, String> {
+        Ok(Font::atlas_pixels(
+            [self.width, self.height],
+            &self.data,
+            self.kind,
+        ))
+    }
+
+    fn texture_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    fn get_known_size(&self) -> Option<[f32; 2]> {
+        Some([self.width.into(), self.height.into()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_asset(glyphs: &[char]) -> Vec<u8> {
+        sample_asset_with(glyphs, VERSION, 1)
+    }
+
+    fn sample_asset_with(
+        glyphs: &[char],
+        version: u16,
+        channels: u16,
+    ) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+        let u16s = |out: &mut Vec<u8>, values: &[u16]| {
+            for value in values {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+        };
+        u16s(&mut out, &[version, 2, 2]);
+        if version >= 2 {
+            u16s(&mut out, &[channels]);
+        }
+        u16s(&mut out, &[1, 4]);
+        out.extend_from_slice(b"test");
+        for metric in [0.2_f32, 1.0, 0.9, -0.2, 0.7, 0.5] {
+            out.extend_from_slice(&metric.to_le_bytes());
+        }
+        out.extend_from_slice(
+            &u32::try_from(glyphs.len()).unwrap_or(0).to_le_bytes(),
+        );
+        for &ch in glyphs {
+            out.extend_from_slice(&u32::from(ch).to_le_bytes());
+            for value in [0.5_f32, 0.0, 0.5, 0.0, 0.7] {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+            u16s(&mut out, &[0, 1, 0, 2]);
+        }
+        out.extend_from_slice(&1_u32.to_le_bytes());
+        out.extend_from_slice(&u32::from('A').to_le_bytes());
+        out.extend_from_slice(&u32::from('V').to_le_bytes());
+        out.extend_from_slice(&(-0.05_f32).to_le_bytes());
+        let pixels = [0, 64, 128, 255];
+        let data_len = pixels.len() * usize::from(channels);
+        out.extend_from_slice(
+            &u32::try_from(data_len).unwrap_or(0).to_le_bytes(),
+        );
+        for pixel in pixels {
+            out.extend(std::iter::repeat_n(pixel, channels.into()));
+        }
+        out
+    }
+
+    #[test]
+    fn load_font_asset() {
+        let faces = Font::load_faces_from_bytes(&sample_asset(&['A', 'V']))
+            .expect("asset should load");
+        assert_eq!(faces.len(), 1);
+        let (name, font) = &faces[0];
+        assert_eq!(name, "test");
+        assert_eq!(font.data.glyphs.len(), 2);
+        assert!(font.data.glyph('V').is_some());
+        assert_eq!(font.data.kerning('A', 'V'), Some(-0.05));
+        assert_eq!(font.data.ascent, 0.9);
+        assert_eq!(font.data.atlas_kind, AtlasKind::Sdf);
+    }
+
+    #[test]
+    fn load_atlas_kinds() {
+        let font = Font::load_from_bytes(&sample_asset_with(&['A'], 1, 1))
+            .expect("version 1 asset should load");
+        assert_eq!(font.data.atlas_kind, AtlasKind::Sdf);
+        let font = Font::load_from_bytes(&sample_asset_with(&['A'], 2, 3))
+            .expect("multi-channel asset should load");
+        assert_eq!(font.data.atlas_kind, AtlasKind::Msdf);
+        let err = Font::load_from_bytes(&sample_asset_with(&['A'], 2, 2))
+            .expect_err("two channels are not supported");
+        assert!(matches!(err, FontLoadError::Invalid(_)));
+    }
+
+    #[test]
+    fn reject_malformed_assets() {
+        let asset = sample_asset(&['A', 'V']);
+        for len in MAGIC.len()..asset.len() {
+            let err = Font::load_from_bytes(&asset[..len])
+                .expect_err("truncated asset should fail");
+            assert_eq!(
+                err.to_string(),
+                FontLoadError::Truncated.to_string(),
+                "at length {len}",
+            );
+        }
+        let mut trailing = asset.clone();
+        trailing.push(0);
+        assert!(Font::load_from_bytes(&trailing).is_err());
+        let mut bad_magic = asset.clone();
+        bad_magic[0] = b'X';
+        let err = Font::load_from_bytes(&bad_magic).expect_err("bad magic");
+        assert!(matches!(err, FontLoadError::NotAFontAsset));
+        let mut bad_version = asset;
+        bad_version[MAGIC.len()] = 99;
+        let err = Font::load_from_bytes(&bad_version).expect_err("version");
+        assert!(matches!(err, FontLoadError::UnsupportedVersion(99)));
+        let err = Font::load_from_bytes(&sample_asset(&['V', 'A']))
+            .expect_err("unsorted glyphs should fail");
+        assert!(matches!(err, FontLoadError::Invalid(_)));
+    }
+}
@@ -1,6 +1,9 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
+use unicode_bidi::BidiInfo;
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::text;
 
 use super::font;
@@ -11,6 +14,60 @@ pub(super) struct FontCharCalc {
     pub cursor: Cursor,
     pub first_line_line: f32,
     pub last_line_line: f32,
+    pub stops: Vec<CharStop>,
+    pub lines: Vec<text::LineMetrics>,
+    /// True if some of the text was hidden by the vertical limit.
+    pub truncated: bool,
+    /// All the text laid out so far, used to resolve its direction.
+    text: String,
+    line_start: usize,
+    line_extents: Option<(f32, f32)>,
+    prev_line_height: f32,
+    /// The value of `last_line_line` for each finished line.
+    line_lines: Vec<f32>,
+}
+
+/// The position of a grapheme cluster, or of the end of the text.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct CharStop {
+    pub index: usize,
+    /// The left edge of the cluster.
+    pub x: f32,
+    /// The right edge of the cluster.  Until the lines are resolved, this
+    /// is the same as `x`.
+    pub right: f32,
+    pub line: usize,
+    /// True if the cluster is displayed right-to-left, in which case the
+    /// caret before it is at its right edge.
+    pub rtl: bool,
+}
+
+impl CharStop {
+    /// The position of the caret before the cluster.
+    fn leading(&self) -> f32 {
+        if self.rtl {
+            self.right
+        } else {
+            self.x
+        }
+    }
+
+    /// The position of the caret after the cluster.
+    fn trailing(&self) -> f32 {
+        if self.rtl {
+            self.x
+        } else {
+            self.right
+        }
+    }
+
+    fn distance(&self, x: f32) -> f32 {
+        if x < self.x {
+            self.x - x
+        } else {
+            (x - self.right).max(0.0)
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -22,10 +79,96 @@ pub(super) struct Cursor {
 }
 
 pub(super) struct CalcParams<'a, F> {
-    pub font: &'a font::FontData,
+    /// The fonts to find glyphs in, in order of preference.  The first is
+    /// the primary font, which also determines the line metrics.
+    pub fonts: &'a [&'a font::FontData],
     pub handle_glyph: F,
 }
 
+/// Receives the glyphs of text as it is laid out.
+pub(super) trait LayoutSink {
+    /// Handle a glyph, positioned relative to the current line.
+    fn glyph(&mut self, glyph: GlyphMetrics);
+
+    /// Handle the end of a line, before the calc moves on to the next.
+    fn end_line(&mut self, _calc: &FontCharCalc) {}
+}
+
+impl LayoutSink for () {
+    fn glyph(&mut self, _glyph: GlyphMetrics) {}
+}
+
+/// How the text beyond the vertical limit was hidden.
+#[derive(Clone, Debug)]
+pub(super) struct Truncation {
+    /// The indices of the hidden clusters.  The clusters after them, if
+    /// any, have moved onto the last visible line.
+    pub hidden: std::ops::Range<usize>,
+    /// How far the clusters after the hidden text moved.
+    pub shift: [f32; 2],
+    /// The position of the start of the ellipsis on its baseline, if one
+    /// should be drawn.
+    pub ellipsis: Option<[f32; 2]>,
+}
+
+/// The advance of the replacement glyph, relative to the font size.
+const TOFU_ADVANCE: f32 = 0.6;
+
+/// The horizontal inset of the replacement glyph's box from its advance.
+const TOFU_INSET: f32 = 0.08;
+
+impl<F> CalcParams<'_, F> {
+    fn primary(&self) -> &font::FontData {
+        self.fonts[0]
+    }
+
+    /// Find the glyph for a character, along with the index of the font it
+    /// was found in and the page of that font's textures it is in.
+    ///
+    /// If no font has a glyph for a visible character, a replacement glyph
+    /// is returned with no font index.
+    fn glyph(&self, ch: char) -> Option<(Option<usize>, usize, font::Glyph)> {
+        let found = self.fonts.iter().enumerate().find_map(|(index, font)| {
+            let (glyph, page) = font.glyph_and_page(ch)?;
+            Some((Some(index), page, glyph))
+        });
+        if found.is_some() || ch.is_whitespace() || is_invisible(ch) {
+            return found;
+        }
+        let capline = self.primary().capline;
+        let top = if capline > 0.0 { capline } else { 0.7 };
+        Some((
+            None,
+            0,
+            font::Glyph {
+                ch,
+                advance: TOFU_ADVANCE,
+                bb_left: TOFU_INSET,
+                bb_right: TOFU_ADVANCE - TOFU_INSET,
+                bb_bottom: 0.0,
+                bb_top: top,
+                tex_left: 0,
+                tex_right: 0,
+                tex_bottom: 0,
+                tex_top: 0,
+            },
+        ))
+    }
+
+    /// Find the kerning between two characters, using the font the right
+    /// character was found in.
+    fn kerning(
+        &self,
+        face: Option<usize>,
+        left: Option<char>,
+        right: char,
+    ) -> f32 {
+        face.zip(left)
+            .and_then(|(face, left)| self.fonts[face].kerning(left, right))
+            .unwrap_or(0.0)
+    }
+}
+
 impl FontCharCalc {
     pub fn reset(&mut self) {
         self.cursor = Cursor {
@@ -36,20 +179,354 @@ impl FontCharCalc {
         };
         self.first_line_line = f32::NAN;
         self.last_line_line = f32::NAN;
+        self.stops.clear();
+        self.lines.clear();
+        self.truncated = false;
+        self.text.clear();
+        self.line_start = 0;
+        self.line_extents = None;
+        self.prev_line_height = 0.0;
+        self.line_lines.clear();
     }
 
-    pub fn align_line<'a>(
+    /// The length of all the text laid out so far.
+    pub fn text_len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// All the text laid out so far.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Lay out a span of text after the text laid out so far, finding its
+    /// glyphs in `fonts`.
+    pub fn lay_out_span(
         &mut self,
-        verts: impl Iterator<Item = &'a mut [f32; 2]>,
+        fonts: &[&font::FontData],
+        font_size: f32,
+        text: &str,
+        sink: &mut impl LayoutSink,
     ) {
-        let horiz_shift = match self.layout.alignment {
-            text::Alignment::Left => 0.0,
-            text::Alignment::Center => -self.cursor.x / 2.0,
-            text::Alignment::Right => -self.cursor.x,
+        self.cursor.font_size = font_size;
+        self.begin_span(fonts[0]);
+        let mut remaining = text;
+        while !remaining.is_empty() {
+            let params = CalcParams {
+                fonts,
+                handle_glyph: |glyph| sink.glyph(glyph),
+            };
+            let (consumed, line_break) = self.push_span(params, remaining);
+            remaining = &remaining[consumed..];
+            if line_break {
+                self.end_line(sink);
+            }
+        }
+    }
+
+    /// Finish the last line, after all the spans are laid out.
+    pub fn end_text(&mut self, sink: &mut impl LayoutSink) {
+        self.push_end_stop();
+        self.end_line(sink);
+    }
+
+    fn end_line(&mut self, sink: &mut impl LayoutSink) {
+        sink.end_line(self);
+        self.finish_line_stops();
+        self.reset_line();
+    }
+
+    /// Lay out spans of text without drawing them, as a text would with
+    /// the same layout and spans.
+    ///
+    /// Each span is given as the fonts to find its glyphs in, its font size
+    /// and its text.
+    pub fn lay_out_spans(
+        layout: text::Layout,
+        spans: &[(&[&font::FontData], f32, &str)],
+    ) -> Self {
+        let mut calc = Self {
+            layout,
+            ..Self::default()
         };
+        calc.reset();
+        let mut starts = Vec::with_capacity(spans.len());
+        for &(fonts, font_size, text) in spans {
+            starts.push((calc.text_len(), (fonts, font_size)));
+            calc.lay_out_span(fonts, font_size, text, &mut ());
+        }
+        calc.end_text(&mut ());
+        if let Some(last_line) = calc.overflow_line() {
+            let &(fonts, font_size) =
+                line_end_span(&starts, &calc.lines[last_line]);
+            let (_, width) = Self::ellipsis(fonts, font_size);
+            calc.truncate(last_line, width);
+        }
+        calc.resolve_lines();
+        calc
+    }
+
+    /// Record the vertical extents of a span's font for the current line.
+    pub fn begin_span(&mut self, font: &font::FontData) {
+        let size = self.cursor.font_size;
+        let (ascent, descent) = (font.ascent * size, font.descent * size);
+        self.line_extents = Some(match self.line_extents {
+            Some((prev_ascent, prev_descent)) => {
+                (prev_ascent.max(ascent), prev_descent.min(descent))
+            }
+            None => (ascent, descent),
+        });
+    }
+
+    fn push_stop(&mut self, index: usize, x: f32) {
+        let line = self.lines.len();
+        self.stops.push(CharStop {
+            index,
+            x,
+            right: x,
+            line,
+            rtl: false,
+        });
+    }
+
+    /// Record the caret position at the end of all the text pushed so far.
+    pub fn push_end_stop(&mut self) {
+        self.push_stop(self.text.len(), self.cursor.x);
+    }
+
+    /// Record the metrics of the current line.  This should be called
+    /// before `reset_line`.
+    pub fn finish_line_stops(&mut self) {
+        let line_height = self.cursor.current_line_height.max(0.0);
+        let line_height = if line_height > 0.0 {
+            line_height
+        } else {
+            self.prev_line_height
+        };
+        self.prev_line_height = line_height;
+        let (ascent, descent) = self
+            .line_extents
+            .take()
+            .or_else(|| self.lines.last().map(|l| (l.ascent, l.descent)))
+            .unwrap_or((0.0, 0.0));
+        self.lines.push(text::LineMetrics {
+            range: self.line_start..self.text.len(),
+            baseline: self.cursor.y - line_height,
+            ascent,
+            descent,
+            left: 0.0,
+            right: self.cursor.x,
+        });
+        self.line_start = self.text.len();
+    }
+
+    /// Place the clusters of each line horizontally, reordering runs of
+    /// mixed direction text as described by UAX #9, and applying the
+    /// alignment.  This should be called once, after the final line is
+    /// finished, and before `align_block_stops`.
+    ///
+    /// Returns how far each stop moved, by index, so that glyphs can be
+    /// moved with the cluster they belong to.
+    pub fn resolve_lines(&mut self) -> Vec<(usize, f32)> {
+        let bidi = BidiInfo::new(&self.text, None);
+        let has_rtl = bidi.has_rtl();
+        let text_len = self.text.len();
+        let mut shifts = Vec::with_capacity(self.stops.len());
+        let mut start = 0;
+        for (line_index, line) in self.lines.iter_mut().enumerate() {
+            let len = self.stops[start..]
+                .partition_point(|stop| stop.line == line_index);
+            let stops = &mut self.stops[start..start + len];
+            start += len;
+            let para = bidi
+                .paragraphs
+                .iter()
+                .find(|para| para.range.contains(&line.range.start))
+                .or_else(|| bidi.paragraphs.last());
+            let rtl_para = para.is_some_and(|para| para.level.is_rtl());
+            // the logical widths of each cluster
+            let widths: Vec<f32> = (0..stops.len())
+                .map(|i| {
+                    let next = stops.get(i + 1).map_or(line.right, |s| s.x);
+                    (next - stops[i].x).max(0.0)
+                })
+                .collect();
+            let mut order: Vec<usize> = Vec::with_capacity(stops.len());
+            let mut placed = vec![false; stops.len()];
+            match para {
+                Some(para) if has_rtl && !line.range.is_empty() => {
+                    let end = line.range.end.min(para.range.end);
+                    let (levels, runs) =
+                        bidi.visual_runs(para, line.range.start..end);
+                    for run in runs {
+                        let rtl = levels[run.start].is_rtl();
+                        let run_start = order.len();
+                        for (i, stop) in stops.iter_mut().enumerate() {
+                            if run.contains(&stop.index) {
+                                stop.rtl = rtl;
+                                placed[i] = true;
+                                order.push(i);
+                            }
+                        }
+                        if rtl {
+                            order[run_start..].reverse();
+                        }
+                    }
+                }
+                _ => {}
+            }
+            // stops outside the resolved runs, such as the end of the text,
+            // go at the end of the line in the paragraph's direction
+            let mut rest: Vec<usize> =
+                (0..stops.len()).filter(|&i| !placed[i]).collect();
+            for &i in &rest {
+                stops[i].rtl = rtl_para && stops[i].index >= text_len;
+            }
+            if rtl_para {
+                rest.reverse();
+                rest.append(&mut order);
+                order = rest;
+            } else {
+                order.append(&mut rest);
+            }
+            let alignment = match (self.layout.alignment, rtl_para) {
+                (text::Alignment::Start, false)
+                | (text::Alignment::End, true) => text::Alignment::Left,
+                (text::Alignment::Start, true)
+                | (text::Alignment::End, false) => text::Alignment::Right,
+                (alignment, _) => alignment,
+            };
+            let shift = match alignment {
+                text::Alignment::Center => -line.right / 2.0,
+                text::Alignment::Right => -line.right,
+                _ => 0.0,
+            };
+            let mut x = 0.0;
+            for i in order {
+                let stop = &mut stops[i];
+                let left = x + shift;
+                x += widths[i];
+                shifts.push((stop.index, left - stop.x));
+                stop.x = left;
+                stop.right = x + shift;
+            }
+            line.left += shift;
+            line.right += shift;
+        }
+        shifts.sort_unstable_by_key(|&(index, _)| index);
+        shifts
+    }
+
+    /// Find the caret position before the character at `index`.
+    pub fn caret(&self, index: usize) -> Option<(f32, &text::LineMetrics)> {
+        let pos = self.stop_position(index)?;
+        let stop = self.stops[pos];
+        Some((stop.leading(), self.lines.get(stop.line)?))
+    }
+
+    fn stop_position(&self, index: usize) -> Option<usize> {
+        self.stops
+            .binary_search_by_key(&index, |stop| stop.index)
+            .ok()
+    }
+
+    /// Get the stops which lie on a particular line.
+    fn line_stops(&self, line: usize) -> &[CharStop] {
+        let start = self.stops.partition_point(|stop| stop.line < line);
+        let end = self.stops.partition_point(|stop| stop.line <= line);
+        &self.stops[start..end]
+    }
+
+    /// Find the character nearest a point.
+    pub fn hit_test(&self, point: [f32; 2]) -> Option<text::TextHit> {
+        let [x, y] = point;
+        // lines are always recorded from top to bottom
+        let line_index = self
+            .lines
+            .iter()
+            .position(|line| y >= line.bottom())
+            .unwrap_or(self.lines.len().checked_sub(1)?);
+        let line = &self.lines[line_index];
+        let stops = self.line_stops(line_index);
+        let base = self.stop_position(stops.first()?.index)?;
+        let vert_inside = y <= line.top() && y >= line.bottom();
+        // the cluster under the point, or else the nearest one; a stop with
+        // no width, like a line break, wins a tie so that points beyond the
+        // end of a line hit the stop there
+        let has_width = |stop: &CharStop| stop.right > stop.x;
+        let (offset, stop) =
+            stops.iter().enumerate().min_by(|(_, a), (_, b)| {
+                a.distance(x)
+                    .total_cmp(&b.distance(x))
+                    .then(has_width(a).cmp(&has_width(b)))
+            })?;
+        let inside = vert_inside && x >= stop.x && x < stop.right;
+        let trailing =
+            (x - stop.trailing()).abs() < (x - stop.leading()).abs();
+        let (affinity, caret) = if trailing {
+            let after = self
+                .stops
+                .get(base + offset + 1)
+                .map_or(self.text.len(), |s| s.index);
+            (text::Affinity::Trailing, after)
+        } else {
+            (text::Affinity::Leading, stop.index)
+        };
+        Some(text::TextHit {
+            index: stop.index,
+            affinity,
+            caret,
+            inside,
+        })
+    }
+
+    /// Find the horizontal extents covered by a range of text on each
+    /// line.  Text of mixed direction may be covered by more than one
+    /// extent per line.
+    pub fn selection(
+        &self,
+        range: std::ops::Range<usize>,
+    ) -> Vec<(f32, f32, &text::LineMetrics)> {
+        let std::ops::Range { start, end } = range;
+        let mut extents = Vec::new();
+        for (line_index, line) in self.lines.iter().enumerate() {
+            if start >= end
+                || start >= line.range.end
+                || end <= line.range.start
+            {
+                continue;
+            }
+            let mut covered: Vec<(f32, f32)> = self
+                .line_stops(line_index)
+                .iter()
+                .filter(|stop| stop.index >= start && stop.index < end)
+                .map(|stop| (stop.x, stop.right))
+                .collect();
+            covered.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let mut covered = covered.into_iter();
+            let Some(mut current) = covered.next() else {
+                continue;
+            };
+            for (left, right) in covered {
+                if left <= current.1 {
+                    current.1 = current.1.max(right);
+                } else {
+                    extents.push((current.0, current.1, line));
+                    current = (left, right);
+                }
+            }
+            extents.push((current.0, current.1, line));
+        }
+        extents
+    }
+
+    /// Move the vertices of the current line down to its baseline.
+    pub fn align_line<'a>(
+        &self,
+        verts: impl Iterator<Item = &'a mut [f32; 2]>,
+    ) {
         let vert_shift = self.cursor.current_line_height.max(0.0);
-        for [x, y] in verts {
-            *x += horiz_shift;
+        for [_x, y] in verts {
             *y -= vert_shift;
         }
     }
@@ -63,12 +540,172 @@ impl FontCharCalc {
         self.cursor.y -= self.cursor.current_line_height;
         self.last_line_line -= self.cursor.current_line_height;
         self.cursor.current_line_height = 0.0;
+        self.line_lines.push(self.last_line_line);
     }
 
-    pub fn align_block<'a>(
+    /// Find the last line within the vertical limit, if there are lines
+    /// beyond it.  At least one line is always visible.
+    pub fn overflow_line(&self) -> Option<usize> {
+        let visible = match self.layout.vertical_limit {
+            text::VerticalLimit::None => return None,
+            text::VerticalLimit::Lines(count) => usize::from(count),
+            text::VerticalLimit::Height(height) => {
+                let top = self.lines.first()?.top();
+                self.lines
+                    .iter()
+                    .take_while(|line| top - line.bottom() <= height)
+                    .count()
+            }
+        };
+        let visible = visible.max(1);
+        (visible < self.lines.len()).then(|| visible - 1)
+    }
+
+    /// Lay out an ellipsis at the origin, using `…` if one of the fonts
+    /// has it, or three full stops otherwise.
+    ///
+    /// Returns the glyphs and the width of the ellipsis.
+    pub fn ellipsis(
+        fonts: &[&font::FontData],
+        font_size: f32,
+    ) -> (Vec<GlyphMetrics>, f32) {
+        let params = CalcParams {
+            fonts,
+            handle_glyph: |_glyph: GlyphMetrics| {},
+        };
+        let text = match params.glyph('\u{2026}') {
+            Some((Some(_), _, _)) => "\u{2026}",
+            _ => "...",
+        };
+        let mut cursor = Cursor {
+            font_size,
+            ..Cursor::default()
+        };
+        let mut glyphs = Vec::new();
+        Self::push_cluster(
+            &params,
+            &mut cursor,
+            (0, text),
+            &mut None,
+            &mut glyphs,
+        );
+        (glyphs, cursor.x)
+    }
+
+    /// Hide the lines after `last_line`.  This should be called after the
+    /// final line is finished, and before `resolve_lines`.
+    ///
+    /// Depending on the overflow mode, the last visible line may be cut
+    /// short to end with an ellipsis `ellipsis_width` wide, followed by
+    /// the end of the text if the middle of the text is hidden, all
+    /// fitting within the wrap width.
+    pub fn truncate(
         &mut self,
-        verts: impl Iterator<Item = &'a mut [f32; 2]>,
-    ) {
+        last_line: usize,
+        ellipsis_width: f32,
+    ) -> Truncation {
+        let mode = self.layout.overflow_mode;
+        let wrap_width = self.layout.wrap_width;
+        let line_end = self.lines[last_line].range.end;
+        let final_line = self.lines.len() - 1;
+        let final_right = self.lines[final_line].right;
+        // the stops moved onto the last visible line: the end of the text,
+        // and for middle truncation, as much of the final line as fits in
+        // half the space
+        let mut tail = self.stops.len() - 1;
+        if mode == text::OverflowMode::EllipsisMiddle {
+            let budget = (wrap_width - ellipsis_width) / 2.0;
+            while let Some(stop) = tail.checked_sub(1).map(|i| self.stops[i]) {
+                if stop.line != final_line
+                    || stop.index <= line_end
+                    || final_right - stop.x > budget
+                {
+                    break;
+                }
+                tail -= 1;
+            }
+        }
+        let tail_width = final_right - self.stops[tail].x;
+        let end = self.stops[tail].index;
+        let head_start = self.stops.partition_point(|s| s.line < last_line);
+        let head_end = self.stops.partition_point(|s| s.line <= last_line);
+        let start_at = |keep: usize| {
+            self.stops[head_start..head_end]
+                .get(keep - head_start)
+                .map_or(line_end, |stop| stop.index)
+        };
+        // hiding only whitespace, like a final line break, doesn't count
+        let hides_text = |keep: usize| {
+            !self.text[start_at(keep)..end]
+                .chars()
+                .all(char::is_whitespace)
+        };
+        let mut keep = head_end;
+        let mut ellipsis = false;
+        if mode != text::OverflowMode::Truncate {
+            let budget = wrap_width - ellipsis_width - tail_width;
+            keep = head_start;
+            while keep < head_end {
+                let right = self.stops[keep + 1..head_end]
+                    .first()
+                    .map_or(self.lines[last_line].right, |stop| stop.x);
+                if right > budget {
+                    break;
+                }
+                keep += 1;
+            }
+            ellipsis = hides_text(keep);
+            while ellipsis
+                && keep > head_start
+                && self.text[self.stops[keep - 1].index..]
+                    .starts_with(char::is_whitespace)
+            {
+                keep -= 1;
+            }
+        }
+        let truncated = hides_text(keep);
+        let start = start_at(keep);
+        let head_right = self.stops[head_start..head_end]
+            .get(keep - head_start)
+            .map_or(self.lines[last_line].right, |stop| stop.x);
+        let mut tail_x = head_right;
+        let mut stops = self.stops[..keep].to_vec();
+        if ellipsis {
+            stops.push(CharStop {
+                index: start,
+                x: head_right,
+                right: head_right,
+                line: last_line,
+                rtl: false,
+            });
+            tail_x += ellipsis_width;
+        }
+        let baseline = self.lines[last_line].baseline;
+        let shift = [
+            tail_x - self.stops[tail].x,
+            baseline - self.lines[final_line].baseline,
+        ];
+        stops.extend(self.stops[tail..].iter().map(|stop| CharStop {
+            x: stop.x + shift[0],
+            right: stop.right + shift[0],
+            line: last_line,
+            ..*stop
+        }));
+        self.stops = stops;
+        self.lines.truncate(last_line + 1);
+        let line = &mut self.lines[last_line];
+        line.range.end = self.text.len();
+        line.right = tail_x + tail_width;
+        self.last_line_line = self.line_lines[last_line];
+        self.truncated = truncated;
+        Truncation {
+            hidden: start..end,
+            shift,
+            ellipsis: ellipsis.then_some([head_right, baseline]),
+        }
+    }
+
+    fn block_shift(&self) -> [f32; 2] {
         let origin_line = match self.layout.flow {
             text::Flow::Down => self.first_line_line,
             text::Flow::Up => self.last_line_line,
@@ -76,28 +713,48 @@ impl FontCharCalc {
                 (self.first_line_line + self.last_line_line) / 2.0
             }
         };
-        let vert_shift = self.layout.origin_y - origin_line;
-        let horiz_shift = self.layout.origin_x;
+        [self.layout.origin_x, self.layout.origin_y - origin_line]
+    }
+
+    pub fn align_block<'a>(
+        &mut self,
+        verts: impl Iterator<Item = &'a mut [f32; 2]>,
+    ) {
+        let [horiz_shift, vert_shift] = self.block_shift();
         for [x, y] in verts {
             *x += horiz_shift;
             *y += vert_shift;
         }
     }
 
+    /// Apply the block alignment to the recorded caret positions.  This
+    /// should be called once, after the final line is finished.
+    pub fn align_block_stops(&mut self) {
+        let [horiz_shift, vert_shift] = self.block_shift();
+        for stop in &mut self.stops {
+            stop.x += horiz_shift;
+            stop.right += horiz_shift;
+        }
+        for line in &mut self.lines {
+            line.baseline += vert_shift;
+            line.left += horiz_shift;
+            line.right += horiz_shift;
+        }
+    }
+
     pub fn push_span(
         &mut self,
         mut params: CalcParams<'_, impl FnMut(GlyphMetrics)>,
         text: &str,
     ) -> (usize, bool) {
-        let mut remaining = text;
         let mut consumed = 0;
         let mut last_ch = None;
         let mut line_break = false;
         let line = match self.layout.line {
-            text::Line::Ascent => params.font.ascent,
-            text::Line::Descent => params.font.descent,
+            text::Line::Ascent => params.primary().ascent,
+            text::Line::Descent => params.primary().descent,
             text::Line::Baseline => 0.0,
-            text::Line::BetweenBaseAndCap => params.font.capline / 2.0,
+            text::Line::BetweenBaseAndCap => params.primary().capline / 2.0,
         };
         let line = line * self.cursor.font_size + self.cursor.y;
         if self.cursor.x == 0.0 {
@@ -108,100 +765,126 @@ impl FontCharCalc {
         } else {
             self.last_line_line.max(line)
         };
-        let line_height = params.font.line_spacing * self.cursor.font_size;
+        let line_height =
+            params.primary().line_spacing * self.cursor.font_size;
         // intentionally propgate NaNs
         if line_height > self.cursor.current_line_height {
             self.cursor.current_line_height = line_height;
         }
-        while !remaining.is_empty() {
-            if self.cursor.x > self.layout.wrap_width {
-                break;
-            }
-            let word_end = remaining.find(is_breaking_space);
-            match word_end {
-                None => {
-                    let (_, cons) = self.push_word(&mut params, remaining);
-                    consumed += cons;
+        // each segment ends at a break opportunity, and is made of a word
+        // followed by the spaces after it
+        'segments: for (end, _) in unicode_linebreak::linebreaks(text) {
+            let segment = &text[consumed..end];
+            let word = segment.trim_end_matches(is_breaking_space);
+            if !word.is_empty() {
+                if self.cursor.x > self.layout.wrap_width {
                     break;
                 }
-                Some(0) => {
-                    let mut iter = remaining.char_indices();
-                    let (_, ch) = iter.next().expect(concat!(
-                        "remaining text was not empty,",
-                        "but str::chars returned no items"
-                    ));
-                    consumed +=
-                        iter.next().map_or(remaining.len(), |(i, _)| i);
-                    if is_line_break(ch) {
-                        line_break = true;
-                        break;
-                    }
-                    Self::push_whitespace(
-                        &mut self.cursor,
-                        &params,
-                        last_ch,
-                        ch,
-                    );
-                    last_ch = Some(ch);
+                let offset = self.text.len() + consumed;
+                let (lch, cons) = self.push_word(&mut params, word, offset);
+                consumed += cons;
+                if cons < word.len() {
+                    break;
                 }
-                Some(index) => {
-                    let word = &remaining[..index];
-                    let (lch, cons) = self.push_word(&mut params, word);
-                    last_ch = lch;
-                    consumed += cons;
-                    if cons < word.len() {
-                        break;
-                    }
+                last_ch = lch;
+            }
+            // trailing spaces may hang past the wrap width
+            for ch in segment[word.len()..].chars() {
+                self.push_stop(self.text.len() + consumed, self.cursor.x);
+                if is_line_break(ch) {
+                    // this includes the LF of a CR LF pair
+                    consumed = end;
+                    line_break = true;
+                    break 'segments;
                 }
+                consumed += ch.len_utf8();
+                Self::push_whitespace(&mut self.cursor, &params, last_ch, ch);
+                last_ch = Some(ch);
             }
-            remaining = &text[consumed..];
         }
-        (consumed, line_break || consumed < remaining.len())
+        self.text.push_str(&text[..consumed]);
+        (consumed, line_break || consumed < text.len())
     }
 
+    /// Lay out a word which may not be broken, unless it is at the start of
+    /// a line and doesn't fit on it.
+    ///
+    /// Caret stops are recorded at the start of each grapheme cluster, so
+    /// that clusters are never split by the caret, by wrapping, or by
+    /// truncation.
     pub fn push_word(
         &mut self,
         params: &mut CalcParams<'_, impl FnMut(GlyphMetrics)>,
         word: &str,
+        offset: usize,
     ) -> (Option<char>, usize) {
         if self.cursor.x == 0.0 {
-            return self.push_word_splitwrap(params, word);
+            return self.push_word_splitwrap(params, word, offset);
         }
         let mut glyphs = Vec::new();
+        let mut stops = Vec::new();
         let mut last_ch = None;
         let mut cursor = self.cursor;
-        for ch in word.chars() {
-            if let Some(glyph) = params.font.glyph(ch) {
-                let kerning = last_ch
-                    .and_then(|left| params.font.kerning(left, ch))
-                    .unwrap_or(0.0);
-                let kerning = kerning * cursor.font_size;
-                let advance = glyph.advance * cursor.font_size;
-                if cursor.x + kerning + advance > self.layout.wrap_width {
-                    return (None, 0);
-                }
-                cursor.x += kerning;
-                glyphs.push(Self::position_char(&mut cursor, *glyph));
-                cursor.x += advance;
-            } else if ch.is_whitespace() {
-                Self::push_whitespace(&mut cursor, params, last_ch, ch);
+        let line = self.lines.len();
+        for (i, cluster) in word.grapheme_indices(true) {
+            stops.push(CharStop {
+                index: offset + i,
+                x: cursor.x,
+                right: cursor.x,
+                line,
+                rtl: false,
+            });
+            Self::push_cluster(
+                params,
+                &mut cursor,
+                (offset + i, cluster),
+                &mut last_ch,
+                &mut glyphs,
+            );
+            if cursor.x > self.layout.wrap_width {
+                return (None, 0);
             }
-            last_ch = Some(ch);
         }
         for glyph in glyphs {
             (params.handle_glyph)(glyph);
         }
+        self.stops.extend(stops);
         self.cursor = cursor;
         (last_ch, word.len())
     }
 
+    /// Lay out the characters of a grapheme cluster.
+    fn push_cluster(
+        params: &CalcParams<'_, impl FnMut(GlyphMetrics)>,
+        cursor: &mut Cursor,
+        (index, cluster): (usize, &str),
+        last_ch: &mut Option<char>,
+        glyphs: &mut Vec<GlyphMetrics>,
+    ) {
+        for ch in cluster.chars() {
+            if let Some((face, page, glyph)) = params.glyph(ch) {
+                let kerning = params.kerning(face, *last_ch, ch);
+                cursor.x += kerning * cursor.font_size;
+                let advance = glyph.advance * cursor.font_size;
+                glyphs.push(Self::position_char(
+                    cursor, index, face, page, glyph,
+                ));
+                cursor.x += advance;
+            } else if ch.is_whitespace() {
+                Self::push_whitespace(cursor, params, *last_ch, ch);
+            }
+            *last_ch = Some(ch);
+        }
+    }
+
     pub fn push_whitespace(
         cursor: &mut Cursor,
         params: &CalcParams<'_, impl FnMut(GlyphMetrics)>,
         last_ch: Option<char>,
         white_char: char,
     ) {
-        let advance = if let Some(glyph) = params.font.glyph(white_char) {
+        let primary = params.primary();
+        let advance = if let Some(glyph) = primary.glyph(white_char) {
             glyph.advance
         } else {
             match white_char {
@@ -216,43 +899,53 @@ impl FontCharCalc {
             }
         };
         let kerning = last_ch
-            .and_then(|left| params.font.kerning(left, white_char))
+            .and_then(|left| primary.kerning(left, white_char))
             .unwrap_or(0.0);
         cursor.x += cursor.font_size * (kerning + advance);
     }
 
+    /// Lay out as much of a word as fits on the current line, splitting it
+    /// between grapheme clusters.  At least one cluster is always laid
+    /// out, so that progress is made even if the line is very narrow.
     fn push_word_splitwrap(
         &mut self,
         params: &mut CalcParams<'_, impl FnMut(GlyphMetrics)>,
         word: &str,
+        offset: usize,
     ) -> (Option<char>, usize) {
         let mut last_ch = None;
-        for (i, ch) in word.char_indices() {
-            if let Some(glyph) = params.font.glyph(ch) {
-                let kerning = last_ch
-                    .and_then(|left| params.font.kerning(left, ch))
-                    .unwrap_or(0.0);
-                let kerning = kerning * self.cursor.font_size;
-                let advance = glyph.advance * self.cursor.font_size;
-                if self.cursor.x + kerning + advance > self.layout.wrap_width {
-                    return (None, i);
-                }
-                self.cursor.x += kerning;
-                (params.handle_glyph)(Self::position_char(
-                    &mut self.cursor,
-                    *glyph,
-                ));
-                self.cursor.x += advance;
-            } else if ch.is_whitespace() {
-                Self::push_whitespace(&mut self.cursor, params, last_ch, ch);
+        let mut glyphs = Vec::new();
+        for (i, cluster) in word.grapheme_indices(true) {
+            let mut cursor = self.cursor;
+            let mut cluster_last_ch = last_ch;
+            Self::push_cluster(
+                params,
+                &mut cursor,
+                (offset + i, cluster),
+                &mut cluster_last_ch,
+                &mut glyphs,
+            );
+            if i > 0 && cursor.x > self.layout.wrap_width {
+                return (None, i);
+            }
+            self.push_stop(offset + i, self.cursor.x);
+            for glyph in glyphs.drain(..) {
+                (params.handle_glyph)(glyph);
             }
-            last_ch = Some(ch);
+            self.cursor = cursor;
+            last_ch = cluster_last_ch;
         }
         (last_ch, word.len())
     }
 
     #[must_use]
-    fn position_char(cursor: &mut Cursor, glyph: font::Glyph) -> GlyphMetrics {
+    fn position_char(
+        cursor: &mut Cursor,
+        index: usize,
+        face: Option<usize>,
+        page: usize,
+        glyph: font::Glyph,
+    ) -> GlyphMetrics {
         let font::Glyph {
             bb_left,
             bb_right,
@@ -269,6 +962,10 @@ impl FontCharCalc {
             bb_right: bb_right * cursor.font_size + cursor.x,
             bb_bottom: bb_bottom * cursor.font_size + cursor.y,
             bb_top: bb_top * cursor.font_size + cursor.y,
+            baseline: cursor.y,
+            index,
+            face,
+            page,
             tex_left,
             tex_right,
             tex_bottom,
@@ -277,9 +974,40 @@ impl FontCharCalc {
     }
 }
 
+/// Characters which end a line, as described by UAX #14.
+/// Find the span which ends a line, which an ellipsis replacing it is drawn
+/// like, given spans and the index each starts at.
+pub(super) fn line_end_span<'a, T>(
+    spans: &'a [(usize, T)],
+    line: &text::LineMetrics,
+) -> &'a T {
+    let index = line.range.end.saturating_sub(1).max(line.range.start);
+    let pos = spans.partition_point(|&(start, _)| start <= index);
+    &spans[pos.saturating_sub(1)].1
+}
+
 #[must_use]
 fn is_line_break(ch: char) -> bool {
-    matches!(ch, '\n')
+    matches!(
+        ch,
+        '\n' | '\r' | '\u{0b}' | '\u{0c}' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+/// Characters which have no visible glyph, and shouldn't be replaced by one
+/// if the font doesn't have them.
+#[must_use]
+fn is_invisible(ch: char) -> bool {
+    ch.is_control()
+        || matches!(
+            ch,
+            '\u{00ad}'
+                | '\u{200b}'..='\u{200f}'
+                | '\u{2028}'..='\u{202e}'
+                | '\u{2060}'..='\u{2064}'
+                | '\u{fe00}'..='\u{fe0f}'
+                | '\u{feff}'
+        )
 }
 
 #[must_use]
@@ -297,8 +1025,327 @@ pub struct GlyphMetrics {
     pub bb_right: f32,
     pub bb_bottom: f32,
     pub bb_top: f32,
+    pub baseline: f32,
+    /// The byte index of the grapheme cluster this glyph is part of.
+    pub index: usize,
+    /// The index of the font this glyph is from in the fonts the span was
+    /// laid out with, or `None` for the replacement glyph drawn for
+    /// characters no font has.
+    pub face: Option<usize>,
+    /// The page of the font's textures the glyph is in.
+    pub page: usize,
     pub tex_left: u16,
     pub tex_right: u16,
     pub tex_bottom: u16,
     pub tex_top: u16,
 }
+
+impl GlyphMetrics {
+    /// Move the glyph, for glyphs laid out away from where they're drawn.
+    pub fn translate(&mut self, [dx, dy]: [f32; 2]) {
+        self.bb_left += dx;
+        self.bb_right += dx;
+        self.bb_bottom += dy;
+        self.bb_top += dy;
+        self.baseline += dy;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use super::*;
+    use crate::platforms::opengl::Texture;
+
+    const FONT_SIZE: f32 = 10.0;
+
+    /// A font where every glyph is one em wide, except combining marks.
+    fn test_font() -> font::FontData {
+        let mut chars: Vec<char> = ('a'..='z')
+            .chain("-\u{301}\u{2026}日本語テキストשלום".chars())
+            .collect();
+        chars.sort_unstable();
+        let glyphs = chars
+            .into_iter()
+            .map(|ch| font::Glyph {
+                ch,
+                advance: if ch == '\u{301}' { 0.0 } else { 1.0 },
+                bb_left: 0.0,
+                bb_right: 1.0,
+                bb_bottom: 0.0,
+                bb_top: 0.7,
+                tex_left: 0,
+                tex_right: 0,
+                tex_bottom: 0,
+                tex_top: 0,
+            })
+            .collect();
+        font::FontData {
+            texture: Texture::solid_color(),
+            padding_ratio: 0.1,
+            glyphs,
+            kerning: Box::new([]),
+            line_spacing: 1.2,
+            ascent: 0.8,
+            capline: 0.7,
+            descent: -0.2,
+            atlas_kind: font::AtlasKind::Sdf,
+            source: None,
+        }
+    }
+
+    fn layout(text: &str, wrap_width: f32) -> FontCharCalc {
+        layout_with(
+            text,
+            text::Layout {
+                wrap_width,
+                ..text::Layout::default()
+            },
+        )
+    }
+
+    fn layout_with(text: &str, layout: text::Layout) -> FontCharCalc {
+        let font = test_font();
+        FontCharCalc::lay_out_spans(layout, &[(&[&font], FONT_SIZE, text)])
+    }
+
+    fn caret_x(calc: &FontCharCalc, index: usize) -> f32 {
+        calc.caret(index).expect("no caret at index").0
+    }
+
+    fn lines(text: &str, wrap_width: f32) -> Vec<&str> {
+        let ranges: Vec<Range<usize>> = layout(text, wrap_width)
+            .lines
+            .iter()
+            .map(|line| line.range.clone())
+            .collect();
+        ranges.into_iter().map(|range| &text[range]).collect()
+    }
+
+    #[test]
+    fn wraps_after_hyphens_and_spaces() {
+        assert_eq!(lines("foo-bar baz", 45.0), ["foo-", "bar ", "baz"]);
+        assert_eq!(lines("foo-bar baz", 75.0), ["foo-bar ", "baz"]);
+    }
+
+    #[test]
+    fn wraps_between_ideographs() {
+        assert_eq!(lines("日本語テキスト", 30.0), ["日本語", "テキス", "ト"]);
+        assert_eq!(lines("abc日本語", 50.0), ["abc日本", "語"]);
+        assert_eq!(lines("ab cd日本", 40.0), ["ab ", "cd日本"]);
+    }
+
+    #[test]
+    fn wraps_at_zero_width_spaces() {
+        assert_eq!(lines("abc\u{200b}def", 40.0), ["abc\u{200b}", "def"]);
+    }
+
+    #[test]
+    fn mandatory_breaks() {
+        assert_eq!(
+            lines("a\r\nb\u{2028}c\nd", f32::INFINITY),
+            ["a\r\n", "b\u{2028}", "c\n", "d"],
+        );
+    }
+
+    #[test]
+    fn long_words_split_between_grapheme_clusters() {
+        let text = "e\u{301}e\u{301}e\u{301}";
+        assert_eq!(lines(text, 15.0), ["e\u{301}", "e\u{301}", "e\u{301}"]);
+        // a line always holds at least one cluster
+        assert_eq!(lines("ab", 5.0), ["a", "b"]);
+    }
+
+    #[test]
+    fn carets_stop_between_grapheme_clusters() {
+        let calc = layout("e\u{301}x", f32::INFINITY);
+        assert!(calc.caret(0).is_some());
+        assert!(calc.caret(1).is_none());
+        let (x, _) = calc.caret(3).expect("no caret after the cluster");
+        assert_eq!(x, FONT_SIZE);
+        let hit = calc.hit_test([FONT_SIZE * 0.75, 0.0]);
+        assert_eq!(hit.map(|hit| hit.caret), Some(3));
+    }
+
+    // "ab " and the spaces are 22.5 wide, each other character is 10
+
+    #[test]
+    fn right_to_left_runs_are_reordered() {
+        let text = "ab שלום cd";
+        let calc = layout(text, f32::INFINITY);
+        assert_eq!(caret_x(&calc, 0), 0.0);
+        // the first Hebrew letter is displayed rightmost in its run, and
+        // the caret before it is at its right edge
+        assert_eq!(caret_x(&calc, 3), 62.5);
+        assert_eq!(caret_x(&calc, 9), 32.5);
+        assert_eq!(caret_x(&calc, 11), 62.5);
+        assert_eq!(caret_x(&calc, 12), 65.0);
+        assert_eq!(caret_x(&calc, text.len()), 85.0);
+    }
+
+    #[test]
+    fn right_to_left_paragraphs() {
+        let text = "שלום ab";
+        let calc = layout_with(
+            text,
+            text::Layout {
+                alignment: text::Alignment::Start,
+                ..text::Layout::default()
+            },
+        );
+        let line = &calc.lines[0];
+        assert_eq!((line.left, line.right), (-62.5, 0.0));
+        assert_eq!(caret_x(&calc, 0), 0.0);
+        assert_eq!(caret_x(&calc, 9), -62.5);
+        // the end of a right-to-left line is on its left
+        assert_eq!(caret_x(&calc, text.len()), -62.5);
+        let calc = layout_with(
+            text,
+            text::Layout {
+                alignment: text::Alignment::End,
+                ..text::Layout::default()
+            },
+        );
+        assert_eq!(calc.lines[0].left, 0.0);
+        let calc = layout_with(
+            "ab",
+            text::Layout {
+                alignment: text::Alignment::End,
+                ..text::Layout::default()
+            },
+        );
+        assert_eq!(calc.lines[0].right, 0.0);
+    }
+
+    #[test]
+    fn hit_test_across_direction_boundaries() {
+        let calc = layout("ab שלום cd", f32::INFINITY);
+        let hit = |x| calc.hit_test([x, 0.0]).expect("no hit");
+        let near_leading = hit(61.0);
+        assert_eq!(near_leading.index, 3);
+        assert_eq!(near_leading.affinity, text::Affinity::Leading);
+        assert_eq!(near_leading.caret, 3);
+        let near_trailing = hit(54.0);
+        assert_eq!(near_trailing.index, 3);
+        assert_eq!(near_trailing.affinity, text::Affinity::Trailing);
+        assert_eq!(near_trailing.caret, 5);
+        assert_eq!(hit(21.0).caret, 2);
+        assert_eq!(hit(63.0).caret, 11);
+        // beyond the end of a line, the line break is hit
+        let calc = layout("ab\ncd", f32::INFINITY);
+        let y = calc.lines[0].baseline;
+        let hit = calc.hit_test([100.0, y]).expect("no hit");
+        assert_eq!((hit.index, hit.caret, hit.inside), (2, 2, false));
+        let hit = calc.hit_test([-5.0, y]).expect("no hit");
+        assert_eq!((hit.index, hit.caret, hit.inside), (0, 0, false));
+    }
+
+    #[test]
+    fn selections_split_across_directions() {
+        let calc = layout("ab שלום cd", f32::INFINITY);
+        let extents = |range| -> Vec<(f32, f32)> {
+            calc.selection(range)
+                .into_iter()
+                .map(|(left, right, _)| (left, right))
+                .collect()
+        };
+        assert_eq!(extents(3..7), [(42.5, 62.5)]);
+        assert_eq!(extents(0..5), [(0.0, 22.5), (52.5, 62.5)]);
+    }
+
+    fn limited(
+        text: &str,
+        wrap_width: f32,
+        vertical_limit: text::VerticalLimit,
+        overflow_mode: text::OverflowMode,
+    ) -> FontCharCalc {
+        layout_with(
+            text,
+            text::Layout {
+                wrap_width,
+                vertical_limit,
+                overflow_mode,
+                ..text::Layout::default()
+            },
+        )
+    }
+
+    #[test]
+    fn vertical_limit_hides_lines() {
+        let calc = limited(
+            "a\nb\nc",
+            f32::INFINITY,
+            text::VerticalLimit::Height(25.0),
+            text::OverflowMode::Truncate,
+        );
+        assert!(calc.truncated);
+        assert_eq!(calc.lines.len(), 2);
+        assert_eq!(calc.lines[1].range, 2..5);
+        assert!(calc.caret(4).is_none());
+        let calc = limited(
+            "a\nb\nc",
+            f32::INFINITY,
+            text::VerticalLimit::Lines(3),
+            text::OverflowMode::Truncate,
+        );
+        assert!(!calc.truncated);
+        assert_eq!(calc.lines.len(), 3);
+        // a trailing empty line hides no text
+        let calc = limited(
+            "a\n",
+            f32::INFINITY,
+            text::VerticalLimit::Lines(1),
+            text::OverflowMode::Ellipsis,
+        );
+        assert!(!calc.truncated);
+        assert_eq!(calc.lines.len(), 1);
+        assert!(calc.caret(2).is_some());
+    }
+
+    #[test]
+    fn last_visible_line_ends_in_ellipsis() {
+        let calc = limited(
+            "aaa bbb ccc",
+            35.0,
+            text::VerticalLimit::Lines(2),
+            text::OverflowMode::Ellipsis,
+        );
+        assert!(calc.truncated);
+        assert_eq!(calc.lines.len(), 2);
+        // the ellipsis replaces the third b, which would not leave room
+        assert_eq!(caret_x(&calc, 6), 20.0);
+        assert!(calc.caret(7).is_none());
+        assert_eq!(caret_x(&calc, 11), 30.0);
+        assert!(calc.lines[1].right <= 35.0);
+        // trailing spaces are removed before the ellipsis
+        let calc = limited(
+            "aaa bbb ccc",
+            45.0,
+            text::VerticalLimit::Lines(2),
+            text::OverflowMode::Ellipsis,
+        );
+        assert_eq!(caret_x(&calc, 7), 30.0);
+        assert_eq!(calc.lines[1].right, 40.0);
+    }
+
+    #[test]
+    fn middle_of_text_replaced_by_ellipsis() {
+        let text = "abc-def-ghi";
+        let calc = limited(
+            text,
+            45.0,
+            text::VerticalLimit::Lines(1),
+            text::OverflowMode::EllipsisMiddle,
+        );
+        assert!(calc.truncated);
+        assert_eq!(calc.lines.len(), 1);
+        assert_eq!(calc.lines[0].range, 0..text.len());
+        assert_eq!(caret_x(&calc, 2), 20.0);
+        assert!(calc.caret(3).is_none());
+        assert_eq!(caret_x(&calc, 10), 30.0);
+        assert_eq!(caret_x(&calc, 11), 40.0);
+        let hit = calc.hit_test([35.0, 0.0]).expect("no hit");
+        assert_eq!(hit.index, 10);
+    }
+}
@@ -15,37 +15,66 @@ pub fn default_font() -> Rc<font::Font> {
     DEFAULT_FONT.with(Rc::clone)
 }
 
+pub fn default_bold_font() -> Rc<font::Font> {
+    DEFAULT_FONT.with(|font| {
+        let bold = font.bold.get().expect("default font has a bold face");
+        Rc::clone(bold)
+    })
+}
+
+macro_rules! font_data {
+    ($texture:expr, $face:ident) => {
+        font::FontData {
+            texture: $texture,
+            padding_ratio: suzy_default_font::$face::PADDING_RATIO,
+            glyphs: suzy_default_font::$face::GLYPHS
+                .iter()
+                .map(|glyph| font::Glyph {
+                    ch: glyph.ch,
+                    advance: glyph.advance,
+                    bb_left: glyph.bb_left,
+                    bb_right: glyph.bb_right,
+                    bb_bottom: glyph.bb_bottom,
+                    bb_top: glyph.bb_top,
+                    tex_left: glyph.tex_left,
+                    tex_right: glyph.tex_right,
+                    tex_bottom: glyph.tex_bottom,
+                    tex_top: glyph.tex_top,
+                })
+                .collect(),
+            kerning: suzy_default_font::$face::KERNING
+                .iter()
+                .map(|pair| font::KerningPair {
+                    left: pair.left,
+                    right: pair.right,
+                    kerning: pair.kerning,
+                })
+                .collect(),
+            line_spacing: suzy_default_font::$face::LINE_SPACING,
+            ascent: suzy_default_font::$face::ASCENT,
+            capline: suzy_default_font::$face::CAPLINE,
+            descent: suzy_default_font::$face::DESCENT,
+            atlas_kind: font::AtlasKind::Sdf,
+            source: None,
+        }
+    };
+}
+
 fn build() -> Rc<font::Font> {
+    // both faces share a single atlas
     let texture = Texture::new(Rc::new(DefaultFontAtlasPopulator));
-    let regular_data = font::FontData {
-        texture,
-        padding_ratio: suzy_default_font::regular::PADDING_RATIO,
-        glyphs: suzy_default_font::regular::GLYPHS
-            .iter()
-            .map(|glyph| font::Glyph {
-                ch: glyph.ch,
-                advance: glyph.advance,
-                bb_left: glyph.bb_left,
-                bb_right: glyph.bb_right,
-                bb_bottom: glyph.bb_bottom,
-                bb_top: glyph.bb_top,
-                tex_left: glyph.tex_left,
-                tex_right: glyph.tex_right,
-                tex_bottom: glyph.tex_bottom,
-                tex_top: glyph.tex_top,
-            })
-            .collect(),
-        kerning: Box::new([]),
-        line_spacing: suzy_default_font::regular::LINE_SPACING,
-        ascent: suzy_default_font::regular::ASCENT,
-        capline: suzy_default_font::regular::CAPLINE,
-        descent: suzy_default_font::regular::DESCENT,
-    };
-    Rc::new(font::Font {
-        data: regular_data,
+    let bold = Rc::new(font::Font {
+        data: font_data!(texture.clone(), bold),
         bold: OnceCell::new(),
         italic: OnceCell::new(),
-    })
+    });
+    let regular = Rc::new(font::Font {
+        data: font_data!(texture, regular),
+        bold: OnceCell::new(),
+        italic: OnceCell::new(),
+    });
+    let _ = regular.bold.set(bold);
+    regular
 }
 
 struct DefaultFontAtlasPopulator;
@@ -65,6 +94,14 @@ impl PopulateTexture for DefaultFontAtlasPopulator {
         ))
     }
 
+    fn populate_pixels(&self) -> Result<opengl::TexturePixels, String> {
+        Ok(font::Font::font_atlas_pixels(
+            suzy_default_font::TEXTURE_WIDTH,
+            suzy_default_font::TEXTURE_HEIGHT,
+            suzy_default_font::TEXTURE_DATA,
+        ))
+    }
+
     fn texture_key(&self) -> &[u8] {
         // random, very unlikely to collide with anything else
         b"\xf6>\xed=\x9d\x80\xc4N\xb9\x14\n\x03Q\x8f&\xea"
@@ -0,0 +1,468 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Fonts loaded from TTF or OTF files, with glyphs rasterized into a
+//! signed distance field atlas the first time they are used.
+
+use std::{
+    cell::{Cell, OnceCell, RefCell},
+    collections::{BTreeMap, HashMap},
+    convert::TryFrom,
+    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use blurry::{
+    ttf_parser::{Face, GlyphId},
+    FontAssetBuilder, GlyphRequest,
+};
+use suzy_build_tools::kerning::Kerning;
+
+use crate::platforms::opengl::{self, PopulateTexture, Texture};
+
+use super::{
+    asset::FontLoadError,
+    font::{AtlasKind, Font, FontData, Glyph, GlyphSource},
+};
+
+/// The size, in pixels per em, glyphs are rasterized at.
+const GLYPH_PIXEL_SIZE: f32 = 48.0;
+
+/// The size of the distance field around each glyph, relative to the em.
+const PADDING_RATIO: f32 = 0.2;
+
+const ATLAS_WIDTH: u32 = 1024;
+const INITIAL_ATLAS_HEIGHT: u32 = 256;
+const MAX_ATLAS_HEIGHT: u32 = 4096;
+
+/// Empty pixels left between glyphs, so they don't bleed into each other.
+const GLYPH_SPACING: u32 = 1;
+
+impl Font {
+    /// Load a font from the contents of a TTF or OTF file.
+    ///
+    /// Rather than being limited to a set of characters chosen ahead of
+    /// time, glyphs are rasterized the first time they are laid out, into
+    /// an atlas which grows as needed.  When the atlas can't grow any more,
+    /// glyphs are added to another atlas texture.
+    pub fn from_font_file(data: Vec<u8>) -> Result<Rc<Self>, FontLoadError> {
+        let file = FontFile::parse(data)
+            .ok_or(FontLoadError::Invalid("failed to parse font file"))?;
+        let face = file.face();
+        let height = f32::from(face.units_per_em());
+        let rel_value = |val| f32::from(val) / height;
+        let line_spacing = 1.0 + rel_value(face.line_gap());
+        let ascent = rel_value(face.ascender());
+        let descent = rel_value(face.descender());
+        let capline = face.capital_height().map_or(ascent * 0.75, rel_value);
+        let source = DynamicGlyphs::new(file);
+        let texture = source.pages.borrow()[0].1.clone();
+        let data = FontData {
+            texture,
+            padding_ratio: PADDING_RATIO,
+            glyphs: Box::new([]),
+            kerning: Box::new([]),
+            line_spacing,
+            ascent,
+            capline,
+            descent,
+            atlas_kind: AtlasKind::Sdf,
+            source: Some(Box::new(source)),
+        };
+        Ok(Rc::new(Font {
+            data,
+            bold: OnceCell::new(),
+            italic: OnceCell::new(),
+        }))
+    }
+}
+
+/// The contents of a font file, with the face parsed from it.
+#[derive(Debug)]
+struct FontFile {
+    // the face and kerning borrow from the data, so must drop first
+    face: Face<'static>,
+    kerning: Kerning<'static>,
+    _data: Vec<u8>,
+}
+
+impl FontFile {
+    fn parse(data: Vec<u8>) -> Option<Self> {
+        // SAFETY: the data is on the heap, so it doesn't move when the vec
+        // does, and is never modified.  The face and kerning borrowing it
+        // are dropped before it.
+        // They are only handed out with lifetimes tied to `self`.
+        let contents: &'static [u8] =
+            unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+        let face = Face::parse(contents, 0).ok()?;
+        let kerning = Kerning::new(&face);
+        Some(Self {
+            face,
+            kerning,
+            _data: data,
+        })
+    }
+
+    fn face(&self) -> &Face<'_> {
+        &self.face
+    }
+
+    fn kerning(&self) -> &Kerning<'_> {
+        &self.kerning
+    }
+}
+
+#[derive(Debug)]
+struct DynamicGlyphs {
+    file: FontFile,
+    /// The atlases glyphs are packed into, with their textures.  Glyphs are
+    /// only added to the last page.
+    pages: RefCell<Vec<(Rc<DynamicAtlas>, Texture)>>,
+    /// Glyphs and the page they are in.  `None` records characters the
+    /// font has no glyph for.
+    glyphs: RefCell<BTreeMap<char, Option<(Glyph, usize)>>>,
+    kerning: RefCell<HashMap<(char, char), Option<f32>>>,
+}
+
+impl DynamicGlyphs {
+    fn new(file: FontFile) -> Self {
+        Self {
+            file,
+            pages: RefCell::new(vec![new_page()]),
+            glyphs: RefCell::default(),
+            kerning: RefCell::default(),
+        }
+    }
+
+    fn rasterize(
+        &self,
+        face: &Face<'_>,
+        glyph_id: GlyphId,
+        ch: char,
+    ) -> Option<(Glyph, usize)> {
+        let units = f32::from(face.units_per_em());
+        let advance = f32::from(face.glyph_hor_advance(glyph_id)?) / units;
+        let empty = Glyph {
+            ch,
+            advance,
+            bb_left: 0.0,
+            bb_right: 0.0,
+            bb_bottom: 0.0,
+            bb_top: 0.0,
+            tex_left: 0,
+            tex_right: 0,
+            tex_bottom: 0,
+            tex_top: 0,
+        };
+        if face.glyph_bounding_box(glyph_id).is_none() {
+            // whitespace, nothing to draw
+            return Some((empty, 0));
+        }
+        let request = GlyphRequest {
+            user_data: (),
+            face,
+            codepoint: ch,
+        };
+        let tile = FontAssetBuilder::with_font_size(GLYPH_PIXEL_SIZE)
+            .with_padding_ratio(PADDING_RATIO)
+            .build(std::iter::once(request))
+            .ok()?;
+        let meta = tile.metadata.first()?;
+        let to_pixels = |coord: f32, size: u16| {
+            // texture coordinates are in the range 0..=1
+            (coord * f32::from(size)).round() as u32
+        };
+        let src_left = to_pixels(meta.tex_left, tile.width);
+        let src_bottom = to_pixels(meta.tex_bottom, tile.height);
+        let width = to_pixels(meta.tex_right, tile.width) - src_left;
+        let height = to_pixels(meta.tex_top, tile.height) - src_bottom;
+        let ([x, y], page) = self.insert(
+            &tile.data,
+            u32::from(tile.width),
+            [src_left, src_bottom],
+            [width, height],
+        )?;
+        let coord = |value: u32| u16::try_from(value).ok();
+        let glyph = Glyph {
+            bb_left: meta.left,
+            bb_right: meta.right,
+            bb_bottom: meta.bottom,
+            bb_top: meta.top,
+            tex_left: coord(x)?,
+            tex_right: coord(x + width)?,
+            tex_bottom: coord(y)?,
+            tex_top: coord(y + height)?,
+            ..empty
+        };
+        Some((glyph, page))
+    }
+
+    /// Copy a region of `src` into the last page, adding a new page if it
+    /// is full.  Returns the position the region was placed at and its
+    /// page.
+    fn insert(
+        &self,
+        src: &[u8],
+        src_width: u32,
+        src_pos: [u32; 2],
+        size: [u32; 2],
+    ) -> Option<([u32; 2], usize)> {
+        let mut pages = self.pages.borrow_mut();
+        let (last, _) = pages.last()?;
+        if let Some(pos) = last.insert(src, src_width, src_pos, size) {
+            return Some((pos, pages.len() - 1));
+        }
+        let page = new_page();
+        let pos = page.0.insert(src, src_width, src_pos, size)?;
+        pages.push(page);
+        Some((pos, pages.len() - 1))
+    }
+}
+
+impl GlyphSource for DynamicGlyphs {
+    fn glyph(&self, ch: char) -> Option<(Glyph, usize)> {
+        if let Some(&glyph) = self.glyphs.borrow().get(&ch) {
+            return glyph;
+        }
+        let face = self.file.face();
+        let Some(glyph_id) = face.glyph_index(ch) else {
+            self.glyphs.borrow_mut().insert(ch, None);
+            return None;
+        };
+        // if rasterizing fails, the glyph isn't cached so that it can be
+        // tried again
+        let glyph = self.rasterize(face, glyph_id, ch)?;
+        self.glyphs.borrow_mut().insert(ch, Some(glyph));
+        Some(glyph)
+    }
+
+    fn kerning(&self, left: char, right: char) -> Option<f32> {
+        if let Some(&kerning) = self.kerning.borrow().get(&(left, right)) {
+            return kerning;
+        }
+        let face = self.file.face();
+        let units = f32::from(face.units_per_em());
+        let kerning = face
+            .glyph_index(left)
+            .zip(face.glyph_index(right))
+            .map(|(left, right)| self.file.kerning().pair(left, right))
+            .filter(|&kerning| kerning != 0)
+            .map(|kerning| f32::from(kerning) / units);
+        self.kerning.borrow_mut().insert((left, right), kerning);
+        kerning
+    }
+
+    fn page_texture(&self, page: usize) -> Option<Texture> {
+        let pages = self.pages.borrow();
+        pages.get(page).map(|(_atlas, texture)| texture.clone())
+    }
+}
+
+fn new_page() -> (Rc<DynamicAtlas>, Texture) {
+    let atlas = Rc::new(DynamicAtlas::new());
+    let texture = Texture::new(Rc::clone(&atlas) as Rc<dyn PopulateTexture>);
+    (atlas, texture)
+}
+
+/// A texture which glyphs are packed into as they are rasterized, in rows
+/// from the bottom.  When the atlas is full, it grows upwards, so the
+/// pixel coordinates of glyphs already in it remain valid, up to a maximum
+/// height.
+#[derive(Debug)]
+struct DynamicAtlas {
+    key: Vec<u8>,
+    generation: Cell<u64>,
+    state: RefCell<AtlasState>,
+}
+
+#[derive(Debug)]
+struct AtlasState {
+    height: u32,
+    data: Vec<u8>,
+    row_x: u32,
+    row_y: u32,
+    row_height: u32,
+}
+
+impl DynamicAtlas {
+    fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let mut key = b"suzy-dynamic-font:".to_vec();
+        key.extend_from_slice(&id.to_le_bytes());
+        let len = ATLAS_WIDTH * INITIAL_ATLAS_HEIGHT;
+        Self {
+            key,
+            generation: Cell::new(0),
+            state: RefCell::new(AtlasState {
+                height: INITIAL_ATLAS_HEIGHT,
+                data: vec![0; len as usize],
+                row_x: 0,
+                row_y: 0,
+                row_height: 0,
+            }),
+        }
+    }
+
+    /// Copy a region of `src` into the atlas, returning the position it was
+    /// placed at, or `None` if the atlas is full.
+    fn insert(
+        &self,
+        src: &[u8],
+        src_width: u32,
+        [src_x, src_y]: [u32; 2],
+        [width, height]: [u32; 2],
+    ) -> Option<[u32; 2]> {
+        let mut state = self.state.borrow_mut();
+        let [x, y] = state.allocate(width, height)?;
+        for row in 0..height {
+            let src_start = ((src_y + row) * src_width + src_x) as usize;
+            let dest_start = ((y + row) * ATLAS_WIDTH + x) as usize;
+            let len = width as usize;
+            state.data[dest_start..dest_start + len]
+                .copy_from_slice(src.get(src_start..src_start + len)?);
+        }
+        self.generation.set(self.generation.get() + 1);
+        Some([x, y])
+    }
+}
+
+impl AtlasState {
+    fn allocate(&mut self, width: u32, height: u32) -> Option<[u32; 2]> {
+        if width > ATLAS_WIDTH {
+            return None;
+        }
+        if self.row_x + width > ATLAS_WIDTH {
+            self.row_y += self.row_height + GLYPH_SPACING;
+            self.row_x = 0;
+            self.row_height = 0;
+        }
+        while self.row_y + height > self.height {
+            if self.height >= MAX_ATLAS_HEIGHT {
+                return None;
+            }
+            self.height = (self.height * 2).min(MAX_ATLAS_HEIGHT);
+            self.data.resize((ATLAS_WIDTH * self.height) as usize, 0);
+        }
+        let pos = [self.row_x, self.row_y];
+        self.row_x += width + GLYPH_SPACING;
+        self.row_height = self.row_height.max(height);
+        Some(pos)
+    }
+}
+
+impl PopulateTexture for DynamicAtlas {
+    fn populate(
+        &self,
+        gl: &opengl::OpenGlBindings,
+        target: opengl::opengl_bindings::types::GLenum,
+    ) -> Result<opengl::TextureSize, String> {
+        let state = self.state.borrow();
+        let height = u16::try_from(state.height)
+            .map_err(|_| "font atlas too large".to_string())?;
+        Ok(Font::populate_font_atlas(
+            gl,
+            target,
+            ATLAS_WIDTH as u16,
+            height,
+            &state.data,
+        ))
+    }
+
+    fn populate_pixels(&self) -> Result<opengl::TexturePixels, String> {
+        let state = self.state.borrow();
+        let height = u16::try_from(state.height)
+            .map_err(|_| "font atlas too large".to_string())?;
+        Ok(Font::font_atlas_pixels(
+            ATLAS_WIDTH as u16,
+            height,
+            &state.data,
+        ))
+    }
+
+    fn texture_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTO: &[u8] = include_bytes!(
+        "../../../../../build-default-font/src/roboto/Roboto-Regular.ttf"
+    );
+
+    fn roboto() -> Rc<Font> {
+        Font::from_font_file(ROBOTO.to_vec()).expect("failed to load Roboto")
+    }
+
+    #[test]
+    fn rasterizes_glyphs_on_demand() {
+        let font = roboto();
+        let glyph = font.data.glyph('A').expect("Roboto has no 'A'");
+        assert!(glyph.advance > 0.0);
+        assert!(glyph.bb_right > glyph.bb_left);
+        assert!(glyph.tex_right > glyph.tex_left);
+        assert!(glyph.tex_top > glyph.tex_bottom);
+        let space = font.data.glyph(' ').expect("Roboto has no space");
+        assert!(space.advance > 0.0);
+        assert_eq!(space.tex_left, space.tex_right);
+        assert!(font.data.glyph('\u{4e2d}').is_none());
+    }
+
+    #[test]
+    fn full_atlas_adds_a_page() {
+        let file = FontFile::parse(ROBOTO.into()).expect("invalid Roboto");
+        let source = DynamicGlyphs::new(file);
+        {
+            let pages = source.pages.borrow();
+            let (first, _) = &pages[0];
+            // fill the gaps left by large tiles with small ones
+            for size in [100, 4] {
+                let tile = vec![7; size as usize * size as usize];
+                while first.insert(&tile, size, [0, 0], [size, size]).is_some()
+                {
+                }
+            }
+        }
+        let (glyph, page) = source.glyph('A').expect("Roboto has an 'A'");
+        assert_eq!(page, 1);
+        assert!(glyph.tex_right > glyph.tex_left);
+        let first = source.page_texture(0).expect("no first page");
+        let second = source.page_texture(1).expect("no second page");
+        assert_ne!(first.id(), second.id());
+        // characters the font doesn't have are still missing
+        assert!(source.glyph('\u{4e2d}').is_none());
+        assert!(source.glyphs.borrow()[&'\u{4e2d}'].is_none());
+    }
+
+    #[test]
+    fn kerning_uses_gpos() {
+        let font = roboto();
+        let kerning = |left, right| font.data.kerning(left, right);
+        assert!(kerning('A', 'V').is_some_and(|kerning| kerning < 0.0));
+        assert!(kerning('T', 'o').is_some_and(|kerning| kerning < 0.0));
+        assert_eq!(kerning('o', 'o'), None);
+    }
+
+    #[test]
+    fn atlas_grows_as_glyphs_are_added() {
+        let atlas = DynamicAtlas::new();
+        let tile = vec![7; 100 * 100];
+        let mut last = [0, 0];
+        while let Some(pos) = atlas.insert(&tile, 100, [0, 0], [100, 100]) {
+            last = pos;
+        }
+        let state = atlas.state.borrow();
+        assert_eq!(state.height, MAX_ATLAS_HEIGHT);
+        assert!(last[1] + 100 <= MAX_ATLAS_HEIGHT);
+        assert!(atlas.generation() > 0);
+        let start = (last[1] * ATLAS_WIDTH + last[0]) as usize;
+        assert_eq!(state.data[start], 7);
+    }
+}
@@ -1,10 +1,11 @@
 /* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
 /* Copyright © 2021 Violet Leonard */
 
-use std::{cell::OnceCell, rc::Rc};
+use std::{cell::OnceCell, fmt, rc::Rc};
 
 use crate::platforms::opengl::{
-    opengl_bindings::types::GLenum, OpenGlBindings, Texture, TextureSize,
+    opengl_bindings::types::GLenum, OpenGlBindings, PopulateTextureUtil,
+    Texture, TexturePixels, TextureSize,
 };
 
 #[derive(Debug)]
@@ -24,6 +25,39 @@ pub struct FontData {
     pub ascent: f32,
     pub capline: f32,
     pub descent: f32,
+    /// The kind of distance field stored in `texture`.
+    pub atlas_kind: AtlasKind,
+    /// Provides glyphs which are not in `glyphs`, for example by
+    /// rasterizing them on demand.
+    pub source: Option<Box<dyn GlyphSource>>,
+}
+
+/// The kind of distance field in a font's texture atlas.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AtlasKind {
+    /// A single-channel signed distance field, stored in the alpha
+    /// channel.
+    #[default]
+    Sdf,
+    /// A multi-channel signed distance field, stored in the red, green and
+    /// blue channels, which keeps the corners of glyphs sharp.
+    Msdf,
+}
+
+/// A source of glyphs for a font beyond those built into its atlas.
+///
+/// Glyphs from a source are positioned in one of the font's texture pages:
+/// page zero is the font's `texture`, and later pages are provided by the
+/// source.
+pub trait GlyphSource: fmt::Debug {
+    /// Find a glyph, along with the page of the font's textures it is in.
+    fn glyph(&self, ch: char) -> Option<(Glyph, usize)>;
+
+    fn kerning(&self, left: char, right: char) -> Option<f32>;
+
+    /// Get a page of the font's textures after the first.
+    fn page_texture(&self, page: usize) -> Option<Texture>;
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -55,13 +89,29 @@ impl FontData {
             })
             .map(|index| self.kerning[index].kerning)
             .ok()
+            .or_else(|| self.source.as_ref()?.kerning(left, right))
     }
 
-    pub fn glyph(&self, ch: char) -> Option<&Glyph> {
+    pub fn glyph(&self, ch: char) -> Option<Glyph> {
+        self.glyph_and_page(ch).map(|(glyph, _page)| glyph)
+    }
+
+    /// Find a glyph, along with the page of the font's textures it is in.
+    pub fn glyph_and_page(&self, ch: char) -> Option<(Glyph, usize)> {
         self.glyphs
             .binary_search_by_key(&ch, |glyph| glyph.ch)
-            .map(|index| &self.glyphs[index])
+            .map(|index| (self.glyphs[index], 0))
             .ok()
+            .or_else(|| self.source.as_ref()?.glyph(ch))
+    }
+
+    /// Get a page of the font's textures.  The first page is `texture`.
+    pub fn page_texture(&self, page: usize) -> Option<Texture> {
+        if page == 0 {
+            Some(self.texture.clone())
+        } else {
+            self.source.as_ref()?.page_texture(page)
+        }
     }
 }
 
@@ -72,25 +122,57 @@ impl Font {
         width: u16,
         height: u16,
         data: &[u8],
+    ) -> TextureSize {
+        Self::populate_atlas(gl, target, [width, height], data, AtlasKind::Sdf)
+    }
+
+    /// Like [`populate_font_atlas`](Self::populate_font_atlas), but `data`
+    /// is a multi-channel signed distance field with three bytes per pixel.
+    pub fn populate_msdf_font_atlas(
+        gl: &OpenGlBindings,
+        target: GLenum,
+        width: u16,
+        height: u16,
+        data: &[u8],
+    ) -> TextureSize {
+        Self::populate_atlas(
+            gl,
+            target,
+            [width, height],
+            data,
+            AtlasKind::Msdf,
+        )
+    }
+
+    pub(super) fn populate_atlas(
+        gl: &OpenGlBindings,
+        target: GLenum,
+        [width, height]: [u16; 2],
+        data: &[u8],
+        kind: AtlasKind,
     ) -> TextureSize {
         use crate::platforms::opengl::{
-            context::short_consts::{ALPHA, CLAMP_TO_EDGE, LINEAR},
+            context::short_consts::{ALPHA, CLAMP_TO_EDGE, LINEAR, RGB},
             opengl_bindings::{
                 TEXTURE_MAG_FILTER, TEXTURE_MIN_FILTER, TEXTURE_WRAP_S,
                 TEXTURE_WRAP_T, UNPACK_ALIGNMENT, UNSIGNED_BYTE,
             },
         };
 
+        let format = match kind {
+            AtlasKind::Sdf => ALPHA,
+            AtlasKind::Msdf => RGB,
+        };
         unsafe {
             gl.PixelStorei(UNPACK_ALIGNMENT, 1);
             gl.TexImage2D(
                 target,
                 0,
-                ALPHA.into(),
+                format.into(),
                 width.into(),
                 height.into(),
                 0,
-                ALPHA.into(),
+                format.into(),
                 UNSIGNED_BYTE,
                 data.as_ptr().cast(),
             );
@@ -99,6 +181,56 @@ impl Font {
             gl.TexParameteri(target, TEXTURE_WRAP_S, CLAMP_TO_EDGE.into());
             gl.TexParameteri(target, TEXTURE_WRAP_T, CLAMP_TO_EDGE.into());
         }
+        Self::atlas_size([width, height], kind)
+    }
+
+    /// Like [`populate_font_atlas`](Self::populate_font_atlas), but keep the
+    /// pixels in memory.
+    #[must_use]
+    pub fn font_atlas_pixels(
+        width: u16,
+        height: u16,
+        data: &[u8],
+    ) -> TexturePixels {
+        Self::atlas_pixels([width, height], data, AtlasKind::Sdf)
+    }
+
+    /// Like [`populate_msdf_font_atlas`](Self::populate_msdf_font_atlas),
+    /// but keep the pixels in memory.
+    #[must_use]
+    pub fn msdf_font_atlas_pixels(
+        width: u16,
+        height: u16,
+        data: &[u8],
+    ) -> TexturePixels {
+        Self::atlas_pixels([width, height], data, AtlasKind::Msdf)
+    }
+
+    pub(super) fn atlas_pixels(
+        size: [u16; 2],
+        data: &[u8],
+        kind: AtlasKind,
+    ) -> TexturePixels {
+        // match what OpenGL samples from ALPHA and RGB textures
+        let rgba = match kind {
+            AtlasKind::Sdf => {
+                PopulateTextureUtil::expand_rgba(size, 1, 1, data, |p| {
+                    [0, 0, 0, p[0]]
+                })
+            }
+            AtlasKind::Msdf => {
+                PopulateTextureUtil::expand_rgba(size, 1, 3, data, |p| {
+                    [p[0], p[1], p[2], 0xff]
+                })
+            }
+        };
+        TexturePixels {
+            size: Self::atlas_size(size, kind),
+            rgba,
+        }
+    }
+
+    fn atlas_size([width, height]: [u16; 2], kind: AtlasKind) -> TextureSize {
         TextureSize {
             image_width: width.into(),
             image_height: height.into(),
@@ -106,6 +238,7 @@ impl Font {
             texture_height: height,
             color_pow: 1.0,
             is_sdf: true,
+            is_msdf: kind == AtlasKind::Msdf,
         }
     }
 }
@@ -0,0 +1,154 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use super::{default_font, font::Font};
+
+/// The weight of a font, using the usual scale where 400 is regular and 700
+/// is bold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FontWeight(pub u16);
+
+impl FontWeight {
+    pub const REGULAR: Self = Self(400);
+    pub const BOLD: Self = Self(700);
+
+    /// Check if this weight should be displayed as bold.
+    #[must_use]
+    pub fn is_bold(self) -> bool {
+        self.0 >= 600
+    }
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        Self::REGULAR
+    }
+}
+
+/// A font selected from the registry, along with the weight and style it
+/// was registered with.
+pub(super) struct Face {
+    pub font: Rc<Font>,
+    pub weight: FontWeight,
+    pub italic: bool,
+}
+
+/// The fonts registered with an OpenGL context, selected by text styles by
+/// family name, weight, and italics.
+///
+/// Each [`OpenGlContext`](crate::platforms::opengl::OpenGlContext) owns a
+/// registry, which text laid out on the same thread uses once the context is
+/// created.  Platforms without a context can make a registry current
+/// themselves.
+#[derive(Default)]
+pub struct FontRegistry {
+    families: RefCell<HashMap<String, Vec<Face>>>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Weak<FontRegistry>> = RefCell::default();
+}
+
+impl FontRegistry {
+    /// Register a font as one face of a family.
+    ///
+    /// Registering a second font with the same family, weight, and style
+    /// replaces the first.
+    pub fn register_font(
+        &self,
+        family: &str,
+        weight: FontWeight,
+        italic: bool,
+        font: Rc<Font>,
+    ) {
+        let mut families = self.families.borrow_mut();
+        let faces = families.entry(family.to_owned()).or_default();
+        faces.retain(|face| face.weight != weight || face.italic != italic);
+        faces.push(Face {
+            font,
+            weight,
+            italic,
+        });
+    }
+
+    /// Find the registered font which best matches a family, weight, and
+    /// style.
+    ///
+    /// A face with the requested style is preferred, then the face with the
+    /// nearest weight.  Returns `None` if nothing is registered for the
+    /// family.
+    #[must_use]
+    pub fn find_font(
+        &self,
+        family: &str,
+        weight: FontWeight,
+        italic: bool,
+    ) -> Option<Rc<Font>> {
+        self.find_face(family, weight, italic).map(|face| face.font)
+    }
+
+    fn find_face(
+        &self,
+        family: &str,
+        weight: FontWeight,
+        italic: bool,
+    ) -> Option<Face> {
+        let families = self.families.borrow();
+        let face = families.get(family)?.iter().min_by_key(|face| {
+            (face.italic != italic, face.weight.0.abs_diff(weight.0))
+        })?;
+        Some(Face {
+            font: Rc::clone(&face.font),
+            weight: face.weight,
+            italic: face.italic,
+        })
+    }
+
+    /// Use this registry for the text laid out on this thread, until another
+    /// is made current or this one is dropped.
+    pub fn make_current(this: &Rc<Self>) {
+        CURRENT.with(|cell| *cell.borrow_mut() = Rc::downgrade(this));
+    }
+}
+
+/// Find the face which best matches a family, weight, and style, in the
+/// current registry.
+pub(super) fn find_face(
+    family: &str,
+    weight: FontWeight,
+    italic: bool,
+) -> Option<Face> {
+    let registry = CURRENT.with(|cell| cell.borrow().upgrade())?;
+    registry.find_face(family, weight, italic)
+}
+/// Select the font for a style, falling back to the default font if the
+/// family is unset or has nothing registered.
+pub(super) fn resolve(
+    family: Option<&str>,
+    weight: FontWeight,
+    italic: bool,
+) -> Face {
+    family
+        .and_then(|family| find_face(family, weight, italic))
+        .unwrap_or_else(|| {
+            if weight.is_bold() {
+                Face {
+                    font: default_font::default_bold_font(),
+                    weight: FontWeight::BOLD,
+                    italic: false,
+                }
+            } else {
+                Face {
+                    font: default_font::default_font(),
+                    weight: FontWeight::REGULAR,
+                    italic: false,
+                }
+            }
+        })
+}
@@ -16,9 +16,21 @@ use super::{
     renderer::{UvRect, UvRectValues},
 };
 
+#[cfg(any(
+    feature = "image-png",
+    feature = "image-jpeg",
+    feature = "image-qoi"
+))]
+mod image;
 mod populate;
 
-pub use populate::{PopulateTexture, PopulateTextureUtil};
+#[cfg(any(
+    feature = "image-png",
+    feature = "image-jpeg",
+    feature = "image-qoi"
+))]
+pub use image::{ImageFormat, ImageLoadError, ImagePopulator};
+pub use populate::{PopulateTexture, PopulateTextureUtil, TexturePixels};
 
 #[derive(Clone, Debug)]
 pub struct Texture {
@@ -81,7 +93,7 @@ impl Texture {
         }
     }
 
-    pub(super) fn get_uv_rect(
+    pub(in crate::platforms) fn get_uv_rect(
         &self,
         size: &TextureSize,
     ) -> super::renderer::UvRect {
@@ -122,6 +134,16 @@ pub struct TextureId {
     populator: Option<Rc<dyn PopulateTexture>>,
 }
 
+#[cfg(any(feature = "platform-software", feature = "platform-recording"))]
+impl TextureId {
+    /// The populator of the texture, or `None` for the solid color texture.
+    pub(in crate::platforms) fn populator(
+        &self,
+    ) -> Option<&Rc<dyn PopulateTexture>> {
+        self.populator.as_ref()
+    }
+}
+
 /*
 #[derive(Clone, Debug, Default)]
 enum Fallback {
@@ -136,7 +158,11 @@ enum Fallback {
 enum TextureState {
     Loading,
     Failed,
-    Ready { id: GLuint, size: TextureSize },
+    Ready {
+        id: GLuint,
+        size: TextureSize,
+        generation: u64,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -158,6 +184,11 @@ pub struct TextureSize {
 
     /// If this image represents a signed distance field.
     pub is_sdf: bool,
+
+    /// If this image represents a multi-channel signed distance field, in
+    /// which case the distance is the median of the red, green and blue
+    /// channels instead of the alpha channel.  Implies `is_sdf`.
+    pub is_msdf: bool,
 }
 
 #[derive(Debug)]
@@ -198,7 +229,7 @@ impl TextureCache {
             None => self.solid_color.as_ref(),
         };
         slot.and_then(|state| {
-            if let TextureState::Ready { id, size } = state {
+            if let TextureState::Ready { id, size, .. } = state {
                 Some((*id, size))
             } else {
                 None
@@ -221,26 +252,46 @@ impl TextureCache {
     pub fn run_populators(&mut self, gl: &OpenGlBindings) {
         let mut new_tex_id = None;
         for (key, state) in &mut self.set {
-            if matches!(state, TextureState::Loading) {
-                let id = new_tex_id.take().unwrap_or_else(|| {
+            let generation = key.populator.generation();
+            let existing_id = match *state {
+                TextureState::Loading => None,
+                TextureState::Ready {
+                    id,
+                    generation: populated,
+                    ..
+                } if populated != generation => Some(id),
+                _ => continue,
+            };
+            let id = existing_id.or_else(|| new_tex_id.take()).unwrap_or_else(
+                || {
                     let mut id_slot = 0;
                     unsafe {
                         gl.GenTextures(1, &mut id_slot);
                     }
                     id_slot
-                });
-                unsafe {
-                    gl.BindTexture(TEXTURE_2D, id);
+                },
+            );
+            unsafe {
+                gl.BindTexture(TEXTURE_2D, id);
+            }
+            match key.populator.populate(gl, TEXTURE_2D) {
+                Ok(size) => {
+                    *state = TextureState::Ready {
+                        id,
+                        size,
+                        generation,
+                    };
                 }
-                match key.populator.populate(gl, TEXTURE_2D) {
-                    Ok(size) => {
-                        *state = TextureState::Ready { id, size };
-                    }
-                    Err(msg) => {
-                        eprintln!("failed to load texture: {msg}");
+                Err(msg) => {
+                    eprintln!("failed to load texture: {msg}");
+                    if new_tex_id.is_none() {
                         new_tex_id = Some(id);
-                        *state = TextureState::Failed;
+                    } else {
+                        unsafe {
+                            gl.DeleteTextures(1, std::ptr::addr_of!(id));
+                        }
                     }
+                    *state = TextureState::Failed;
                 }
             }
         }
@@ -298,7 +349,9 @@ impl TextureCache {
                     texture_height: 2,
                     color_pow: 1.0,
                     is_sdf: false,
+                    is_msdf: false,
                 },
+                generation: 0,
             }
         });
         if let Some(id) = new_tex_id {
@@ -0,0 +1,593 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Decoding image files into textures.
+
+use std::{
+    borrow::Cow,
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use crate::platforms::opengl::{
+    opengl_bindings::types::GLenum, OpenGlBindings,
+};
+
+use super::{
+    PopulateTexture, PopulateTextureUtil, TexturePixels, TextureSize,
+};
+
+/// An image file format which can be decoded into a texture.
+///
+/// Each format is decoded only if the matching feature is enabled:
+/// `image-png`, `image-jpeg` or `image-qoi`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Qoi,
+}
+
+impl ImageFormat {
+    /// Detect the format of an image from the start of its data.
+    #[must_use]
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if data.starts_with(&[0xff, 0xd8, 0xff]) {
+            Some(Self::Jpeg)
+        } else if data.starts_with(b"qoif") {
+            Some(Self::Qoi)
+        } else {
+            None
+        }
+    }
+
+    fn feature(self) -> &'static str {
+        match self {
+            Self::Png => "image-png",
+            Self::Jpeg => "image-jpeg",
+            Self::Qoi => "image-qoi",
+        }
+    }
+}
+
+/// An error encountered while loading an image.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageLoadError {
+    /// Reading the image failed.
+    Io(io::Error),
+    /// The data is not in a recognized image format.
+    UnknownFormat,
+    /// The image is in a format whose feature is not enabled.
+    FormatNotEnabled(ImageFormat),
+    /// The image is larger than a texture can be.
+    TooLarge,
+    /// The image data is invalid or uses an unsupported encoding.
+    Decode(String),
+}
+
+impl fmt::Display for ImageLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read image: {err}"),
+            Self::UnknownFormat => f.write_str("unrecognized image format"),
+            Self::FormatNotEnabled(format) => write!(
+                f,
+                "loading {format:?} images requires the `{}` feature",
+                format.feature()
+            ),
+            Self::TooLarge => f.write_str("image is too large for a texture"),
+            Self::Decode(msg) => write!(f, "failed to decode image: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageLoadError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// The start of the key of an image held in memory, which is followed by
+/// the contents of the image.
+const BYTES_KEY_PREFIX: &[u8] = b"suzy-image:";
+
+#[derive(Debug)]
+enum Source {
+    Static(&'static [u8]),
+    /// The contents are stored in the key, after [`BYTES_KEY_PREFIX`].
+    Bytes,
+    File(PathBuf),
+}
+
+/// Populates a texture by decoding a PNG, JPEG or QOI image.
+///
+/// The size of the image is read from its header when the populator is
+/// created, and the image is decoded when the texture is first drawn.
+/// Images loaded from the same file, from the same static data, or from
+/// equal bytes, share a texture.
+pub struct ImagePopulator {
+    key: Vec<u8>,
+    source: Source,
+    format: ImageFormat,
+    size: [u16; 2],
+}
+
+impl ImagePopulator {
+    /// Create a populator for an image held in memory, like one included
+    /// with `include_bytes!`.
+    pub fn from_bytes(
+        data: impl Into<Cow<'static, [u8]>>,
+    ) -> Result<Self, ImageLoadError> {
+        let data = data.into();
+        let format =
+            ImageFormat::detect(&data).ok_or(ImageLoadError::UnknownFormat)?;
+        let size = read_size(format, &*data)?;
+        let (key, source) = match data {
+            Cow::Borrowed(data) => {
+                // static data can't change, so is identified by its address
+                let mut key = b"suzy-image-static:".to_vec();
+                key.extend_from_slice(&(data.as_ptr() as usize).to_le_bytes());
+                key.extend_from_slice(&data.len().to_le_bytes());
+                (key, Source::Static(data))
+            }
+            Cow::Owned(data) => {
+                let mut key =
+                    Vec::with_capacity(BYTES_KEY_PREFIX.len() + data.len());
+                key.extend_from_slice(BYTES_KEY_PREFIX);
+                key.extend_from_slice(&data);
+                (key, Source::Bytes)
+            }
+        };
+        Ok(Self {
+            key,
+            source,
+            format,
+            size,
+        })
+    }
+
+    /// Create a populator for an image file.
+    ///
+    /// Only the header is read now, the file is read again when the
+    /// texture is populated.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ImageLoadError> {
+        let path = path.as_ref();
+        let mut reader = BufReader::new(File::open(path)?);
+        let format = ImageFormat::detect(reader.fill_buf()?)
+            .ok_or(ImageLoadError::UnknownFormat)?;
+        let size = read_size(format, reader)?;
+        // different paths to the same file should share a texture
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
+        let mut key = b"suzy-image-file:".to_vec();
+        key.extend_from_slice(path.as_os_str().as_encoded_bytes());
+        Ok(Self {
+            key,
+            source: Source::File(path),
+            format,
+            size,
+        })
+    }
+
+    /// Get the format of the image.
+    #[must_use]
+    pub fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    /// Get the `[width, height]` of the image, in pixels.
+    #[must_use]
+    pub fn size(&self) -> [u16; 2] {
+        self.size
+    }
+
+    fn decode(&self) -> Result<Decoded, ImageLoadError> {
+        match &self.source {
+            Source::Static(data) => decode(self.format, *data),
+            Source::Bytes => {
+                decode(self.format, &self.key[BYTES_KEY_PREFIX.len()..])
+            }
+            Source::File(path) => {
+                decode(self.format, BufReader::new(File::open(path)?))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ImagePopulator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ImagePopulator");
+        match &self.source {
+            Source::Static(data) => debug.field("bytes", &data.len()),
+            Source::Bytes => debug
+                .field("bytes", &(self.key.len() - BYTES_KEY_PREFIX.len())),
+            Source::File(path) => debug.field("path", path),
+        };
+        debug
+            .field("format", &self.format)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+impl PopulateTexture for ImagePopulator {
+    fn populate(
+        &self,
+        gl: &OpenGlBindings,
+        target: GLenum,
+    ) -> Result<TextureSize, String> {
+        let image = self.decode().map_err(|err| err.to_string())?;
+        let Decoded {
+            width,
+            height,
+            has_alpha,
+            pixels,
+        } = image;
+        Ok(if has_alpha {
+            PopulateTextureUtil::populate_color_rgba(
+                gl, target, width, height, 1, &pixels,
+            )
+        } else {
+            PopulateTextureUtil::populate_color_rgb(
+                gl, target, width, height, 1, &pixels,
+            )
+        })
+    }
+
+    fn populate_pixels(&self) -> Result<TexturePixels, String> {
+        let image = self.decode().map_err(|err| err.to_string())?;
+        let Decoded {
+            width,
+            height,
+            has_alpha,
+            pixels,
+        } = image;
+        Ok(if has_alpha {
+            PopulateTextureUtil::pixels_color_rgba(width, height, 1, &pixels)
+        } else {
+            PopulateTextureUtil::pixels_color_rgb(width, height, 1, &pixels)
+        })
+    }
+
+    fn texture_key(&self) -> &[u8] {
+        &self.key
+    }
+
+    fn get_known_size(&self) -> Option<[f32; 2]> {
+        let [width, height] = self.size;
+        Some([width.into(), height.into()])
+    }
+
+    fn debug(&self) -> &dyn fmt::Debug {
+        self
+    }
+}
+
+/// A decoded image, as RGB or RGBA in rows from the bottom.
+#[derive(Debug)]
+struct Decoded {
+    width: u16,
+    height: u16,
+    has_alpha: bool,
+    pixels: Vec<u8>,
+}
+
+impl Decoded {
+    /// Convert 8-bit gray, gray and alpha, RGB or RGBA pixels in rows from
+    /// the top.
+    fn new(
+        width: u16,
+        height: u16,
+        channels: usize,
+        pixels: &[u8],
+    ) -> Result<Self, ImageLoadError> {
+        let row_len = usize::from(width) * channels;
+        if pixels.len() < row_len * usize::from(height) {
+            return Err(ImageLoadError::Decode("image data is short".into()));
+        }
+        let has_alpha = matches!(channels, 2 | 4);
+        let out_channels = if has_alpha { 4 } else { 3 };
+        let mut out = Vec::with_capacity(
+            usize::from(width) * usize::from(height) * out_channels,
+        );
+        let rows = pixels.chunks_exact(row_len.max(1));
+        for row in rows.take(height.into()).rev() {
+            match channels {
+                1 => out.extend(row.iter().flat_map(|&gray| [gray; 3])),
+                2 => out.extend(row.chunks_exact(2).flat_map(|pixel| {
+                    [pixel[0], pixel[0], pixel[0], pixel[1]]
+                })),
+                _ => out.extend_from_slice(row),
+            }
+        }
+        Ok(Self {
+            width,
+            height,
+            has_alpha,
+            pixels: out,
+        })
+    }
+}
+
+#[cfg(any(feature = "image-png", feature = "image-qoi"))]
+fn dimension(value: u32) -> Result<u16, ImageLoadError> {
+    use std::convert::TryFrom;
+
+    u16::try_from(value).map_err(|_| ImageLoadError::TooLarge)
+}
+
+fn decode_error(err: impl fmt::Display) -> ImageLoadError {
+    ImageLoadError::Decode(err.to_string())
+}
+
+fn read_size(
+    format: ImageFormat,
+    reader: impl BufRead,
+) -> Result<[u16; 2], ImageLoadError> {
+    match format {
+        #[cfg(feature = "image-png")]
+        ImageFormat::Png => {
+            let reader = png_reader(reader)?;
+            let info = reader.info();
+            Ok([dimension(info.width)?, dimension(info.height)?])
+        }
+        #[cfg(feature = "image-jpeg")]
+        ImageFormat::Jpeg => {
+            let (_, info) = jpeg_decoder(reader)?;
+            Ok([info.width, info.height])
+        }
+        #[cfg(feature = "image-qoi")]
+        ImageFormat::Qoi => {
+            let decoder =
+                qoi::Decoder::from_stream(reader).map_err(decode_error)?;
+            let header = decoder.header();
+            Ok([dimension(header.width)?, dimension(header.height)?])
+        }
+        #[allow(unreachable_patterns)]
+        _ => Err(ImageLoadError::FormatNotEnabled(format)),
+    }
+}
+
+fn decode(
+    format: ImageFormat,
+    reader: impl BufRead,
+) -> Result<Decoded, ImageLoadError> {
+    match format {
+        #[cfg(feature = "image-png")]
+        ImageFormat::Png => {
+            let mut reader = png_reader(reader)?;
+            let mut pixels = vec![0; reader.output_buffer_size()];
+            let frame =
+                reader.next_frame(&mut pixels).map_err(decode_error)?;
+            let channels = match frame.color_type {
+                png::ColorType::Grayscale => 1,
+                png::ColorType::GrayscaleAlpha => 2,
+                png::ColorType::Rgb => 3,
+                png::ColorType::Rgba => 4,
+                png::ColorType::Indexed => {
+                    return Err(decode_error("palette was not expanded"))
+                }
+            };
+            let width = dimension(frame.width)?;
+            let height = dimension(frame.height)?;
+            Decoded::new(width, height, channels, &pixels)
+        }
+        #[cfg(feature = "image-jpeg")]
+        ImageFormat::Jpeg => {
+            use jpeg_decoder::PixelFormat;
+
+            let (mut decoder, info) = jpeg_decoder(reader)?;
+            let pixels = decoder.decode().map_err(decode_error)?;
+            let (channels, pixels) = match info.pixel_format {
+                PixelFormat::L8 => (1, pixels),
+                PixelFormat::L16 => {
+                    let gray = pixels.chunks_exact(2).map(|bytes| {
+                        let value = u16::from_ne_bytes([bytes[0], bytes[1]]);
+                        value.to_be_bytes()[0]
+                    });
+                    (1, gray.collect())
+                }
+                PixelFormat::RGB24 => (3, pixels),
+                PixelFormat::CMYK32 => {
+                    let rgb = pixels.chunks_exact(4).flat_map(|cmyk| {
+                        let white = 255 - u16::from(cmyk[3]);
+                        let channel = |ink: u8| {
+                            let value = (255 - u16::from(ink)) * white / 255;
+                            value.to_le_bytes()[0]
+                        };
+                        [channel(cmyk[0]), channel(cmyk[1]), channel(cmyk[2])]
+                    });
+                    (3, rgb.collect())
+                }
+            };
+            Decoded::new(info.width, info.height, channels, &pixels)
+        }
+        #[cfg(feature = "image-qoi")]
+        ImageFormat::Qoi => {
+            let mut decoder =
+                qoi::Decoder::from_stream(reader).map_err(decode_error)?;
+            let header = *decoder.header();
+            let pixels = decoder.decode_to_vec().map_err(decode_error)?;
+            let width = dimension(header.width)?;
+            let height = dimension(header.height)?;
+            let channels = header.channels.as_u8().into();
+            Decoded::new(width, height, channels, &pixels)
+        }
+        #[allow(unreachable_patterns)]
+        _ => Err(ImageLoadError::FormatNotEnabled(format)),
+    }
+}
+
+#[cfg(feature = "image-png")]
+fn png_reader<R: io::Read>(
+    reader: R,
+) -> Result<png::Reader<R>, ImageLoadError> {
+    let mut decoder = png::Decoder::new(reader);
+    // expand palettes and low bit depths, and strip 16-bit samples to 8
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    decoder.read_info().map_err(decode_error)
+}
+
+#[cfg(feature = "image-jpeg")]
+fn jpeg_decoder<R: io::Read>(
+    reader: R,
+) -> Result<(jpeg_decoder::Decoder<R>, jpeg_decoder::ImageInfo), ImageLoadError>
+{
+    let mut decoder = jpeg_decoder::Decoder::new(reader);
+    decoder.read_info().map_err(decode_error)?;
+    let info = decoder
+        .info()
+        .ok_or_else(|| decode_error("missing image header"))?;
+    Ok((decoder, info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_formats() {
+        let detect = ImageFormat::detect;
+        assert_eq!(detect(b"\x89PNG\r\n\x1a\n...."), Some(ImageFormat::Png));
+        assert_eq!(detect(b"\xff\xd8\xff\xe0.."), Some(ImageFormat::Jpeg));
+        assert_eq!(detect(b"qoif...."), Some(ImageFormat::Qoi));
+        assert_eq!(detect(b"GIF89a"), None);
+        let err = ImagePopulator::from_bytes(&b"not an image"[..])
+            .expect_err("unknown format");
+        assert!(matches!(err, ImageLoadError::UnknownFormat));
+    }
+
+    #[test]
+    fn rows_are_flipped_and_expanded() {
+        let image = Decoded::new(2, 2, 2, &[1, 2, 3, 4, 5, 6, 7, 8])
+            .expect("pixels should convert");
+        assert!(image.has_alpha);
+        assert_eq!(
+            image.pixels,
+            [5, 5, 5, 6, 7, 7, 7, 8, 1, 1, 1, 2, 3, 3, 3, 4]
+        );
+        assert!(Decoded::new(2, 2, 3, &[0; 11]).is_err());
+    }
+
+    #[cfg(feature = "image-png")]
+    fn encode_png(
+        size: [u32; 2],
+        color: png::ColorType,
+        depth: png::BitDepth,
+        palette: Option<&[u8]>,
+        data: &[u8],
+    ) -> Vec<u8> {
+        let mut out = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut out, size[0], size[1]);
+            encoder.set_color(color);
+            encoder.set_depth(depth);
+            if let Some(palette) = palette {
+                encoder.set_palette(palette.to_vec());
+            }
+            let mut writer = encoder.write_header().expect("png header");
+            writer.write_image_data(data).expect("png data");
+        }
+        out
+    }
+
+    #[cfg(feature = "image-png")]
+    #[test]
+    fn decode_png() {
+        use png::{BitDepth, ColorType};
+
+        let palette = [255, 0, 0, 0, 0, 255];
+        let data = encode_png(
+            [2, 2],
+            ColorType::Indexed,
+            BitDepth::Eight,
+            Some(&palette),
+            &[0, 1, 1, 1],
+        );
+        let populator =
+            ImagePopulator::from_bytes(data).expect("png should load");
+        assert_eq!(populator.format(), ImageFormat::Png);
+        assert_eq!(populator.get_known_size(), Some([2.0, 2.0]));
+        let image = populator.decode().expect("png should decode");
+        assert!(!image.has_alpha);
+        assert_eq!(image.pixels, [0, 0, 255, 0, 0, 255, 255, 0, 0, 0, 0, 255]);
+        // 16-bit gray is reduced to its high byte
+        let data = encode_png(
+            [1, 2],
+            ColorType::Grayscale,
+            BitDepth::Sixteen,
+            None,
+            &[0x12, 0x34, 0xab, 0xcd],
+        );
+        let image = ImagePopulator::from_bytes(data)
+            .and_then(|populator| populator.decode())
+            .expect("png should decode");
+        assert_eq!(image.pixels, [0xab, 0xab, 0xab, 0x12, 0x12, 0x12]);
+    }
+
+    #[cfg(feature = "image-qoi")]
+    #[test]
+    fn decode_qoi() {
+        let pixels = [1, 2, 3, 4, 5, 6, 7, 8];
+        let data = qoi::encode_to_vec(pixels, 1, 2).expect("qoi encoding");
+        let populator =
+            ImagePopulator::from_bytes(data.clone()).expect("qoi should load");
+        assert_eq!(populator.size(), [1, 2]);
+        let image = populator.decode().expect("qoi should decode");
+        assert!(image.has_alpha);
+        assert_eq!(image.pixels, [5, 6, 7, 8, 1, 2, 3, 4]);
+        // the same contents share a texture
+        let again = ImagePopulator::from_bytes(data).expect("qoi should load");
+        assert_eq!(populator.texture_key(), again.texture_key());
+        let other = qoi::encode_to_vec([0; 8], 1, 2).expect("qoi encoding");
+        let other =
+            ImagePopulator::from_bytes(other).expect("qoi should load");
+        assert_ne!(populator.texture_key(), other.texture_key());
+    }
+
+    #[cfg(feature = "image-qoi")]
+    #[test]
+    fn static_bytes_share_a_texture() {
+        let data = qoi::encode_to_vec([3; 4], 1, 1).expect("qoi encoding");
+        let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+        let populator =
+            ImagePopulator::from_bytes(data).expect("qoi should load");
+        let again = ImagePopulator::from_bytes(data).expect("qoi should load");
+        assert_eq!(populator.texture_key(), again.texture_key());
+        let image = populator.decode().expect("qoi should decode");
+        assert_eq!(image.pixels, [3; 4]);
+    }
+
+    #[cfg(feature = "image-qoi")]
+    #[test]
+    fn open_file() {
+        let data = qoi::encode_to_vec([9; 3], 1, 1).expect("qoi encoding");
+        let dir = std::env::temp_dir();
+        let path =
+            dir.join(format!("suzy-image-test-{}.qoi", std::process::id()));
+        std::fs::write(&path, data).expect("write test image");
+        let populator = ImagePopulator::open(&path).expect("file should load");
+        let image = populator.decode();
+        let same = ImagePopulator::open(
+            dir.join(".").join(path.file_name().unwrap_or_default()),
+        );
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(image.expect("file should decode").pixels, [9; 3]);
+        let same = same.expect("file should load");
+        assert_eq!(populator.texture_key(), same.texture_key());
+    }
+}
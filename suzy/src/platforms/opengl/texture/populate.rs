@@ -39,12 +39,27 @@ pub trait PopulateTexture {
 
     fn texture_key(&self) -> &[u8];
 
+    /// A populator whose contents change over time can return a new value
+    /// here to have the texture populated again before it is next drawn.
+    fn generation(&self) -> u64 {
+        0
+    }
+
     /// This function should return Some, if the populator can perfectly
     /// determine the size the texture will be without loading it.
     fn get_known_size(&self) -> Option<[f32; 2]> {
         None
     }
 
+    /// Decode the texture into memory instead of uploading it, for render
+    /// platforms which draw without a GPU.
+    ///
+    /// The default implementation fails, as not every populator can produce
+    /// its pixels without OpenGL.
+    fn populate_pixels(&self) -> Result<TexturePixels, String> {
+        Err("this texture can only be populated by OpenGL".to_string())
+    }
+
     /// An implementation may override this with a better debug implementation.
     fn debug(&self) -> &dyn fmt::Debug {
         &DEFAULT_POPULATE_DEBUG
@@ -71,6 +86,18 @@ impl Hash for dyn PopulateTexture {
     }
 }
 
+/// A texture which has been populated in memory, see
+/// [`PopulateTexture::populate_pixels`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TexturePixels {
+    /// The size of the texture, as if it had been populated by OpenGL.
+    pub size: TextureSize,
+
+    /// RGBA pixels, `texture_width` by `texture_height`, in rows starting
+    /// from the bottom of the image.
+    pub rgba: Vec<u8>,
+}
+
 /// This type provides some helper functions for common texture populator
 /// needs.
 pub struct PopulateTextureUtil;
@@ -120,6 +147,7 @@ impl PopulateTextureUtil {
                 texture_height: height,
                 color_pow: 2.2,
                 is_sdf: sdf,
+                is_msdf: false,
             }
         } else {
             let texture_width = width.next_power_of_two();
@@ -156,6 +184,7 @@ impl PopulateTextureUtil {
                 texture_height,
                 color_pow: 2.2,
                 is_sdf: sdf,
+                is_msdf: false,
             }
         }
     }
@@ -200,6 +229,82 @@ impl PopulateTextureUtil {
         let size = [width, height];
         Self::populate_format(gl, target, RGBA, size, alignment, false, pixels)
     }
+
+    /// Like [`populate_color_rgb`](Self::populate_color_rgb), but keep the
+    /// pixels in memory.
+    #[must_use]
+    pub fn pixels_color_rgb(
+        width: u16,
+        height: u16,
+        alignment: u16,
+        pixels: &[u8],
+    ) -> TexturePixels {
+        assert_eq!(pixels.len(), Self::data_len(width, height, alignment, 3));
+        let rgba =
+            Self::expand_rgba([width, height], alignment, 3, pixels, |p| {
+                [p[0], p[1], p[2], 0xff]
+            });
+        Self::color_pixels([width, height], rgba)
+    }
+
+    /// Like [`populate_color_rgba`](Self::populate_color_rgba), but keep the
+    /// pixels in memory.
+    #[must_use]
+    pub fn pixels_color_rgba(
+        width: u16,
+        height: u16,
+        alignment: u16,
+        pixels: &[u8],
+    ) -> TexturePixels {
+        assert_eq!(pixels.len(), Self::data_len(width, height, alignment, 4));
+        let rgba =
+            Self::expand_rgba([width, height], alignment, 4, pixels, |p| {
+                [p[0], p[1], p[2], p[3]]
+            });
+        Self::color_pixels([width, height], rgba)
+    }
+
+    fn color_pixels(
+        [width, height]: [u16; 2],
+        rgba: Vec<u8>,
+    ) -> TexturePixels {
+        TexturePixels {
+            size: TextureSize {
+                image_width: width.into(),
+                image_height: height.into(),
+                texture_width: width,
+                texture_height: height,
+                color_pow: 2.2,
+                is_sdf: false,
+                is_msdf: false,
+            },
+            rgba,
+        }
+    }
+
+    /// Convert rows of pixels with any number of channels to tightly packed
+    /// RGBA pixels.
+    pub(in crate::platforms::opengl) fn expand_rgba<F>(
+        [width, height]: [u16; 2],
+        alignment: u16,
+        channels: u16,
+        pixels: &[u8],
+        expand: F,
+    ) -> Vec<u8>
+    where
+        F: Fn(&[u8]) -> [u8; 4],
+    {
+        let row_len = Self::data_len(width, 1, alignment, channels);
+        let pixel_row_len = usize::from(width) * usize::from(channels);
+        let mut rgba =
+            Vec::with_capacity(usize::from(width) * usize::from(height) * 4);
+        for row in pixels.chunks(row_len).take(height.into()) {
+            for pixel in row[..pixel_row_len].chunks_exact(channels.into()) {
+                rgba.extend_from_slice(&expand(pixel));
+            }
+        }
+        rgba
+    }
 }
 
 #[derive(Clone, Copy, Default, PartialEq)]
@@ -13,7 +13,7 @@ use super::{
         BLEND, COLOR_BUFFER_BIT, COLOR_CLEAR_VALUE, ONE_MINUS_SRC_ALPHA,
         PACK_ALIGNMENT, RGBA, SRC_ALPHA, UNSIGNED_BYTE, VIEWPORT,
     },
-    {Mat4, OpenGlContext, OpenGlRenderPlatform},
+    {FontRegistry, Mat4, OpenGlContext, OpenGlRenderPlatform},
 };
 
 pub struct Window {
@@ -27,6 +27,12 @@ impl Window {
         Window { ctx }
     }
 
+    /// Get the fonts registered with this window's context.
+    #[must_use]
+    pub fn fonts(&self) -> &FontRegistry {
+        self.ctx.fonts()
+    }
+
     pub fn clear_color(&mut self, color: Color) {
         let Color { r, g, b, a } = color;
         unsafe {
@@ -53,7 +59,7 @@ impl Window {
                 width.into(),
                 height.into(),
             );
-            self.ctx.mask.configure_for_size(
+            self.ctx.masks.configure_for_size(
                 &self.ctx.bindings,
                 width,
                 height,
@@ -0,0 +1,195 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! This module provides a render platform which records what is drawn as a
+//! list of commands, instead of drawing any pixels.
+//!
+//! This allows tests to check the structure of what an app draws, for
+//! example that a button's label has the right text and is centered in the
+//! button, without comparing pixels or needing a GPU.
+//!
+//! The graphics of this platform are shared with the
+//! [OpenGL platform](super::opengl), so text is laid out with real font
+//! metrics.  Laying out text needs a font, either registered with
+//! a [`FontRegistry`](super::opengl::FontRegistry) made current, or from
+//! the `default-font` feature.
+
+mod graphics;
+
+use std::ops::Range;
+
+use crate::{
+    dims::{Padding2d, SimpleRect},
+    graphics::{Color, CornerStyle, ShapeOutline},
+    text::Layout,
+};
+
+pub use super::opengl::{
+    Mask, Mat4, Shape, SlicedImage, Text, TextStyle, Transform,
+};
+pub use graphics::DrawContext;
+
+/// A render platform which records draw commands with a [`Recorder`].
+pub struct RecordingRenderPlatform;
+
+impl crate::platform::RenderPlatform for RecordingRenderPlatform {
+    type DrawContextBuilder = fn(&mut ()) -> DrawContext<'_>;
+
+    type SlicedImage = SlicedImage;
+    type Shape = Shape;
+    type TextStyle = TextStyle;
+    type Text = Text;
+}
+
+/// Collects the commands drawn by a [`RecordingRenderPlatform`] app.
+#[derive(Clone, Debug, Default)]
+pub struct Recorder {
+    commands: Vec<DrawCommand>,
+}
+
+impl Recorder {
+    /// Create a recorder with no commands.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw an app, replacing any commands recorded before.
+    pub fn draw_app(
+        &mut self,
+        app: &mut crate::app::App<RecordingRenderPlatform>,
+    ) -> &[DrawCommand] {
+        self.commands.clear();
+        app.draw(&mut DrawContext::new(self));
+        &self.commands
+    }
+
+    /// Update an app's watches, then record drawing it.
+    pub fn record(
+        &mut self,
+        app: &mut crate::app::App<RecordingRenderPlatform>,
+    ) -> &[DrawCommand] {
+        app.update_watches();
+        self.draw_app(app)
+    }
+
+    /// The commands recorded by the last draw.
+    #[must_use]
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Take the commands recorded by the last draw.
+    pub fn take_commands(&mut self) -> Vec<DrawCommand> {
+        std::mem::take(&mut self.commands)
+    }
+
+    /// Find the recorded text with the given content.
+    #[must_use]
+    pub fn find_text(&self, content: &str) -> Option<&RecordedText> {
+        self.commands
+            .iter()
+            .filter_map(|command| match &command.graphic {
+                RecordedGraphic::Text(text) => Some(text),
+                RecordedGraphic::Image(_) | RecordedGraphic::Shape(_) => None,
+            })
+            .find(|text| text.content == content)
+    }
+}
+
+/// A graphic drawn by a [`RecordingRenderPlatform`] app, with the masks
+/// and transforms it was drawn inside.
+#[derive(Clone, Debug)]
+pub struct DrawCommand {
+    pub graphic: RecordedGraphic,
+
+    /// The masks active when the graphic was drawn, outermost first.
+    pub masks: Vec<MaskEntry>,
+
+    /// The matrices of the transforms the graphic was drawn inside,
+    /// outermost first.
+    pub transforms: Vec<Mat4>,
+}
+
+impl DrawCommand {
+    /// Get the combined matrix of all the transforms the graphic was drawn
+    /// inside.
+    #[must_use]
+    pub fn matrix(&self) -> Mat4 {
+        self.transforms
+            .iter()
+            .fold(Mat4::identity(), |combined, matrix| combined * matrix)
+    }
+
+    /// Check if the graphic is being used as the shape of a mask, instead of
+    /// being drawn.
+    #[must_use]
+    pub fn is_mask_shape(&self) -> bool {
+        self.masks.last().is_some_and(|mask| mask.is_shape)
+    }
+}
+
+/// A mask which was active when a graphic was drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaskEntry {
+    /// Counts the masks pushed while drawing, starting from zero, to tell
+    /// them apart.
+    pub id: usize,
+
+    /// If the graphic was drawn as part of the shape of the mask, rather
+    /// than being clipped by it.
+    pub is_shape: bool,
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum RecordedGraphic {
+    Image(RecordedImage),
+    Shape(RecordedShape),
+    Text(RecordedText),
+}
+
+/// The state of a [`SlicedImage`] when it was drawn.
+#[derive(Clone, Debug)]
+pub struct RecordedImage {
+    pub rect: SimpleRect,
+    pub color: Color,
+    pub slice_padding: Padding2d,
+    pub corners: CornerStyle,
+
+    /// The key of the image's texture, or `None` for the solid color
+    /// texture.
+    pub texture_key: Option<Box<[u8]>>,
+}
+
+/// The state of a [`Shape`] when it was drawn.
+#[derive(Clone, Debug)]
+pub struct RecordedShape {
+    pub rect: SimpleRect,
+    pub outline: ShapeOutline,
+    pub fill_color: Color,
+    pub stroke_color: Color,
+    pub stroke_width: f32,
+}
+
+/// The state of a [`Text`] when it was drawn.
+#[derive(Clone, Debug)]
+pub struct RecordedText {
+    /// The text of all the spans.
+    pub content: String,
+    pub spans: Vec<RecordedSpan>,
+    pub layout: Layout,
+
+    /// The bounds of the laid-out lines, or `None` if there was no text.
+    pub bounds: Option<SimpleRect>,
+    pub is_truncated: bool,
+}
+
+/// A span of a [`RecordedText`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedSpan {
+    /// The byte range of the span in the text's content.
+    pub range: Range<usize>,
+    pub font_size: f32,
+    pub color: Color,
+}
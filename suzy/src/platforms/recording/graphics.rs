@@ -0,0 +1,129 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+mod mask;
+mod transform;
+
+use crate::{
+    dims::SimpleRect, graphics::Graphic, platform::graphics::Text as _,
+    platforms::opengl::Mat4,
+};
+
+use super::{
+    DrawCommand, MaskEntry, RecordedGraphic, RecordedImage, RecordedShape,
+    RecordedSpan, RecordedText, Recorder, RecordingRenderPlatform, Shape,
+    SlicedImage, Text,
+};
+
+pub struct DrawContext<'a> {
+    recorder: &'a mut Recorder,
+    masks: Vec<MaskEntry>,
+    transforms: Vec<Mat4>,
+    next_mask_id: usize,
+}
+
+impl<'a> DrawContext<'a> {
+    pub(super) fn new(recorder: &'a mut Recorder) -> Self {
+        Self {
+            recorder,
+            masks: Vec::new(),
+            transforms: Vec::new(),
+            next_mask_id: 0,
+        }
+    }
+
+    pub fn push_mask(&mut self) {
+        self.masks.push(MaskEntry {
+            id: self.next_mask_id,
+            is_shape: true,
+        });
+        self.next_mask_id += 1;
+    }
+
+    pub fn start_masking(&mut self) {
+        let mask = self
+            .masks
+            .last_mut()
+            .expect("attempted to start masking without first pushing a mask");
+        mask.is_shape = false;
+    }
+
+    pub fn pop_mask(&mut self) {
+        self.masks
+            .pop()
+            .expect("attempted to pop a mask without first pushing a mask");
+    }
+
+    pub fn push_transform(&mut self, matrix: Mat4) {
+        self.transforms.push(matrix);
+    }
+
+    pub fn pop_transform(&mut self) {
+        self.transforms.pop().expect(
+            "attempted to pop a transform without first pushing a transform",
+        );
+    }
+
+    /// Add a command for a graphic, drawn inside the current masks and
+    /// transforms.
+    pub fn record(&mut self, graphic: RecordedGraphic) {
+        self.recorder.commands.push(DrawCommand {
+            graphic,
+            masks: self.masks.clone(),
+            transforms: self.transforms.clone(),
+        });
+    }
+}
+
+impl Graphic<RecordingRenderPlatform> for SlicedImage {
+    fn draw(&mut self, ctx: &mut DrawContext<'_>) {
+        let texture_key = self
+            .texture
+            .id()
+            .populator()
+            .map(|populator| populator.texture_key().into());
+        ctx.record(RecordedGraphic::Image(RecordedImage {
+            rect: SimpleRect::from(&*self),
+            color: self.color(),
+            slice_padding: self.padding,
+            corners: self.corners(),
+            texture_key,
+        }));
+    }
+}
+
+impl Graphic<RecordingRenderPlatform> for Shape {
+    fn draw(&mut self, ctx: &mut DrawContext<'_>) {
+        ctx.record(RecordedGraphic::Shape(RecordedShape {
+            rect: SimpleRect::from(&*self),
+            outline: self.outline(),
+            fill_color: self.fill_color(),
+            stroke_color: self.stroke_color(),
+            stroke_width: self.stroke_width(),
+        }));
+    }
+}
+
+impl Graphic<RecordingRenderPlatform> for Text {
+    fn draw(&mut self, ctx: &mut DrawContext<'_>) {
+        let content = self.content();
+        let starts: Vec<_> = self.spans().map(|(start, _)| start).collect();
+        let ends = starts.iter().skip(1).copied().chain([content.len()]);
+        let spans = self
+            .spans()
+            .zip(ends)
+            .map(|((start, style), end)| RecordedSpan {
+                range: start..end,
+                font_size: style.font_size,
+                color: style.color(),
+            })
+            .collect();
+        ctx.record(RecordedGraphic::Text(RecordedText {
+            content: content.to_string(),
+            spans,
+            layout: self.layout(),
+            bounds: self.bounds(),
+            is_truncated: self.is_truncated(),
+        }));
+    }
+}
@@ -0,0 +1,48 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use crate::{
+    graphics::{DrawContext, Graphic},
+    platforms::{opengl::Mask, recording::RecordingRenderPlatform},
+    widget::WidgetGraphic,
+};
+
+pub struct Push<'a, T> {
+    mask: &'a mut Mask<T>,
+}
+
+pub struct Pop;
+
+impl<T> WidgetGraphic<RecordingRenderPlatform> for Mask<T>
+where
+    T: Graphic<RecordingRenderPlatform>,
+{
+    type BeforeGetter = fn(&mut ()) -> Push<'_, T>;
+
+    type AfterGetter = fn(&mut ()) -> Pop;
+
+    fn before_children(&mut self) -> Push<'_, T> {
+        Push { mask: self }
+    }
+
+    fn after_children(&mut self) -> Pop {
+        Pop
+    }
+}
+
+impl<T> Graphic<RecordingRenderPlatform> for Push<'_, T>
+where
+    T: Graphic<RecordingRenderPlatform>,
+{
+    fn draw(&mut self, ctx: &mut DrawContext<'_, RecordingRenderPlatform>) {
+        ctx.push_mask();
+        self.mask.graphic.draw(ctx);
+        ctx.start_masking();
+    }
+}
+
+impl Graphic<RecordingRenderPlatform> for Pop {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, RecordingRenderPlatform>) {
+        ctx.pop_mask();
+    }
+}
@@ -0,0 +1,39 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use crate::{
+    graphics::{DrawContext, Graphic},
+    platforms::{opengl::Transform, recording::RecordingRenderPlatform},
+    widget::WidgetGraphic,
+};
+
+pub struct Push<'a> {
+    trans: &'a Transform,
+}
+
+pub struct Pop;
+
+impl WidgetGraphic<RecordingRenderPlatform> for Transform {
+    type BeforeGetter = fn(&mut ()) -> Push<'_>;
+    type AfterGetter = fn(&mut ()) -> Pop;
+
+    fn before_children(&mut self) -> Push<'_> {
+        Push { trans: self }
+    }
+
+    fn after_children(&mut self) -> Pop {
+        Pop
+    }
+}
+
+impl Graphic<RecordingRenderPlatform> for Push<'_> {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, RecordingRenderPlatform>) {
+        ctx.push_transform(self.trans.matrix);
+    }
+}
+
+impl Graphic<RecordingRenderPlatform> for Pop {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, RecordingRenderPlatform>) {
+        ctx.pop_transform();
+    }
+}
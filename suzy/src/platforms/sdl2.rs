@@ -3,10 +3,16 @@
 
 #![allow(missing_docs)]
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    convert::TryFrom,
+    ops::{Deref, DerefMut},
+};
 
 use crate::{
     graphics::Color,
+    keyboard::{
+        Key, KeyAction, KeyEventData, Modifiers, ScanCode, TextInputData,
+    },
     platforms::opengl::OpenGlRenderPlatform,
     pointer::{AltMouseButton, PointerAction, PointerEventData, PointerId},
     watch::WatchedValueCore,
@@ -53,6 +59,7 @@ impl SdlPlatform {
                 }
             }
             app.update_watches();
+            window.set_text_input_area(app.text_input_area());
             window.gl_win.clear();
             window.gl_win.draw_app(app);
             window.flip();
@@ -184,6 +191,55 @@ impl AppHandleSdlEvent for crate::app::App<OpenGlRenderPlatform> {
                     y,
                 });
             }
+            Event::KeyDown {
+                keycode,
+                scancode,
+                keymod,
+                repeat,
+                ..
+            } => {
+                self.key_event(KeyEventData {
+                    key: keycode.map_or(Key::Unknown, ToSuzyKey::to_suzy_key),
+                    scancode: ScanCode(scancode.map_or(0, |sc| sc as u32)),
+                    modifiers: keymod.to_suzy_modifiers(),
+                    action: KeyAction::Down,
+                    repeat,
+                });
+            }
+            Event::KeyUp {
+                keycode,
+                scancode,
+                keymod,
+                repeat,
+                ..
+            } => {
+                self.key_event(KeyEventData {
+                    key: keycode.map_or(Key::Unknown, ToSuzyKey::to_suzy_key),
+                    scancode: ScanCode(scancode.map_or(0, |sc| sc as u32)),
+                    modifiers: keymod.to_suzy_modifiers(),
+                    action: KeyAction::Up,
+                    repeat,
+                });
+            }
+            Event::TextInput { text, .. } => {
+                self.text_input(TextInputData::Input(text));
+            }
+            Event::TextEditing {
+                text,
+                start,
+                length,
+                ..
+            } => {
+                // SDL reports the cursor in characters, rather than bytes
+                let byte_offset = |chars: i32| {
+                    let chars = usize::try_from(chars).unwrap_or(0);
+                    text.char_indices()
+                        .nth(chars)
+                        .map_or(text.len(), |(offset, _ch)| offset)
+                };
+                let cursor = byte_offset(start)..byte_offset(start + length);
+                self.text_input(TextInputData::Editing { text, cursor });
+            }
             _ => {}
         }
     }
@@ -214,6 +270,76 @@ impl ToSuzyMouseButton for sdl2::mouse::MouseButton {
     }
 }
 
+pub trait ToSuzyKey {
+    fn to_suzy_key(self) -> Key;
+}
+
+impl ToSuzyKey for sdl2::keyboard::Keycode {
+    fn to_suzy_key(self) -> Key {
+        use sdl2::keyboard::Keycode;
+        match self {
+            Keycode::Space => Key::Space,
+            Keycode::Return | Keycode::Return2 | Keycode::KpEnter => {
+                Key::Enter
+            }
+            Keycode::Tab => Key::Tab,
+            Keycode::Backspace => Key::Backspace,
+            Keycode::Delete => Key::Delete,
+            Keycode::Insert => Key::Insert,
+            Keycode::Escape => Key::Escape,
+            Keycode::Left => Key::Left,
+            Keycode::Right => Key::Right,
+            Keycode::Up => Key::Up,
+            Keycode::Down => Key::Down,
+            Keycode::Home => Key::Home,
+            Keycode::End => Key::End,
+            Keycode::PageUp => Key::PageUp,
+            Keycode::PageDown => Key::PageDown,
+            Keycode::F1 => Key::F(1),
+            Keycode::F2 => Key::F(2),
+            Keycode::F3 => Key::F(3),
+            Keycode::F4 => Key::F(4),
+            Keycode::F5 => Key::F(5),
+            Keycode::F6 => Key::F(6),
+            Keycode::F7 => Key::F(7),
+            Keycode::F8 => Key::F(8),
+            Keycode::F9 => Key::F(9),
+            Keycode::F10 => Key::F(10),
+            Keycode::F11 => Key::F(11),
+            Keycode::F12 => Key::F(12),
+            Keycode::LShift | Keycode::RShift => Key::Shift,
+            Keycode::LCtrl | Keycode::RCtrl => Key::Control,
+            Keycode::LAlt | Keycode::RAlt => Key::Alt,
+            Keycode::LGui | Keycode::RGui => Key::Meta,
+            Keycode::CapsLock => Key::CapsLock,
+            Keycode::Menu | Keycode::Application => Key::Menu,
+            // SDL keycodes for printable keys are the unicode codepoint of
+            // the unmodified character
+            other => u32::try_from(other as i32)
+                .ok()
+                .and_then(char::from_u32)
+                .filter(|ch| !ch.is_control())
+                .map_or(Key::Unknown, Key::Character),
+        }
+    }
+}
+
+pub trait ToSuzyModifiers {
+    fn to_suzy_modifiers(self) -> Modifiers;
+}
+
+impl ToSuzyModifiers for sdl2::keyboard::Mod {
+    fn to_suzy_modifiers(self) -> Modifiers {
+        use sdl2::keyboard::Mod;
+        Modifiers {
+            shift: self.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD),
+            ctrl: self.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD),
+            alt: self.intersects(Mod::LALTMOD | Mod::RALTMOD),
+            meta: self.intersects(Mod::LGUIMOD | Mod::RGUIMOD),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub(super) struct TestEnvironment;
 
@@ -5,7 +5,11 @@ use std::convert::TryInto;
 
 use sdl2::video::WindowBuildError;
 
-use crate::{graphics::Color, platforms::opengl};
+use crate::{
+    dims::{Rect, SimpleRect},
+    graphics::Color,
+    platforms::opengl,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct WindowSettings<'a> {
@@ -27,8 +31,9 @@ impl Default for WindowSettings<'static> {
 }
 
 pub struct Window {
-    _video: sdl2::VideoSubsystem,
+    video: sdl2::VideoSubsystem,
     pub(super) window: sdl2::video::Window,
+    text_input_rect: Option<sdl2::rect::Rect>,
     _context: sdl2::video::GLContext,
     pub gl_win: opengl::Window,
 }
@@ -70,9 +75,13 @@ impl Window {
         };
         let mut gl_win = opengl::Window::new(plat_gl_context);
         gl_win.clear_color(settings.background_color);
+        // text input is enabled by default; it will be re-enabled when a
+        // widget which accepts text input gains focus
+        video.text_input().stop();
         Ok(Window {
-            _video: video,
+            video,
             window,
+            text_input_rect: None,
             _context: context,
             gl_win,
         })
@@ -96,6 +105,35 @@ impl Window {
         self.gl_win.clear_color(color);
     }
 
+    /// Start or stop text input, depending on if an area is provided.
+    ///
+    /// The area is used to position the input method's candidate window.
+    pub fn set_text_input_area(&mut self, area: Option<SimpleRect>) {
+        let text_input = self.video.text_input();
+        let rect = area.map(|area| {
+            let [_, height] = self.logical_size();
+            sdl2::rect::Rect::new(
+                area.left() as i32,
+                (height - area.top()) as i32,
+                area.width().max(1.0) as u32,
+                area.height().max(1.0) as u32,
+            )
+        });
+        if rect == self.text_input_rect {
+            return;
+        }
+        match rect {
+            Some(rect) => {
+                text_input.set_rect(rect);
+                if !text_input.is_active() {
+                    text_input.start();
+                }
+            }
+            None => text_input.stop(),
+        }
+        self.text_input_rect = rect;
+    }
+
     pub(super) fn dpi(&self) -> [f32; 2] {
         let (_, hdpi, vdpi) = self
             .window
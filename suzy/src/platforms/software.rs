@@ -0,0 +1,37 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! This module provides a render platform which draws on the CPU, into an
+//! RGBA buffer in memory.
+//!
+//! It needs no GPU, window or native libraries, which makes it useful for
+//! checking what an app draws pixel-by-pixel in tests, and as a fallback on
+//! machines without working OpenGL drivers.
+//!
+//! The graphics of this platform are shared with the
+//! [OpenGL platform](super::opengl), and the same math the OpenGL shader
+//! uses is done for each pixel.  Textures are loaded with
+//! [`PopulateTexture::populate_pixels`](super::opengl::PopulateTexture::populate_pixels).
+
+mod canvas;
+mod graphics;
+mod raster;
+mod texture;
+
+pub use super::opengl::{
+    Mask, Shape, SlicedImage, Text, TextStyle, Transform,
+};
+pub use canvas::Canvas;
+pub use graphics::DrawContext;
+
+/// A render platform which draws on the CPU, into a [`Canvas`].
+pub struct SoftwareRenderPlatform;
+
+impl crate::platform::RenderPlatform for SoftwareRenderPlatform {
+    type DrawContextBuilder = fn(&mut ()) -> DrawContext<'_>;
+
+    type SlicedImage = SlicedImage;
+    type Shape = Shape;
+    type TextStyle = TextStyle;
+    type Text = Text;
+}
@@ -0,0 +1,111 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use crate::{graphics::Color, platforms::opengl::Mat4};
+
+use super::{texture::TextureCache, DrawContext, SoftwareRenderPlatform};
+
+/// An RGBA buffer which a [`SoftwareRenderPlatform`] app draws into, in
+/// place of a window.
+///
+/// Pixels are stored sRGB encoded, four bytes each, in rows starting from
+/// the bottom, the same as a screenshot of an OpenGL window.
+pub struct Canvas {
+    pub(super) width: u16,
+    pub(super) height: u16,
+    pub(super) pixels: Vec<u8>,
+    /// The alpha of each pushed mask, innermost last.
+    pub(super) masks: Vec<Vec<u8>>,
+    pub(super) textures: TextureCache,
+    clear_color: Color,
+}
+
+impl Canvas {
+    /// Create a canvas of the given size, in pixels.
+    #[must_use]
+    pub fn new(width: u16, height: u16) -> Self {
+        let len = usize::from(width) * usize::from(height);
+        Self {
+            width,
+            height,
+            pixels: vec![0; len * 4],
+            masks: Vec::new(),
+            textures: TextureCache::default(),
+            clear_color: Color::from_rgba(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// The width of the canvas, in pixels.
+    #[must_use]
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// The height of the canvas, in pixels.
+    #[must_use]
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Change the size of the canvas, clearing it.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        let len = usize::from(width) * usize::from(height);
+        self.width = width;
+        self.height = height;
+        self.pixels.resize(len * 4, 0);
+        for mask in &mut self.masks {
+            mask.resize(len, 0);
+        }
+        self.clear();
+    }
+
+    pub fn clear_color(&mut self, color: Color) {
+        self.clear_color = color;
+    }
+
+    #[must_use]
+    pub fn get_clear_color(&self) -> Color {
+        self.clear_color
+    }
+
+    /// Fill the canvas with the clear color.
+    pub fn clear(&mut self) {
+        let color = self.clear_color.rgba8();
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&color);
+        }
+    }
+
+    pub fn draw_app(
+        &mut self,
+        app: &mut crate::app::App<SoftwareRenderPlatform>,
+    ) {
+        use crate::watch::WatchedValueCore;
+        let screen_width = app.state().window_width().get_unwatched();
+        let screen_height = app.state().window_height().get_unwatched();
+        let matrix = Mat4::translate(-1.0, -1.0)
+            * Mat4::scale(2.0 / screen_width, 2.0 / screen_height);
+        app.draw(&mut DrawContext::new(self, matrix));
+    }
+
+    /// The pixels of the canvas.
+    #[must_use]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    #[must_use]
+    pub fn take_screenshot(&self) -> Box<[u8]> {
+        self.pixels.clone().into_boxed_slice()
+    }
+
+    pub fn draw_and_take_screenshot(
+        &mut self,
+        app: &mut crate::app::App<SoftwareRenderPlatform>,
+    ) -> Box<[u8]> {
+        app.update_watches();
+        self.clear();
+        self.draw_app(app);
+        self.take_screenshot()
+    }
+}
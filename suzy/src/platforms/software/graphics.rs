@@ -0,0 +1,165 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+mod mask;
+mod transform;
+
+use crate::{
+    graphics::Graphic,
+    platforms::opengl::{
+        renderer::{UvRect, UvType, Vertex, VertexVec},
+        Mat4, Shape, SlicedImage, Text, Texture,
+    },
+};
+
+use super::{
+    raster::{self, Target},
+    Canvas, SoftwareRenderPlatform,
+};
+
+pub struct DrawContext<'a> {
+    canvas: &'a mut Canvas,
+    matrix: Mat4,
+    /// The number of masks pushed.
+    masks: usize,
+    /// If the shape of the innermost mask is being drawn.
+    drawing_mask: bool,
+}
+
+impl<'a> DrawContext<'a> {
+    pub(super) fn new(canvas: &'a mut Canvas, matrix: Mat4) -> Self {
+        Self {
+            canvas,
+            matrix,
+            masks: 0,
+            drawing_mask: false,
+        }
+    }
+
+    pub fn push_mask(&mut self) {
+        assert!(
+            !self.drawing_mask,
+            "attempting to push a mask while drawing the shape of another mask",
+        );
+        self.masks += 1;
+        self.drawing_mask = true;
+        let len =
+            usize::from(self.canvas.width) * usize::from(self.canvas.height);
+        let levels = &mut self.canvas.masks;
+        if levels.len() < self.masks {
+            levels.resize_with(self.masks, || vec![0; len]);
+        }
+        levels[self.masks - 1].fill(0);
+    }
+
+    pub fn start_masking(&mut self) {
+        assert!(
+            self.masks > 0,
+            "attempted to start masking without first pushing a mask",
+        );
+        self.drawing_mask = false;
+    }
+
+    pub fn pop_mask(&mut self) {
+        assert!(
+            self.masks > 0,
+            "attempted to pop a mask without first pushing a mask",
+        );
+        self.masks -= 1;
+        self.drawing_mask = false;
+    }
+
+    pub fn update_matrix<F>(&mut self, f: F)
+    where
+        F: FnOnce(Mat4) -> Mat4,
+    {
+        self.matrix = f(self.matrix);
+    }
+
+    /// Get the uv rect of a texture, loading it if needed.  Returns `None`
+    /// if the texture failed to load.
+    pub fn uv_rect(&mut self, texture: &Texture) -> Option<UvRect> {
+        let pixels = self.canvas.textures.lookup(&texture.id())?;
+        Some(texture.get_uv_rect(&pixels.size))
+    }
+
+    /// Draw indexed triangles with a texture, loading it if needed.
+    pub fn draw_triangles<Uv: UvType>(
+        &mut self,
+        texture: &Texture,
+        vertices: &[Vertex<Uv>],
+        indices: &[u16],
+    ) {
+        let canvas = &mut *self.canvas;
+        let Some(pixels) = canvas.textures.lookup(&texture.id()) else {
+            return;
+        };
+        // the shape of a mask is clipped by the mask containing it, and
+        // anything else by the innermost mask
+        let masks = &mut canvas.masks;
+        let (mask, clip) = if self.drawing_mask {
+            let (outer, inner) = masks.split_at_mut(self.masks - 1);
+            (Some(&mut inner[0][..]), outer.last().map(|mask| &mask[..]))
+        } else {
+            let clip = self.masks.checked_sub(1).map(|i| &masks[i][..]);
+            (None, clip)
+        };
+        let mut target = Target {
+            width: canvas.width,
+            height: canvas.height,
+            pixels: &mut canvas.pixels,
+            mask,
+            clip,
+        };
+        raster::draw_triangles(
+            &mut target,
+            pixels,
+            &self.matrix,
+            vertices,
+            indices,
+        );
+    }
+
+    fn draw_vertex_vec(
+        &mut self,
+        texture: &Texture,
+        vertices: &VertexVec,
+        indices: &[u16],
+    ) {
+        match vertices {
+            VertexVec::U16(vec) => self.draw_triangles(texture, vec, indices),
+            VertexVec::F32(vec) => self.draw_triangles(texture, vec, indices),
+        }
+    }
+}
+
+impl Graphic<SoftwareRenderPlatform> for SlicedImage {
+    fn draw(&mut self, ctx: &mut DrawContext<'_>) {
+        if let Some(uv_rect) = ctx.uv_rect(&self.texture) {
+            let mut vertices = VertexVec::default();
+            let mut indices = Vec::new();
+            self.push_geometry(uv_rect, &mut vertices, &mut indices);
+            ctx.draw_vertex_vec(&self.texture, &vertices, &indices);
+        }
+    }
+}
+
+impl Graphic<SoftwareRenderPlatform> for Shape {
+    fn draw(&mut self, ctx: &mut DrawContext<'_>) {
+        let texture = Texture::solid_color();
+        if let Some(uv_rect) = ctx.uv_rect(&texture) {
+            let mut vertices = VertexVec::default();
+            let mut indices = Vec::new();
+            self.push_geometry(uv_rect, &mut vertices, &mut indices);
+            ctx.draw_vertex_vec(&texture, &vertices, &indices);
+        }
+    }
+}
+
+impl Graphic<SoftwareRenderPlatform> for Text {
+    fn draw(&mut self, ctx: &mut DrawContext<'_>) {
+        for (texture, vertices, indices) in self.meshes() {
+            ctx.draw_triangles(texture, vertices, indices);
+        }
+    }
+}
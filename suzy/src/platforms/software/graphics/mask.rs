@@ -0,0 +1,48 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use crate::{
+    graphics::{DrawContext, Graphic},
+    platforms::{opengl::Mask, software::SoftwareRenderPlatform},
+    widget::WidgetGraphic,
+};
+
+pub struct Push<'a, T> {
+    mask: &'a mut Mask<T>,
+}
+
+pub struct Pop;
+
+impl<T> WidgetGraphic<SoftwareRenderPlatform> for Mask<T>
+where
+    T: Graphic<SoftwareRenderPlatform>,
+{
+    type BeforeGetter = fn(&mut ()) -> Push<'_, T>;
+
+    type AfterGetter = fn(&mut ()) -> Pop;
+
+    fn before_children(&mut self) -> Push<'_, T> {
+        Push { mask: self }
+    }
+
+    fn after_children(&mut self) -> Pop {
+        Pop
+    }
+}
+
+impl<T> Graphic<SoftwareRenderPlatform> for Push<'_, T>
+where
+    T: Graphic<SoftwareRenderPlatform>,
+{
+    fn draw(&mut self, ctx: &mut DrawContext<'_, SoftwareRenderPlatform>) {
+        ctx.push_mask();
+        self.mask.graphic.draw(ctx);
+        ctx.start_masking();
+    }
+}
+
+impl Graphic<SoftwareRenderPlatform> for Pop {
+    fn draw(&mut self, ctx: &mut DrawContext<'_, SoftwareRenderPlatform>) {
+        ctx.pop_mask();
+    }
+}
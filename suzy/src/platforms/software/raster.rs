@@ -0,0 +1,388 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use crate::{
+    graphics::Color,
+    platforms::opengl::{
+        renderer::{UvType, Vertex},
+        Mat4, TexturePixels,
+    },
+    units::QuantizeU8,
+};
+
+pub(super) struct Target<'a> {
+    pub width: u16,
+    pub height: u16,
+    /// sRGB encoded RGBA pixels, in rows from the bottom.
+    pub pixels: &'a mut [u8],
+    /// The alpha of a mask for each pixel, to draw into instead of the
+    /// pixels, like drawing the shape of a mask in the OpenGL renderer.
+    pub mask: Option<&'a mut [u8]>,
+    /// The alpha of the mask to clip drawing by.
+    pub clip: Option<&'a [u8]>,
+}
+
+/// The outputs of the vertex shader, which are interpolated across each
+/// triangle.
+#[derive(Clone, Copy, Debug)]
+struct Varyings {
+    uv: [f32; 2],
+    color: [f32; 4],
+    /// The alpha base offset and the alpha peak.
+    config: [f32; 2],
+    distance: [f32; 2],
+    smoothing: [f32; 1],
+}
+
+impl Varyings {
+    fn new<Uv: UvType>(vertex: &Vertex<Uv>) -> Self {
+        let [u, v] = vertex.uv;
+        let [r, g, b, a] = vertex.color.map(|c| f32::from(c) / 255.0);
+        let [base, peak, vector, _] =
+            vertex.config.0.map(|c| f32::from(c) / 255.0);
+        let dy = if vector > 0.5 { 1.0 } else { 0.0 };
+        let dx = if (vector - 0.5 * dy).max(0.0) > 0.25 {
+            1.0
+        } else {
+            0.0
+        };
+        Self {
+            uv: [u.to_f32(), v.to_f32()],
+            color: [srgb_decode(r), srgb_decode(g), srgb_decode(b), a],
+            config: [base * (255.0 / 256.0), peak],
+            distance: [dx, dy],
+            smoothing: [vertex.smoothing],
+        }
+    }
+
+    fn interpolate([a, b, c]: [&Self; 3], weights: [f32; 3]) -> Self {
+        fn mix<const N: usize>(
+            a: [f32; N],
+            b: [f32; N],
+            c: [f32; N],
+            [wa, wb, wc]: [f32; 3],
+        ) -> [f32; N] {
+            let mut out = [0.0; N];
+            for (i, out) in out.iter_mut().enumerate() {
+                *out = a[i] * wa + b[i] * wb + c[i] * wc;
+            }
+            out
+        }
+        Self {
+            uv: mix(a.uv, b.uv, c.uv, weights),
+            color: mix(a.color, b.color, c.color, weights),
+            config: mix(a.config, b.config, c.config, weights),
+            distance: mix(a.distance, b.distance, c.distance, weights),
+            smoothing: mix(a.smoothing, b.smoothing, c.smoothing, weights),
+        }
+    }
+}
+
+/// Draw indexed triangles, with the same result for each pixel as the
+/// standard shader in the OpenGL renderer.
+pub(super) fn draw_triangles<Uv: UvType>(
+    target: &mut Target<'_>,
+    texture: &TexturePixels,
+    matrix: &Mat4,
+    vertices: &[Vertex<Uv>],
+    indices: &[u16],
+) {
+    let width = f32::from(target.width);
+    let height = f32::from(target.height);
+    let points: Vec<_> = vertices
+        .iter()
+        .map(|vertex| {
+            let [x, y] = vertex.xy;
+            let (clip_x, clip_y, _, clip_w) = matrix * (x, y, 0.0, 1.0);
+            let position = [
+                (clip_x / clip_w + 1.0) * 0.5 * width,
+                (clip_y / clip_w + 1.0) * 0.5 * height,
+            ];
+            (position, Varyings::new(vertex))
+        })
+        .collect();
+    for triangle in indices.chunks_exact(3) {
+        let point = |i: u16| points.get(usize::from(i));
+        if let (Some(a), Some(b), Some(c)) =
+            (point(triangle[0]), point(triangle[1]), point(triangle[2]))
+        {
+            draw_triangle(target, texture, [a, b, c]);
+        }
+    }
+}
+
+fn draw_triangle(
+    target: &mut Target<'_>,
+    texture: &TexturePixels,
+    [a, b, c]: [&([f32; 2], Varyings); 3],
+) {
+    let area = edge(a.0, b.0, c.0);
+    if area.is_nan() || area == 0.0 {
+        return;
+    }
+    // wind every triangle counter-clockwise, so points inside are on the
+    // left of each edge
+    let (b, c) = if area > 0.0 { (b, c) } else { (c, b) };
+    let area = area.abs();
+    let xs = [a.0[0], b.0[0], c.0[0]];
+    let ys = [a.0[1], b.0[1], c.0[1]];
+    let span = |coords: [f32; 3], len: u16| {
+        let len = f32::from(len);
+        let min = coords.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = coords.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        // the casts saturate, and clamping first keeps NaN out
+        let start = min.floor().clamp(0.0, len) as usize;
+        let end = max.ceil().clamp(0.0, len) as usize;
+        start..end
+    };
+    let edges = [(b.0, c.0), (c.0, a.0), (a.0, b.0)];
+    let row_len = usize::from(target.width);
+    for y in span(ys, target.height) {
+        let center_y = y as f32 + 0.5;
+        'pixel: for x in span(xs, target.width) {
+            let center = [x as f32 + 0.5, center_y];
+            let mut weights = [0.0; 3];
+            for (weight, &(from, to)) in weights.iter_mut().zip(&edges) {
+                let dist = edge(from, to, center);
+                // pixels exactly on an edge shared by two triangles are
+                // only drawn by one of them
+                if dist < 0.0 || (dist == 0.0 && !owns_edge(from, to)) {
+                    continue 'pixel;
+                }
+                *weight = dist / area;
+            }
+            let varyings = Varyings::interpolate([&a.1, &b.1, &c.1], weights);
+            fragment(target, texture, y * row_len + x, &varyings);
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p`; positive if `p` is
+/// on the left of the line from `a` to `b`.
+fn edge(a: [f32; 2], b: [f32; 2], p: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+fn owns_edge(from: [f32; 2], to: [f32; 2]) -> bool {
+    let dy = to[1] - from[1];
+    dy < 0.0 || (dy == 0.0 && to[0] > from[0])
+}
+
+fn fragment(
+    target: &mut Target<'_>,
+    texture: &TexturePixels,
+    index: usize,
+    varyings: &Varyings,
+) {
+    let [r, g, b, mut alpha] = shade(texture, varyings);
+    if let Some(clip) = target.clip {
+        alpha *= f32::from(clip[index]) / 255.0;
+    }
+    if let Some(mask) = &mut target.mask {
+        if alpha > 0.0 {
+            let dest = &mut mask[index];
+            let old = f32::from(*dest) / 255.0;
+            *dest = (alpha * alpha + old * (1.0 - alpha)).quantize_u8();
+        }
+        return;
+    }
+    if alpha.is_nan() || alpha <= 0.0 {
+        return;
+    }
+    let dest = &mut target.pixels[index * 4..index * 4 + 4];
+    let old = Color::from_rgba8(dest[0], dest[1], dest[2], dest[3]);
+    let inv = 1.0 - alpha;
+    let new = Color::from_rgba(
+        r * alpha + old.r * inv,
+        g * alpha + old.g * inv,
+        b * alpha + old.b * inv,
+        alpha * alpha + old.a * inv,
+    );
+    dest.copy_from_slice(&new.rgba8());
+}
+
+/// The fragment shader, returning a linear color and its alpha.
+fn shade(texture: &TexturePixels, varyings: &Varyings) -> [f32; 4] {
+    let size = &texture.size;
+    let [mut r, mut g, mut b, a] = sample(texture, varyings.uv);
+    let mut sdf_value = if size.is_msdf {
+        r.min(g).max(r.max(g).min(b))
+    } else {
+        a
+    };
+    if size.is_sdf {
+        r = 1.0;
+        g = 1.0;
+        b = 1.0;
+    } else {
+        sdf_value = sdf_value.max(1.0);
+    }
+    let [dx, dy] = varyings.distance;
+    sdf_value *= 1.0 - dx.hypot(dy).min(1.0);
+    let [base, peak] = varyings.config;
+    sdf_value = peak - (sdf_value - peak).abs();
+    sdf_value = sdf_value + (base + base) - 1.0;
+    sdf_value *= varyings.smoothing[0];
+    let [cr, cg, cb, ca] = varyings.color;
+    [
+        r.powf(size.color_pow) * cr,
+        g.powf(size.color_pow) * cg,
+        b.powf(size.color_pow) * cb,
+        sdf_value.clamp(0.0, 1.0) * ca,
+    ]
+}
+
+/// Sample a texture with linear filtering, clamped to the edges, where the
+/// coordinates are in texels.
+fn sample(texture: &TexturePixels, [u, v]: [f32; 2]) -> [f32; 4] {
+    let width = usize::from(texture.size.texture_width);
+    let height = usize::from(texture.size.texture_height);
+    if width == 0 || height == 0 || texture.rgba.len() < width * height * 4 {
+        return [0.0; 4];
+    }
+    let texel = |x: f32, y: f32| {
+        // the casts saturate, so negative coordinates clamp to zero
+        let x = (x as usize).min(width - 1);
+        let y = (y as usize).min(height - 1);
+        let start = (y * width + x) * 4;
+        let mut out = [0.0; 4];
+        for (out, &byte) in out.iter_mut().zip(&texture.rgba[start..]) {
+            *out = f32::from(byte) / 255.0;
+        }
+        out
+    };
+    let x = u - 0.5;
+    let y = v - 0.5;
+    let (left, bottom) = (x.floor(), y.floor());
+    let (fx, fy) = (x - left, y - bottom);
+    let corners = [
+        (texel(left, bottom), (1.0 - fx) * (1.0 - fy)),
+        (texel(left + 1.0, bottom), fx * (1.0 - fy)),
+        (texel(left, bottom + 1.0), (1.0 - fx) * fy),
+        (texel(left + 1.0, bottom + 1.0), fx * fy),
+    ];
+    let mut out = [0.0; 4];
+    for (texel, weight) in corners {
+        for (out, channel) in out.iter_mut().zip(texel) {
+            *out += channel * weight;
+        }
+    }
+    out
+}
+
+fn srgb_decode(value: f32) -> f32 {
+    if value > 0.04045 {
+        ((value + 0.055) / 1.055).powf(2.4)
+    } else {
+        value / 12.92
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platforms::opengl::{renderer::VertexConfig, TextureSize};
+
+    fn white() -> TexturePixels {
+        TexturePixels {
+            size: TextureSize {
+                image_width: 2.0,
+                image_height: 2.0,
+                texture_width: 2,
+                texture_height: 2,
+                color_pow: 1.0,
+                is_sdf: false,
+                is_msdf: false,
+            },
+            rgba: vec![0xff; 16],
+        }
+    }
+
+    fn vertex(x: f32, y: f32, color: [u8; 4]) -> Vertex<u16> {
+        Vertex {
+            xy: [x, y],
+            uv: [1, 1],
+            color,
+            config: VertexConfig::new().alpha_base(0.0),
+            smoothing: 1.0,
+        }
+    }
+
+    fn draw(
+        pixels: &mut [u8],
+        mask: Option<&mut [u8]>,
+        clip: Option<&[u8]>,
+        vertices: &[Vertex<u16>],
+        indices: &[u16],
+    ) {
+        let mut target = Target {
+            width: 4,
+            height: 4,
+            pixels,
+            mask,
+            clip,
+        };
+        // map the 4x4 target to 0..4 on each axis
+        let matrix = Mat4::translate(-1.0, -1.0) * Mat4::scale(0.5, 0.5);
+        draw_triangles(&mut target, &white(), &matrix, vertices, indices);
+    }
+
+    #[test]
+    fn shared_edges_are_drawn_once() {
+        let mut pixels = [0; 64];
+        let half_red = [255, 0, 0, 128];
+        let vertices = [
+            vertex(0.0, 0.0, half_red),
+            vertex(4.0, 0.0, half_red),
+            vertex(0.0, 4.0, half_red),
+            vertex(4.0, 4.0, half_red),
+        ];
+        draw(&mut pixels, None, None, &vertices, &[0, 1, 2, 1, 3, 2]);
+        let first = &pixels[..4];
+        assert_eq!(first[1..3], [0, 0]);
+        assert!(first[0] > 0);
+        for pixel in pixels.chunks_exact(4) {
+            assert_eq!(pixel, first);
+        }
+    }
+
+    #[test]
+    fn mask_limits_drawing() {
+        let mut pixels = [0; 64];
+        let mut mask = [0; 16];
+        let white = [255; 4];
+        let left_half = [
+            vertex(0.0, 0.0, white),
+            vertex(2.0, 0.0, white),
+            vertex(0.0, 4.0, white),
+            vertex(2.0, 4.0, white),
+        ];
+        let whole = [
+            vertex(0.0, 0.0, white),
+            vertex(4.0, 0.0, white),
+            vertex(0.0, 4.0, white),
+            vertex(4.0, 4.0, white),
+        ];
+        let indices = [0, 1, 2, 1, 3, 2];
+        draw(&mut pixels, Some(&mut mask), None, &left_half, &indices);
+        assert!(pixels.iter().all(|&byte| byte == 0));
+        draw(&mut pixels, None, Some(&mask), &whole, &indices);
+        for (i, pixel) in pixels.chunks_exact(4).enumerate() {
+            let expected = if i % 4 < 2 { [255; 4] } else { [0; 4] };
+            assert_eq!(pixel, expected, "pixel {i}");
+        }
+    }
+
+    #[test]
+    fn linear_filtering_clamps_to_edge() {
+        let mut texture = white();
+        texture.rgba = vec![
+            0, 0, 0, 255, 255, 255, 255, 255, // bottom row
+            0, 0, 0, 255, 255, 255, 255, 255, // top row
+        ];
+        assert_eq!(sample(&texture, [0.5, 0.5]), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(sample(&texture, [0.0, 0.0]), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(sample(&texture, [1.0, 1.0]), [0.5, 0.5, 0.5, 1.0]);
+        assert_eq!(sample(&texture, [5.0, 1.0]), [1.0; 4]);
+    }
+}
@@ -0,0 +1,80 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use std::collections::HashMap;
+
+use crate::platforms::opengl::{TextureId, TexturePixels, TextureSize};
+
+enum TextureState {
+    Failed {
+        generation: u64,
+    },
+    Ready {
+        pixels: TexturePixels,
+        generation: u64,
+    },
+}
+
+pub(super) struct TextureCache {
+    set: HashMap<Box<[u8]>, TextureState>,
+    solid_color: TexturePixels,
+}
+
+impl Default for TextureCache {
+    fn default() -> Self {
+        Self {
+            set: HashMap::new(),
+            // the same as the solid color texture in OpenGL
+            solid_color: TexturePixels {
+                size: TextureSize {
+                    image_width: 2.0,
+                    image_height: 2.0,
+                    texture_width: 2,
+                    texture_height: 2,
+                    color_pow: 1.0,
+                    is_sdf: false,
+                    is_msdf: false,
+                },
+                rgba: vec![0xff; 16],
+            },
+        }
+    }
+}
+
+impl TextureCache {
+    /// Get the pixels of a texture, populating it first if it is new or
+    /// its populator has a new generation.
+    pub fn lookup(&mut self, id: &TextureId) -> Option<&TexturePixels> {
+        let Some(populator) = id.populator() else {
+            return Some(&self.solid_color);
+        };
+        let key = populator.texture_key();
+        let generation = populator.generation();
+        let stale = match self.set.get(key) {
+            Some(
+                TextureState::Failed {
+                    generation: populated,
+                }
+                | TextureState::Ready {
+                    generation: populated,
+                    ..
+                },
+            ) => *populated != generation,
+            None => true,
+        };
+        if stale {
+            let state = match populator.populate_pixels() {
+                Ok(pixels) => TextureState::Ready { pixels, generation },
+                Err(msg) => {
+                    eprintln!("failed to load texture: {msg}");
+                    TextureState::Failed { generation }
+                }
+            };
+            self.set.insert(key.into(), state);
+        }
+        match self.set.get(key)? {
+            TextureState::Ready { pixels, .. } => Some(pixels),
+            TextureState::Failed { .. } => None,
+        }
+    }
+}
@@ -94,6 +94,7 @@ pub use internal::PointerEvent as PointerEventData;
 pub struct PointerEvent<'a> {
     data: PointerEventData,
     grab_map: &'a mut HashMap<PointerId, UniqueHandleId>,
+    focus: Option<UniqueHandleId>,
 }
 
 impl std::fmt::Debug for PointerEvent<'_> {
@@ -107,7 +108,21 @@ impl<'a> PointerEvent<'a> {
         data: PointerEventData,
         grab_map: &'a mut HashMap<PointerId, UniqueHandleId>,
     ) -> Self {
-        PointerEvent { data, grab_map }
+        PointerEvent {
+            data,
+            grab_map,
+            focus: None,
+        }
+    }
+
+    /// Offer focus to the identified handle, if a descendant has not
+    /// already claimed it.
+    pub(crate) fn offer_focus(&mut self, holder: UniqueHandleId) {
+        self.focus.get_or_insert(holder);
+    }
+
+    pub(crate) fn take_focus(&mut self) -> Option<UniqueHandleId> {
+        self.focus.take()
     }
 }
 
@@ -185,7 +200,7 @@ impl PointerEvent<'_> {
     {
         self.grab_map
             .get(&self.id())
-            .map_or(false, |v| v == &holder.into())
+            .is_some_and(|v| v == &holder.into())
     }
 
     /// Try to stop grabbing this pointer, indicating that the identified
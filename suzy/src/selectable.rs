@@ -18,8 +18,9 @@
 //! enums.  This pattern allows additional states to be added in the future
 //! with reasonable fallbacks for backwards-compatibility.
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 enum SelectionStateAll {
+    #[default]
     Normal,
     Hover,
     Focus,
@@ -27,12 +28,6 @@ enum SelectionStateAll {
     Active,
 }
 
-impl Default for SelectionStateAll {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 /// A selection state is an opaque type which indicates the current state
 /// a selectable widget should transition to.
 ///
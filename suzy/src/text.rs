@@ -3,6 +3,10 @@
 
 //! Types for dealing with formatted text.
 
+use std::{fmt, ops::Range};
+
+use crate::platform::graphics::TextStyle;
+
 /// An enum describing horizontal text alignment settings.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Alignment {
@@ -13,6 +17,12 @@ pub enum Alignment {
     Center,
     /// Right-aligned
     Right,
+    /// Aligned to the start of each paragraph's direction; left-aligned for
+    /// left-to-right text, and right-aligned for right-to-left text.
+    Start,
+    /// Aligned to the end of each paragraph's direction; right-aligned for
+    /// left-to-right text, and left-aligned for right-to-left text.
+    End,
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -40,11 +50,19 @@ pub enum VerticalLimit {
     Height(f32),
 }
 
+/// How text which doesn't fit within the vertical limit is hidden.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum OverflowMode {
+    /// Lines beyond the limit are hidden.
     #[default]
     Truncate,
+    /// Lines beyond the limit are hidden, and the last visible line is cut
+    /// short to end with an ellipsis which fits within the wrap width.
     Ellipsis,
+    /// The middle of the text is hidden: the last visible line is cut short
+    /// to end with an ellipsis followed by the end of the text, all fitting
+    /// within the wrap width.  This is useful for things like file paths.
+    EllipsisMiddle,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -74,7 +92,243 @@ impl Default for Layout {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+/// Which edge of a character a position is associated with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Affinity {
+    /// The edge before the character, in reading order.
+    #[default]
+    Leading,
+    /// The edge after the character, in reading order.
+    Trailing,
+}
+
+/// The result of mapping a point to a position in laid-out text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextHit {
+    /// The byte index of the character nearest the point.
+    pub index: usize,
+    /// Which edge of the character the point is nearest.
+    pub affinity: Affinity,
+    /// The byte index where a caret placed at the point should go.
+    ///
+    /// This is `index` for a leading hit, and the index of the following
+    /// character for a trailing hit.
+    pub caret: usize,
+    /// True if the point lies within the bounds of the character, rather
+    /// than beyond the start or end of a line.
+    pub inside: bool,
+}
+
+/// The position and extents of a single line of laid-out text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineMetrics {
+    /// The byte range of the text on this line.
+    ///
+    /// This includes any whitespace or line break which ends the line.  If
+    /// the text after the line was hidden by the vertical limit, this
+    /// extends to the end of the text.
+    pub range: Range<usize>,
+    /// The vertical position of the baseline.
+    pub baseline: f32,
+    /// The distance from the baseline to the top of the line.
+    pub ascent: f32,
+    /// The distance from the baseline to the bottom of the line; this is
+    /// typically negative.
+    pub descent: f32,
+    /// The horizontal position of the start of the line.
+    pub left: f32,
+    /// The horizontal position of the end of the line.
+    pub right: f32,
+}
+
+impl LineMetrics {
+    /// Get the vertical position of the top of the line.
+    #[must_use]
+    pub fn top(&self) -> f32 {
+        self.baseline + self.ascent
+    }
+
+    /// Get the vertical position of the bottom of the line.
+    #[must_use]
+    pub fn bottom(&self) -> f32 {
+        self.baseline + self.descent
+    }
+}
+
+/// An error encountered while parsing rich text markup.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct RichTextTagParseError {
+    /// A description of the problem.
     pub msg: String,
+    /// The byte position in the source markup where the problem was found.
+    pub position: usize,
+}
+
+impl fmt::Display for RichTextTagParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.msg, self.position)
+    }
+}
+
+impl std::error::Error for RichTextTagParseError {}
+
+/// Parse rich text markup into a sequence of styled spans.
+///
+/// Tags are written `<name>` or `<name=value>`, and apply to the text up to
+/// a matching `</name>`, or `</>`, or the end of the markup.  Tags may
+/// nest; the style for each tag is derived from the style of the enclosing
+/// tag using [`TextStyle::push_tag`].  A literal `<` is written `<<`.
+///
+/// The returned spans can be passed one at a time to
+/// [`Text::push_span`](crate::platform::graphics::Text::push_span).
+pub fn parse_rich<S>(
+    base: S,
+    markup: &str,
+) -> Result<Vec<(S, &str)>, RichTextTagParseError>
+where
+    S: TextStyle,
+{
+    let offset = |sub: &str| {
+        (sub.as_ptr() as usize).wrapping_sub(markup.as_ptr() as usize)
+    };
+    let error =
+        |msg: String, position: usize| RichTextTagParseError { msg, position };
+    let mut spans = Vec::new();
+    let mut stack: Vec<(&str, S)> = Vec::new();
+    let mut style = base;
+    let mut rest = markup;
+    while !rest.is_empty() {
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        if text_end > 0 {
+            spans.push((style.clone(), &rest[..text_end]));
+        }
+        rest = &rest[text_end..];
+        if let Some(escaped) = rest.strip_prefix("<<") {
+            spans.push((style.clone(), &rest[..1]));
+            rest = escaped;
+            continue;
+        }
+        let Some(tag_start) = rest.strip_prefix('<') else {
+            break;
+        };
+        let Some(tag_len) = tag_start.find('>') else {
+            return Err(error("unterminated tag".to_string(), offset(rest)));
+        };
+        let tag = &tag_start[..tag_len];
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            match stack.pop() {
+                Some((open, prev)) if name.is_empty() || name == open => {
+                    style = prev;
+                }
+                Some((open, _)) => {
+                    return Err(error(
+                        format!("expected </{open}>, found </{name}>"),
+                        offset(rest),
+                    ));
+                }
+                None => {
+                    return Err(error(
+                        format!("unexpected closing tag </{name}>"),
+                        offset(rest),
+                    ));
+                }
+            }
+        } else {
+            let name = tag.split('=').next().unwrap_or_default().trim();
+            let mut remaining = tag;
+            match style.push_tag(&mut remaining) {
+                Ok(inner) => {
+                    stack.push((name, std::mem::replace(&mut style, inner)));
+                }
+                Err(err) => {
+                    let tag_range = offset(tag)..=offset(tag) + tag.len();
+                    let position = Some(offset(remaining))
+                        .filter(|pos| tag_range.contains(pos))
+                        .unwrap_or(offset(tag));
+                    return Err(error(err.msg, position));
+                }
+            }
+        }
+        rest = &tag_start[tag_len + 1..];
+    }
+    Ok(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::Color;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct TagStyle(Vec<String>);
+
+    impl TextStyle for TagStyle {
+        fn with_size_and_color(_size: f32, _color: Color) -> Self {
+            Self(Vec::new())
+        }
+
+        fn push_tag(
+            &self,
+            tag: &mut &str,
+        ) -> Result<Self, RichTextTagParseError> {
+            if let Some(pos) = tag.find('!') {
+                *tag = &tag[pos..];
+                return Err(RichTextTagParseError {
+                    msg: "bad tag".to_string(),
+                    position: 0,
+                });
+            }
+            let mut tags = self.0.clone();
+            tags.push(tag.to_string());
+            Ok(Self(tags))
+        }
+    }
+
+    fn tags(spans: &[(TagStyle, &str)]) -> Vec<(String, String)> {
+        spans
+            .iter()
+            .map(|(style, text)| (style.0.join(","), text.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_nested_tags() {
+        let spans = parse_rich(
+            TagStyle(Vec::new()),
+            "a <b>bold <color=red>red</color></b> <<i> <i>x",
+        )
+        .expect("markup should parse");
+        let expected = [
+            ("", "a "),
+            ("b", "bold "),
+            ("b,color=red", "red"),
+            ("", " "),
+            ("", "<"),
+            ("", "i> "),
+            ("i", "x"),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|(style, text)| (style.to_string(), text.to_string()))
+            .collect();
+        assert_eq!(tags(&spans), expected);
+    }
+
+    #[test]
+    fn parse_errors_have_positions() {
+        let base = TagStyle(Vec::new());
+        let err = parse_rich(base.clone(), "ab<b>c</i>")
+            .expect_err("markup should fail to parse");
+        assert_eq!(err.position, 6);
+        let err = parse_rich(base.clone(), "ab</b>")
+            .expect_err("markup should fail to parse");
+        assert_eq!(err.position, 2);
+        let err = parse_rich(base.clone(), "abc<size=1")
+            .expect_err("markup should fail to parse");
+        assert_eq!(err.position, 3);
+        let err = parse_rich(base, "x<size=!2>")
+            .expect_err("markup should fail to parse");
+        assert_eq!(err.position, 7);
+    }
 }
@@ -7,8 +7,12 @@
 use std::ops::{Deref, DerefMut};
 
 use crate::{
-    adapter::Adaptable, dims::Rect, graphics::DrawContext,
-    platform::RenderPlatform, pointer::PointerEvent,
+    adapter::Adaptable,
+    dims::Rect,
+    graphics::DrawContext,
+    keyboard::FocusedEvent,
+    platform::RenderPlatform,
+    pointer::{PointerAction, PointerEvent},
 };
 
 mod anon;
@@ -26,7 +30,8 @@ mod unique_handle;
 use internal::WidgetInternal;
 use receivers::{
     DrawChildReceiver, DrawGraphicBeforeReceiver, DrawGraphicOrderedReceiver,
-    DrawGraphicUnorderedReceiver, PointerEventChildReceiver,
+    DrawGraphicUnorderedReceiver, FocusChildReceiver,
+    FocusedEventChildReceiver, PointerEventChildReceiver,
 };
 
 pub use anon::AnonWidget;
@@ -206,7 +211,7 @@ impl<T: ?Sized> Widget<T> {
         let wid_int = &mut this.internal;
         let content = &mut wid_int.content;
         let rect = &wid_int.rect;
-        T::pointer_event_before(content, rect, event)
+        let handled = T::pointer_event_before(content, rect, event)
             || {
                 let mut handled_by_child = false;
                 T::desc(PointerEventChildReceiver {
@@ -216,7 +221,13 @@ impl<T: ?Sized> Widget<T> {
                 });
                 handled_by_child
             }
-            || T::pointer_event(content, rect, event)
+            || T::pointer_event(content, rect, event);
+        if handled && matches!(event.action(), PointerAction::Down) {
+            if let Some(id) = T::focus_handle(content) {
+                event.offer_focus(id);
+            }
+        }
+        handled
     }
 
     pub(crate) fn pointer_event_self<P>(
@@ -232,6 +243,61 @@ impl<T: ?Sized> Widget<T> {
         T::pointer_event(content, rect, event)
     }
 
+    /// Deliver an event to the focused widget within this one.
+    ///
+    /// Returns `None` if the focused widget is not this widget or one of its
+    /// descendants; otherwise, returns if the event was handled.
+    pub(crate) fn focused_event<P, E>(
+        this: &mut Self,
+        event: &mut E,
+    ) -> Option<bool>
+    where
+        T: Content<P>,
+        E: FocusedEvent,
+    {
+        let wid_int = &mut this.internal;
+        let content = &mut wid_int.content;
+        let rect = &wid_int.rect;
+        if T::focus_handle(content).is_some_and(|id| event.is_target(id)) {
+            return Some(event.deliver::<T, P>(content, rect));
+        }
+        let mut found = None;
+        T::desc(FocusedEventChildReceiver {
+            content,
+            event,
+            found: &mut found,
+        });
+        match found {
+            Some(false) => Some(event.deliver::<T, P>(content, rect)),
+            found => found,
+        }
+    }
+
+    pub(crate) fn focused_event_self<P, E>(
+        this: &mut Self,
+        event: &mut E,
+    ) -> bool
+    where
+        T: Content<P>,
+        E: FocusedEvent,
+    {
+        let wid_int = &mut this.internal;
+        event.deliver::<T, P>(&mut wid_int.content, &wid_int.rect)
+    }
+
+    /// Collect the focus handles of this widget and its descendants, in
+    /// tree order.
+    pub(crate) fn collect_focus<P>(
+        this: &mut Self,
+        list: &mut Vec<UniqueHandleId>,
+    ) where
+        T: Content<P>,
+    {
+        let content = &mut this.internal.content;
+        list.extend(T::focus_handle(content));
+        T::desc(FocusChildReceiver { content, list });
+    }
+
     fn proxy_rect<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&WidgetRect) -> R,
@@ -7,9 +7,12 @@ use super::{Content, Widget};
 
 mod private {
     use super::Content;
+    use crate::dims::SimpleRect;
     use crate::graphics::DrawContext;
+    use crate::keyboard::{KeyEvent, TextInputAreaQuery, TextInputEvent};
     use crate::platform::RenderPlatform;
     use crate::pointer::PointerEvent;
+    use crate::widget::UniqueHandleId;
 
     pub trait Widget<P> {
         fn draw(&mut self, ctx: &mut DrawContext<'_, P>)
@@ -18,6 +21,18 @@ mod private {
         fn pointer_event(&mut self, event: &mut PointerEvent<'_>) -> bool;
         fn pointer_event_self(&mut self, event: &mut PointerEvent<'_>)
             -> bool;
+        fn key_event(&mut self, event: &mut KeyEvent<'_>) -> Option<bool>;
+        fn key_event_self(&mut self, event: &mut KeyEvent<'_>) -> bool;
+        fn text_input(
+            &mut self,
+            event: &mut TextInputEvent<'_>,
+        ) -> Option<bool>;
+        fn text_input_self(&mut self, event: &mut TextInputEvent<'_>) -> bool;
+        fn text_input_area(
+            &mut self,
+            focus: &UniqueHandleId,
+        ) -> Option<SimpleRect>;
+        fn collect_focus(&mut self, list: &mut Vec<UniqueHandleId>);
         fn as_any(self: Box<Self>) -> Box<dyn std::any::Any>;
         fn as_any_ref(&self) -> &dyn std::any::Any;
         fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
@@ -46,6 +61,38 @@ mod private {
             super::Widget::pointer_event_self(self, event)
         }
 
+        fn key_event(&mut self, event: &mut KeyEvent<'_>) -> Option<bool> {
+            super::Widget::focused_event(self, event)
+        }
+
+        fn key_event_self(&mut self, event: &mut KeyEvent<'_>) -> bool {
+            super::Widget::focused_event_self(self, event)
+        }
+
+        fn text_input(
+            &mut self,
+            event: &mut TextInputEvent<'_>,
+        ) -> Option<bool> {
+            super::Widget::focused_event(self, event)
+        }
+
+        fn text_input_self(&mut self, event: &mut TextInputEvent<'_>) -> bool {
+            super::Widget::focused_event_self(self, event)
+        }
+
+        fn text_input_area(
+            &mut self,
+            focus: &UniqueHandleId,
+        ) -> Option<SimpleRect> {
+            let mut query = TextInputAreaQuery { focus, area: None };
+            super::Widget::focused_event(self, &mut query)?;
+            query.area
+        }
+
+        fn collect_focus(&mut self, list: &mut Vec<UniqueHandleId>) {
+            super::Widget::collect_focus(self, list);
+        }
+
         fn as_any(self: Box<Self>) -> Box<dyn std::any::Any> {
             self
         }
@@ -2,8 +2,10 @@
 /* Copyright © 2021 Violet Leonard */
 
 use crate::{
+    dims::SimpleRect,
+    keyboard::{KeyEvent, TextInputEvent},
     pointer::PointerEvent,
-    widget::{self, WidgetRect},
+    widget::{self, UniqueHandleId, WidgetRect},
 };
 
 with_default_render_platform! {
@@ -104,6 +106,61 @@ impl widget::Content for MyWidgetData {
             false
         }
 
+        /// Override this method to handle key events directly by a custom
+        /// widget.
+        ///
+        /// This is called when this widget has focus, or when a descendant
+        /// with focus did not handle the event.
+        ///
+        /// Return true if this successfully handled the event.
+        fn key_event(
+            &mut self,
+            rect: &WidgetRect,
+            event: &mut KeyEvent<'_>,
+        ) -> bool {
+            let _unused = (rect, event);
+            false
+        }
+
+        /// Override this method to handle text input directly by a custom
+        /// widget.
+        ///
+        /// Like `key_event`, this is called when this widget has focus, or
+        /// when a descendant with focus did not handle the event.
+        ///
+        /// Return true if this successfully handled the event.
+        fn text_input(
+            &mut self,
+            rect: &WidgetRect,
+            event: &mut TextInputEvent<'_>,
+        ) -> bool {
+            let _unused = (rect, event);
+            false
+        }
+
+        /// Override this method to indicate this widget accepts text input
+        /// while it has focus.
+        ///
+        /// The returned area is where text is being entered; platforms use
+        /// it to position the input method's candidate window.  The default
+        /// is `None`, which means the widget does not accept text input.
+        #[must_use]
+        fn text_input_area(&self, rect: &WidgetRect) -> Option<SimpleRect> {
+            let _unused = rect;
+            None
+        }
+
+        /// Override this method to allow this widget to receive focus.
+        ///
+        /// Key events are delivered to a widget when the app's focus is set
+        /// to the handle returned here.  Focusable widgets gain focus when
+        /// they handle a pointer press, and the tab key moves focus between
+        /// them in tree order.  The default is `None`.
+        #[must_use]
+        fn focus_handle(&self) -> Option<UniqueHandleId> {
+            None
+        }
+
         /// If this widget is used with RunAsApp, defines the window title.
         #[must_use]
         fn app_title() -> String {
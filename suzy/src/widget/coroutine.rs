@@ -87,18 +87,14 @@ impl std::task::Wake for WatchedWaker {
     }
 }
 
+#[derive(Default)]
 enum State<T> {
+    #[default]
     Inactive,
     Starting(T),
     Running(Pin<Box<dyn Future<Output = ()>>>),
 }
 
-impl<T> Default for State<T> {
-    fn default() -> Self {
-        Self::Inactive
-    }
-}
-
 /// A Coroutine stores the state of a Rust Future which is run within
 /// Suzy's watch system.  This can be used to sequence events over multiple
 /// frames.
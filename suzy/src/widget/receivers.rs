@@ -9,12 +9,15 @@ use std::{
 use crate::{
     app::{self, AppState},
     graphics::{DrawContext, Graphic},
+    keyboard::FocusedEvent,
     platform::RenderPlatform,
     pointer::PointerEvent,
     watch::{self, DefaultOwner, WatchArg, WatchName, WatchedMeta},
 };
 
-use super::{Desc, Ephemeral, Widget, WidgetGraphic, WidgetRect};
+use super::{
+    Desc, Ephemeral, UniqueHandleId, Widget, WidgetGraphic, WidgetRect,
+};
 
 macro_rules! impl_empty {
     ($T:ident; $P:ident; watch) => {
@@ -429,6 +432,140 @@ where
     }
 }
 
+pub(super) struct FocusedEventChildReceiver<'a, 'b, T: ?Sized, E> {
+    pub content: &'a mut T,
+    pub event: &'a mut E,
+    pub found: &'b mut Option<bool>,
+}
+
+impl<'a, 'b, T, E, P> Desc<T, P> for FocusedEventChildReceiver<'a, 'b, T, E>
+where
+    T: ?Sized + super::Content<P>,
+    E: FocusedEvent,
+{
+    impl_empty! { T; P; watch graphic }
+
+    fn child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Widget<Child>,
+        Child: super::Content<P>,
+    {
+        if self.found.is_none() {
+            *self.found =
+                Widget::focused_event(map_fn(self.content), self.event);
+        }
+    }
+
+    fn iter_children<F, Child>(&mut self, iter_fn: F)
+    where
+        F: 'static,
+        F: for<'i> Fn(
+            &'i mut T,
+        ) -> Box<
+            dyn 'i + Iterator<Item = &'i mut Ephemeral<Child>>,
+        >,
+        Child: super::Content<P>,
+    {
+        self.iter_children_explicit(move |leaf, _arg| iter_fn(leaf));
+    }
+
+    fn iter_children_explicit<F, Child>(&mut self, iter_fn: F)
+    where
+        F: for<'i> Fn(
+            &'i mut T,
+            Option<WatchArg<'_, 'static, DefaultOwner>>,
+        ) -> Box<
+            dyn 'i + Iterator<Item = &'i mut Ephemeral<Child>>,
+        >,
+        Child: super::Content<P>,
+    {
+        let Self {
+            content,
+            event,
+            found,
+        } = self;
+        for child in iter_fn(content, None) {
+            if found.is_none() {
+                **found = child.access_mut(|widget| {
+                    Widget::focused_event(widget, &mut **event)
+                });
+            }
+        }
+    }
+
+    fn bare_child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Child,
+        Child: super::Content<P>,
+    {
+        Child::desc(FocusedEventChildReceiver {
+            content: map_fn(self.content),
+            event: self.event,
+            found: self.found,
+        });
+    }
+}
+
+pub(super) struct FocusChildReceiver<'a, T: ?Sized> {
+    pub content: &'a mut T,
+    pub list: &'a mut Vec<UniqueHandleId>,
+}
+
+impl<'a, T, P> Desc<T, P> for FocusChildReceiver<'a, T>
+where
+    T: ?Sized + super::Content<P>,
+{
+    impl_empty! { T; P; watch graphic }
+
+    fn child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Widget<Child>,
+        Child: super::Content<P>,
+    {
+        Widget::collect_focus(map_fn(self.content), self.list);
+    }
+
+    fn iter_children<F, Child>(&mut self, iter_fn: F)
+    where
+        F: 'static,
+        F: for<'i> Fn(
+            &'i mut T,
+        ) -> Box<
+            dyn 'i + Iterator<Item = &'i mut Ephemeral<Child>>,
+        >,
+        Child: super::Content<P>,
+    {
+        self.iter_children_explicit(move |leaf, _arg| iter_fn(leaf));
+    }
+
+    fn iter_children_explicit<F, Child>(&mut self, iter_fn: F)
+    where
+        F: for<'i> Fn(
+            &'i mut T,
+            Option<WatchArg<'_, 'static, DefaultOwner>>,
+        ) -> Box<
+            dyn 'i + Iterator<Item = &'i mut Ephemeral<Child>>,
+        >,
+        Child: super::Content<P>,
+    {
+        let Self { content, list } = self;
+        for child in iter_fn(content, None) {
+            child.access_mut(|widget| Widget::collect_focus(widget, list));
+        }
+    }
+
+    fn bare_child<F, Child>(&mut self, map_fn: F)
+    where
+        F: FnOnce(&mut T) -> &mut Child,
+        Child: super::Content<P>,
+    {
+        Child::desc(FocusChildReceiver {
+            content: map_fn(self.content),
+            list: self.list,
+        });
+    }
+}
+
 pub(super) struct DrawGraphicBeforeReceiver<'a, 'b, T, P>
 where
     T: ?Sized,
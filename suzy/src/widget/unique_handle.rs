@@ -6,7 +6,7 @@ use std::{
     rc::{Rc, Weak},
 };
 
-use crate::{pointer::PointerId, watch::WatchedQueue};
+use crate::{app::AppState, pointer::PointerId, watch::WatchedQueue};
 
 #[derive(Default)]
 struct HandleContents {
@@ -31,6 +31,25 @@ impl UniqueHandle {
         }
     }
 
+    /// Check if this handle currently has focus.
+    ///
+    /// This will bind watch closures it is called in.  Returns false if
+    /// called from outside the context of a suzy app.
+    #[must_use]
+    pub fn is_focused(&self) -> bool {
+        let id = self.id();
+        AppState::try_with_current(|state| state.is_focused(&id))
+            .unwrap_or(false)
+    }
+
+    /// Move focus to this handle.
+    ///
+    /// This has no effect if called from outside the context of a suzy app.
+    pub fn request_focus(&self) {
+        let id = self.id();
+        AppState::try_with_current(|state| state.set_focus(Some(id)));
+    }
+
     pub fn handle_pointer_grab_stolen<F: FnOnce(PointerId)>(&self, f: F) {
         crate::watch::WatchArg::try_with_current(|arg| {
             self.ptr.grab_stolen.borrow().handle_item(arg, |id| {
@@ -4,13 +4,20 @@
 //! Suzy comes with a set of built-in widgets.
 
 mod button;
+mod editing;
 mod label;
+mod textarea;
+mod textinput;
 mod togglebutton;
 
-pub use button::{Button, ButtonBehavior};
+pub use button::{Button, ButtonBehavior, DefaultButtonContent};
 
 pub use label::{Label, LabelContent};
 
+pub use textarea::{TextArea, TextAreaContent};
+
+pub use textinput::{TextInput, TextInputContent};
+
 pub use togglebutton::{ToggleButton, ToggleButtonGroup, ToggleButtonValue};
 
 /// A trait for widgets which have an obvious singular text graphic associated
@@ -5,10 +5,13 @@ use drying_paint::{Watched, WatchedQueue};
 
 use crate::{
     graphics::Color,
+    keyboard::{Key, KeyAction, KeyEvent},
     platform::RenderPlatform,
     pointer::{PointerAction, PointerEvent, PointerId},
-    selectable::{Selectable, SelectionState, SelectionStateV1},
-    widget::{self, UniqueHandle, Widget, WidgetRect},
+    selectable::{
+        Selectable, SelectionState, SelectionStateV1, SelectionStateV2,
+    },
+    widget::{self, UniqueHandle, UniqueHandleId, Widget, WidgetRect},
 };
 
 /// A Widget providing the behavior of a button.
@@ -17,6 +20,7 @@ pub struct ButtonBehavior<T> {
     state: Watched<SelectionState>,
     interactable: Watched<bool>,
     pointers_down: usize,
+    key_down: bool,
     handle: UniqueHandle,
     content: T,
 }
@@ -42,6 +46,14 @@ impl<T> ButtonBehavior<T> {
             self.on_click.handle_item(arg, |()| f());
         });
     }
+
+    fn rest_state(&self) -> SelectionState {
+        if self.handle.is_focused() {
+            SelectionState::focus()
+        } else {
+            SelectionState::normal()
+        }
+    }
 }
 
 impl<T, P> widget::Content<P> for ButtonBehavior<T>
@@ -59,6 +71,19 @@ where
             }
         });
         desc.watch(|button, _rect| {
+            let focused = button.handle.is_focused();
+            match (button.state.v2(), focused) {
+                (SelectionStateV2::Normal, true) => {
+                    *button.state = SelectionState::focus();
+                }
+                (SelectionStateV2::Focus, false) => {
+                    *button.state = SelectionState::normal();
+                }
+                _ => {}
+            }
+        });
+        desc.watch(|button, _rect| {
+            let rest_state = button.rest_state();
             let Self {
                 pointers_down,
                 state,
@@ -68,12 +93,44 @@ where
             handle.handle_pointer_grab_stolen(|_pointer_id| {
                 *pointers_down -= 1;
                 if *pointers_down == 0 {
-                    **state = SelectionState::normal();
+                    **state = rest_state;
                 }
             });
         });
     }
 
+    fn focus_handle(&self) -> Option<UniqueHandleId> {
+        (*self.interactable).then(|| self.handle.id())
+    }
+
+    fn key_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut KeyEvent<'_>,
+    ) -> bool {
+        if !matches!(event.key(), Key::Enter | Key::Space) {
+            return false;
+        }
+        match event.action() {
+            KeyAction::Down => {
+                if !event.is_repeat() && *self.interactable {
+                    self.key_down = true;
+                    *self.state = SelectionState::active();
+                }
+            }
+            KeyAction::Up => {
+                if self.key_down {
+                    self.key_down = false;
+                    if self.pointers_down == 0 {
+                        *self.state = self.rest_state();
+                    }
+                    self.on_click.push_external(());
+                }
+            }
+        }
+        true
+    }
+
     fn hittest(&self, rect: &WidgetRect, point: [f32; 2]) -> bool {
         self.content.hittest(rect, point)
     }
@@ -101,7 +158,7 @@ where
                 if ungrabbed {
                     self.pointers_down -= 1;
                     if self.pointers_down == 0 {
-                        *self.state = SelectionState::normal();
+                        *self.state = self.rest_state();
                     }
                 }
                 ungrabbed
@@ -114,7 +171,7 @@ where
                         *self.state = if event.id() == PointerId::Mouse {
                             SelectionState::hover()
                         } else {
-                            SelectionState::normal()
+                            self.rest_state()
                         };
                         self.on_click.push_external(());
                     }
@@ -123,14 +180,17 @@ where
             }
             PointerAction::Hover(_, _) => {
                 match (self.state.v1(), self.hittest(rect, event.pos())) {
-                    (SelectionStateV1::Normal, true) => {
+                    (
+                        SelectionStateV1::Normal | SelectionStateV1::Focus,
+                        true,
+                    ) => {
                         if *self.interactable {
                             *self.state = SelectionState::hover();
                         }
                         true
                     }
                     (SelectionStateV1::Hover, false) => {
-                        *self.state = SelectionState::normal();
+                        *self.state = self.rest_state();
                         true
                     }
                     _ => false,
@@ -148,6 +208,7 @@ impl<T: Default> Default for ButtonBehavior<T> {
             state: Watched::default(),
             interactable: Watched::new(true),
             pointers_down: 0,
+            key_down: false,
             handle: UniqueHandle::default(),
             content: T::default(),
         }
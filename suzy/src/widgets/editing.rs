@@ -0,0 +1,177 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Caret movement and editing operations shared by the text editing widgets.
+//!
+//! All indices are byte indices into a string, and are expected to lie on
+//! character boundaries.  The caret moves between grapheme clusters, so
+//! that combining marks and emoji sequences are never split.
+
+use std::{ops::Range, time};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The space between the edge of an editing widget and its text.
+pub(super) const PADDING: f32 = 8.0;
+
+/// The width of the caret graphic.
+pub(super) const CARET_WIDTH: f32 = 2.0;
+
+const BLINK_MILLIS: u128 = 530;
+
+/// Check if a blinking caret should currently be shown.
+///
+/// This binds the current watch closure to the frame time.
+pub(super) fn caret_blink_on(blink_start: time::Instant) -> bool {
+    let elapsed = crate::app::time().saturating_duration_since(blink_start);
+    elapsed.as_millis() % (2 * BLINK_MILLIS) < BLINK_MILLIS
+}
+
+/// Find the grapheme cluster boundary at or before `index`.
+pub(super) fn floor_boundary(text: &str, index: usize) -> usize {
+    grapheme_boundaries(text)
+        .take_while(|&i| i <= index)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Find the grapheme cluster boundary before `index`.
+pub(super) fn prev_grapheme(text: &str, index: usize) -> usize {
+    text[..index]
+        .grapheme_indices(true)
+        .next_back()
+        .map_or(0, |(i, _)| i)
+}
+
+/// Find the grapheme cluster boundary after `index`.
+pub(super) fn next_grapheme(text: &str, index: usize) -> usize {
+    text[index..]
+        .graphemes(true)
+        .next()
+        .map_or(text.len(), |cluster| index + cluster.len())
+}
+
+/// Iterate over the grapheme cluster boundaries in a string, including the
+/// end of the string.
+pub(super) fn grapheme_boundaries(
+    text: &str,
+) -> impl '_ + Iterator<Item = usize> {
+    text.grapheme_indices(true)
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Find the start of the word before `index`.
+pub(super) fn prev_word(text: &str, index: usize) -> usize {
+    let mut iter = text[..index].char_indices().rev().peekable();
+    while iter.next_if(|&(_, ch)| !is_word_char(ch)).is_some() {}
+    let mut start = iter.peek().map_or(0, |&(i, _)| i);
+    while let Some((i, _)) = iter.next_if(|&(_, ch)| is_word_char(ch)) {
+        start = i;
+    }
+    start
+}
+
+/// Find the end of the word after `index`.
+pub(super) fn next_word(text: &str, index: usize) -> usize {
+    let mut iter = text[index..].char_indices().peekable();
+    while iter.next_if(|&(_, ch)| !is_word_char(ch)).is_some() {}
+    while iter.next_if(|&(_, ch)| is_word_char(ch)).is_some() {}
+    iter.peek().map_or(text.len(), |&(i, _)| index + i)
+}
+
+/// Get the range between two indices, in ascending order.
+pub(super) fn ordered(a: usize, b: usize) -> Range<usize> {
+    a.min(b)..a.max(b)
+}
+
+/// Replace a range of text with some input, truncating the input so that
+/// the text contains at most `max_length` characters.
+///
+/// Returns the index immediately after the inserted text.
+pub(super) fn replace(
+    text: &mut String,
+    range: Range<usize>,
+    input: &str,
+    max_length: Option<usize>,
+) -> usize {
+    let input = match max_length {
+        Some(max_length) => {
+            let kept = text[..range.start].chars().count()
+                + text[range.end..].chars().count();
+            let available = max_length.saturating_sub(kept);
+            let end = input
+                .char_indices()
+                .nth(available)
+                .map_or(input.len(), |(i, _)| i);
+            &input[..end]
+        }
+        None => input,
+    };
+    let end = range.start + input.len();
+    text.replace_range(range, input);
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_movement() {
+        let text = "aé€b";
+        assert_eq!(next_grapheme(text, 0), 1);
+        assert_eq!(next_grapheme(text, 1), 3);
+        assert_eq!(next_grapheme(text, 3), 6);
+        assert_eq!(next_grapheme(text, 7), 7);
+        assert_eq!(prev_grapheme(text, 6), 3);
+        assert_eq!(prev_grapheme(text, 0), 0);
+        assert_eq!(floor_boundary(text, 2), 1);
+        assert_eq!(floor_boundary(text, 100), 7);
+        assert_eq!(floor_boundary("e\u{301}x", 2), 0);
+    }
+
+    #[test]
+    fn grapheme_movement() {
+        // e + combining acute, a family emoji, a flag
+        let text = "e\u{301}\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{1f1ef}\u{1f1f5}x";
+        let family = 3;
+        let flag = family + 18;
+        let x = flag + 8;
+        assert_eq!(next_grapheme(text, 0), family);
+        assert_eq!(next_grapheme(text, family), flag);
+        assert_eq!(next_grapheme(text, flag), x);
+        assert_eq!(prev_grapheme(text, x), flag);
+        assert_eq!(prev_grapheme(text, flag), family);
+        assert_eq!(prev_grapheme(text, family), 0);
+        let boundaries: Vec<_> = grapheme_boundaries(text).collect();
+        assert_eq!(boundaries, [0, family, flag, x, x + 1]);
+    }
+
+    #[test]
+    fn word_movement() {
+        let text = "hello,  big world";
+        assert_eq!(next_word(text, 0), 5);
+        assert_eq!(next_word(text, 5), 11);
+        assert_eq!(next_word(text, 11), 17);
+        assert_eq!(prev_word(text, 17), 12);
+        assert_eq!(prev_word(text, 12), 8);
+        assert_eq!(prev_word(text, 8), 0);
+        assert_eq!(prev_word(text, 0), 0);
+    }
+
+    #[test]
+    fn replace_respects_max_length() {
+        let mut text = "abcd".to_string();
+        assert_eq!(replace(&mut text, 1..3, "xyz", Some(5)), 4);
+        assert_eq!(text, "axyzd");
+        assert_eq!(replace(&mut text, 5..5, "é", Some(5)), 5);
+        assert_eq!(text, "axyzd");
+        assert_eq!(replace(&mut text, 0..5, "éé", None), 4);
+        assert_eq!(text, "éé");
+    }
+}
@@ -28,9 +28,45 @@ where
     pub font_size: Watched<f32>,
     pub color: Watched<Color>,
     pub layout: Watched<text::Layout>,
+    /// If set, `text` is parsed as rich text markup, see
+    /// [`text::parse_rich`].  Markup which fails to parse is displayed
+    /// as-is, and the error is available from
+    /// [`rich_text_error`](Self::rich_text_error).
+    pub rich_text: Watched<bool>,
+    content_size: Watched<[f32; 2]>,
+    truncated: Watched<bool>,
+    rich_text_error: Watched<Option<text::RichTextTagParseError>>,
     graphic: P::Text,
 }
 
+impl<P> LabelContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Get the `[width, height]` of the text as it is currently laid out.
+    ///
+    /// This is watched, so a parent can size the label to fit its text.
+    pub fn content_size(&self) -> [f32; 2] {
+        *self.content_size
+    }
+
+    /// Check if some of the text was hidden by the layout's vertical limit.
+    ///
+    /// This is watched, so the full text can be shown another way, like in
+    /// a tooltip.
+    pub fn is_truncated(&self) -> bool {
+        *self.truncated
+    }
+
+    /// Get the error from parsing the text as rich text markup, if it
+    /// failed to parse.
+    ///
+    /// This is watched, so the error can be reported as the text is edited.
+    pub fn rich_text_error(&self) -> Option<&text::RichTextTagParseError> {
+        self.rich_text_error.as_ref()
+    }
+}
+
 impl<P> Default for LabelContent<P>
 where
     P: ?Sized + RenderPlatform,
@@ -50,6 +86,10 @@ where
                 vertical_limit: text::VerticalLimit::None,
                 overflow_mode: text::OverflowMode::Truncate,
             }),
+            rich_text: Watched::new(false),
+            content_size: Watched::new([0.0, 0.0]),
+            truncated: Watched::new(false),
+            rich_text_error: Watched::new(None),
             graphic: P::Text::default(),
         }
     }
@@ -80,14 +120,39 @@ where
             this.graphic.set_layout(layout);
         });
         desc.watch(|this, _rect| {
-            let style =
+            let style: P::TextStyle =
                 crate::platform::graphics::TextStyle::with_size_and_color(
                     *this.font_size,
                     *this.color,
                 );
             this.graphic.clear();
-            this.graphic.push_span(style, &this.text);
+            let (spans, error) = if *this.rich_text {
+                match text::parse_rich(style.clone(), &this.text) {
+                    Ok(spans) => (Some(spans), None),
+                    Err(error) => (None, Some(error)),
+                }
+            } else {
+                (None, None)
+            };
+            Watched::set_if_neq(&mut this.rich_text_error, error);
+            match spans {
+                Some(spans) => {
+                    for (span_style, span) in spans {
+                        this.graphic.push_span(span_style, span);
+                    }
+                }
+                None => this.graphic.push_span(style, &this.text),
+            }
             this.graphic.finish();
+            let size = this
+                .graphic
+                .bounds()
+                .map_or([0.0, 0.0], |rect| [rect.width(), rect.height()]);
+            Watched::set_if_neq(&mut this.content_size, size);
+            Watched::set_if_neq(
+                &mut this.truncated,
+                this.graphic.is_truncated(),
+            );
         });
         desc.graphic(|this| &mut this.graphic);
     }
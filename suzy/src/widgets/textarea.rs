@@ -0,0 +1,826 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use std::{ops::Range, time};
+
+use drying_paint::{Watched, WatchedQueue};
+
+use crate::{
+    app,
+    dims::{Padding2d, Rect, SimpleRect},
+    graphics::{Color, Conditional, CornerStyle},
+    keyboard::{Key, KeyAction, KeyEvent, TextInputData, TextInputEvent},
+    platform::{
+        graphics::{SlicedImage, Text, TextStyle},
+        RenderPlatform,
+    },
+    pointer::{PointerAction, PointerEvent},
+    text,
+    widget::{self, UniqueHandle, UniqueHandleId, Widget, WidgetRect},
+};
+
+use super::editing::{self, CARET_WIDTH, PADDING};
+
+/// A widget which allows editing multiple lines of text
+#[cfg(feature = "platform-opengl")]
+pub type TextArea<P = crate::platforms::DefaultRenderPlatform> =
+    Widget<TextAreaContent<P>>;
+
+/// A widget which allows editing multiple lines of text
+#[cfg(not(feature = "platform-opengl"))]
+pub type TextArea<P> = Widget<TextAreaContent<P>>;
+
+/// A line of text, as displayed after wrapping.
+#[derive(Clone, Copy, Debug)]
+struct Line {
+    start: usize,
+    end: usize,
+}
+
+/// Where the text being composed with an input method is displayed.
+#[derive(Clone, Copy, Debug, Default)]
+struct CompositionSpan {
+    at: usize,
+    len: usize,
+    cursor: usize,
+}
+
+/// The content for a widget which allows editing multiple lines of text.
+///
+/// Text is wrapped to the width of the widget, and scrolls vertically.
+/// Only the lines which are visible are laid out for display, and only the
+/// paragraphs which change are re-wrapped after an edit, so large
+/// documents remain responsive.
+///
+/// Use `TextArea::on_change` to handle edits.
+pub struct TextAreaContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    pub text: Watched<String>,
+    pub font_size: Watched<f32>,
+    pub color: Watched<Color>,
+    on_change: WatchedQueue<'static, ()>,
+    caret: Watched<usize>,
+    anchor: Watched<usize>,
+    composition: Watched<Option<(String, Range<usize>)>>,
+    first_line: Watched<usize>,
+    handle: UniqueHandle,
+    // layout state, in terms of the displayed text
+    laid_out: String,
+    lines: Vec<Line>,
+    composition_span: Option<CompositionSpan>,
+    measured_for: Option<(f32, f32)>,
+    line_height: f32,
+    caret_height: f32,
+    inner: SimpleRect,
+    style: (f32, Color),
+    // interaction state
+    preferred_x: Option<f32>,
+    last_caret: Option<usize>,
+    caret_visible: bool,
+    wheel_remainder: f32,
+    caret_area: Option<SimpleRect>,
+    blink_start: time::Instant,
+    background: P::SlicedImage,
+    selection_images: [Conditional<P::SlicedImage>; 3],
+    caret_image: Conditional<P::SlicedImage>,
+    measure: P::Text,
+    graphic: P::Text,
+}
+
+impl<P> TextAreaContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Handle changes to the text made by the user.
+    pub fn on_change<F: FnOnce()>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.on_change.handle_item(arg, |()| f());
+        });
+    }
+
+    /// Get the position of the caret, as a byte index into `text`.
+    pub fn caret(&self) -> usize {
+        *self.caret
+    }
+
+    /// Get the selected range of `text`.
+    ///
+    /// The range is empty if no text is selected.
+    pub fn selection(&self) -> Range<usize> {
+        editing::ordered(*self.caret, *self.anchor)
+    }
+
+    /// Select a range of `text`, placing the caret at the end of the range.
+    pub fn set_selection(&mut self, range: Range<usize>) {
+        let start = editing::floor_boundary(&self.text, range.start);
+        let end = editing::floor_boundary(&self.text, range.end);
+        *self.anchor = start;
+        *self.caret = end;
+        self.preferred_x = None;
+    }
+
+    /// Select all of `text`.
+    pub fn select_all(&mut self) {
+        *self.anchor = 0;
+        *self.caret = self.text.len();
+        self.preferred_x = None;
+    }
+
+    /// Get the index of the first line of text which is visible.
+    ///
+    /// Lines are counted after wrapping.
+    pub fn first_visible_line(&self) -> usize {
+        *self.first_line
+    }
+
+    /// Get the number of lines of text, after wrapping.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn move_caret(&mut self, index: usize, extend: bool) {
+        *self.caret = index;
+        if !extend {
+            *self.anchor = index;
+        }
+        self.preferred_x = None;
+    }
+
+    fn edit(&mut self, range: Range<usize>, input: &str) {
+        if range.is_empty() && input.is_empty() {
+            return;
+        }
+        let end = editing::replace(&mut self.text, range, input, None);
+        self.move_caret(end, false);
+        self.on_change.push_external(());
+    }
+
+    fn display_index(&self, index: usize) -> usize {
+        match self.composition_span {
+            Some(span) if index > span.at => index + span.len,
+            _ => index,
+        }
+    }
+
+    fn text_index(&self, index: usize) -> usize {
+        match self.composition_span {
+            Some(span) if index >= span.at + span.len => index - span.len,
+            Some(span) => index.min(span.at),
+            None => index,
+        }
+    }
+
+    fn make_style(&self) -> P::TextStyle {
+        let (font_size, color) = self.style;
+        TextStyle::with_size_and_color(font_size, color)
+    }
+
+    fn layout(
+        &self,
+        origin_x: f32,
+        origin_y: f32,
+        wrap: bool,
+    ) -> text::Layout {
+        text::Layout {
+            alignment: text::Alignment::Left,
+            line: text::Line::Ascent,
+            flow: text::Flow::Down,
+            origin_x,
+            origin_y,
+            wrap_width: if wrap {
+                self.inner.width()
+            } else {
+                f32::INFINITY
+            },
+            vertical_limit: text::VerticalLimit::None,
+            overflow_mode: text::OverflowMode::Truncate,
+        }
+    }
+
+    /// Measure the spacing of lines for the current style.
+    fn measure_line_height(&mut self) {
+        let style = self.make_style();
+        self.measure.set_layout(self.layout(0.0, 0.0, false));
+        self.measure.clear();
+        self.measure.push_span(style, "X\nX");
+        self.measure.finish();
+        let (font_size, _) = self.style;
+        (self.line_height, self.caret_height) =
+            match self.measure.line_metrics() {
+                [first, second, ..] => (
+                    first.baseline - second.baseline,
+                    first.ascent - first.descent,
+                ),
+                _ => (font_size, font_size),
+            };
+        if self.line_height <= 0.0 {
+            self.line_height = font_size;
+        }
+    }
+
+    /// Wrap a paragraph, which begins at `offset` in the displayed text.
+    fn wrap_paragraph(&mut self, paragraph: &str, offset: usize) -> Vec<Line> {
+        let style = self.make_style();
+        self.measure.set_layout(self.layout(0.0, 0.0, true));
+        self.measure.clear();
+        self.measure.push_span(style, paragraph);
+        self.measure.finish();
+        let lines: Vec<Line> = self
+            .measure
+            .line_metrics()
+            .iter()
+            .map(|line| Line {
+                start: offset + line.range.start,
+                end: offset + line.range.end,
+            })
+            .collect();
+        if lines.is_empty() {
+            vec![Line {
+                start: offset,
+                end: offset + paragraph.len(),
+            }]
+        } else {
+            lines
+        }
+    }
+
+    /// Bring the wrapped lines up to date with the text, re-wrapping only
+    /// the paragraphs which have changed.
+    fn update_lines(&mut self) {
+        let text: &str = &self.text;
+        let caret = editing::floor_boundary(text, *self.caret);
+        let (display, span) = match &*self.composition {
+            Some((comp, cursor)) => {
+                let mut display = String::with_capacity(text.len());
+                display.push_str(&text[..caret]);
+                display.push_str(comp);
+                display.push_str(&text[caret..]);
+                let span = CompositionSpan {
+                    at: caret,
+                    len: comp.len(),
+                    cursor: editing::floor_boundary(comp, cursor.start),
+                };
+                (display, Some(span))
+            }
+            None => (text.to_owned(), None),
+        };
+        self.composition_span = span;
+        if !self.lines.is_empty() && display == self.laid_out {
+            return;
+        }
+        let old = std::mem::take(&mut self.laid_out);
+        let prefix = old
+            .bytes()
+            .zip(display.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let prefix = editing::floor_boundary(&display, prefix);
+        let mut suffix = old[prefix..]
+            .bytes()
+            .rev()
+            .zip(display[prefix..].bytes().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !display.is_char_boundary(display.len() - suffix) {
+            suffix -= 1;
+        }
+        let para_start = old[..prefix].rfind('\n').map_or(0, |i| i + 1);
+        let old_end = old.len() - suffix;
+        let old_para_end =
+            old[old_end..].find('\n').map_or(old.len(), |i| old_end + i);
+        let new_end = display.len() - suffix;
+        let new_para_end = display[new_end..]
+            .find('\n')
+            .map_or(display.len(), |i| new_end + i);
+        let first = self.lines.partition_point(|l| l.start < para_start);
+        let last = self.lines.partition_point(|l| l.start <= old_para_end);
+        let mut new_lines = Vec::new();
+        let mut offset = para_start;
+        for paragraph in display[para_start..new_para_end].split('\n') {
+            new_lines.extend(self.wrap_paragraph(paragraph, offset));
+            offset += paragraph.len() + 1;
+        }
+        let (grow, shrink) = if display.len() >= old.len() {
+            (display.len() - old.len(), 0)
+        } else {
+            (0, old.len() - display.len())
+        };
+        for line in &mut self.lines[last..] {
+            line.start = line.start + grow - shrink;
+            line.end = line.end + grow - shrink;
+        }
+        self.lines.splice(first..last, new_lines);
+        self.laid_out = display;
+    }
+
+    fn line_of(&self, index: usize) -> usize {
+        self.lines
+            .partition_point(|line| line.start <= index)
+            .saturating_sub(1)
+    }
+
+    /// Get the caret positions within a line, in displayed text indices.
+    fn line_stops(&mut self, line: usize) -> Vec<(usize, f32)> {
+        let Line { start, end } = self.lines[line];
+        let wrapped = self
+            .lines
+            .get(line + 1)
+            .is_some_and(|next| next.start == end);
+        let left = self.inner.left();
+        let style = self.make_style();
+        let text = &self.laid_out[start..end];
+        self.measure.set_layout(self.layout(left, 0.0, false));
+        self.measure.clear();
+        self.measure.push_span(style, text);
+        self.measure.finish();
+        let measure = &self.measure;
+        editing::grapheme_boundaries(text)
+            .filter(|&i| !wrapped || i < text.len())
+            .map(|i| {
+                let x = measure.caret_rect(i).map_or(left, |r| r.left());
+                (start + i, x)
+            })
+            .collect()
+    }
+
+    fn x_of(&mut self, index: usize) -> f32 {
+        let line = self.line_of(index);
+        let stops = self.line_stops(line);
+        stops
+            .iter()
+            .find(|&&(i, _)| i == index)
+            .or(stops.last())
+            .map_or(self.inner.left(), |&(_, x)| x)
+    }
+
+    fn nearest_in_line(&mut self, line: usize, x: f32) -> usize {
+        self.line_stops(line)
+            .into_iter()
+            .min_by(|(_, a), (_, b)| (a - x).abs().total_cmp(&(b - x).abs()))
+            .map_or(self.lines[line].start, |(index, _)| index)
+    }
+
+    fn visible_rows(&self) -> usize {
+        ((self.inner.height() / self.line_height).floor() as usize).max(1)
+    }
+
+    fn max_first_line(&self) -> usize {
+        self.lines.len().saturating_sub(self.visible_rows())
+    }
+
+    fn row_top(&self, line: usize) -> f32 {
+        let row = line as f32 - *self.first_line as f32;
+        self.inner.top() - row * self.line_height
+    }
+
+    fn index_at(&mut self, point: [f32; 2]) -> usize {
+        self.update_lines();
+        let [x, y] = point;
+        let row = ((self.inner.top() - y) / self.line_height).floor();
+        let line = (*self.first_line as f32 + row)
+            .max(0.0)
+            .min(self.lines.len().saturating_sub(1) as f32);
+        let index = self.nearest_in_line(line as usize, x);
+        self.text_index(index)
+    }
+
+    /// Move the caret by a number of lines, keeping its horizontal position.
+    fn move_lines(&mut self, lines: isize, extend: bool) {
+        self.update_lines();
+        let caret = self.display_index(*self.caret);
+        let line = self.line_of(caret);
+        let x = match self.preferred_x {
+            Some(x) => x,
+            None => self.x_of(caret),
+        };
+        let target = line as isize + lines;
+        let index = if target < 0 {
+            0
+        } else if target as usize >= self.lines.len() {
+            self.laid_out.len()
+        } else {
+            self.nearest_in_line(target as usize, x)
+        };
+        self.move_caret(self.text_index(index), extend);
+        self.preferred_x = Some(x);
+    }
+
+    fn scroll_lines(&mut self, lines: isize) {
+        let first = *self.first_line as isize + lines;
+        let first = first.max(0).min(self.max_first_line() as isize);
+        if first as usize != *self.first_line {
+            *self.first_line = first as usize;
+        }
+    }
+
+    fn place_rect(
+        image: &mut Conditional<P::SlicedImage>,
+        left: f32,
+        right: f32,
+        top: f32,
+        bottom: f32,
+    ) {
+        image.enable = left < right && bottom < top;
+        image.graphic.set_horizontal_stretch(left, right);
+        image.graphic.set_vertical_stretch(bottom, top);
+    }
+
+    /// Lay out the visible lines, and position the caret and selection.
+    fn update_graphics(&mut self, focused: bool) {
+        let caret = self.display_index(*self.caret);
+        let caret_line = self.line_of(caret);
+        if self.last_caret != Some(caret) {
+            // scroll so that the caret is visible
+            self.last_caret = Some(caret);
+            let rows = self.visible_rows();
+            let first = *self.first_line;
+            if caret_line < first {
+                *self.first_line = caret_line;
+            } else if caret_line >= first + rows {
+                *self.first_line = caret_line + 1 - rows;
+            }
+        }
+        if *self.first_line > self.max_first_line() {
+            *self.first_line = self.max_first_line();
+        }
+        let first = *self.first_line;
+        let last = (first + self.visible_rows()).min(self.lines.len());
+        let visible_text = match self.lines.get(first..last) {
+            Some([first, .., last]) => &self.laid_out[first.start..last.end],
+            Some([only]) => &self.laid_out[only.start..only.end],
+            _ => "",
+        };
+        let style = self.make_style();
+        self.graphic.set_layout(self.layout(
+            self.inner.left(),
+            self.inner.top(),
+            true,
+        ));
+        self.graphic.clear();
+        self.graphic.push_span(style, visible_text);
+        self.graphic.finish();
+        // position the caret
+        let caret_x = match self.composition_span {
+            Some(span) => self.x_of(span.at + span.cursor),
+            None => self.x_of(caret),
+        };
+        let caret_x = caret_x.min(self.inner.right());
+        let top = self.row_top(caret_line);
+        let bottom = top - self.caret_height;
+        let mut caret_area = SimpleRect::default();
+        caret_area.set_horizontal_stretch(caret_x, caret_x);
+        caret_area.set_vertical_stretch(bottom, top);
+        self.caret_area = Some(caret_area);
+        self.caret_visible = (first..last).contains(&caret_line);
+        self.caret_image.graphic.set_horizontal_stretch(
+            caret_x - CARET_WIDTH / 2.0,
+            caret_x + CARET_WIDTH / 2.0,
+        );
+        self.caret_image.graphic.set_vertical_stretch(bottom, top);
+        // position the selection, using up to three rectangles: one for
+        // the partial first line, one for the whole lines between, and one
+        // for the partial last line
+        let selection = self.selection();
+        let sel_start = self.display_index(selection.start);
+        let sel_end = self.display_index(selection.end);
+        let start_line = self.line_of(sel_start);
+        let end_line = self.line_of(sel_end);
+        let start_x = self.x_of(sel_start);
+        let end_x = self.x_of(sel_end);
+        let (left, right) = (self.inner.left(), self.inner.right());
+        let row_span = |this: &Self, from: usize, to: usize| {
+            let from = from.max(first);
+            let to = to.min(last);
+            let top = this.row_top(from);
+            let bottom = top - (to.max(from) - from) as f32 * this.line_height;
+            (top, bottom)
+        };
+        let show = focused && !selection.is_empty();
+        let rects = if start_line == end_line {
+            [
+                (start_x, end_x, row_span(self, start_line, start_line + 1)),
+                (0.0, 0.0, (0.0, 0.0)),
+                (0.0, 0.0, (0.0, 0.0)),
+            ]
+        } else {
+            [
+                (start_x, right, row_span(self, start_line, start_line + 1)),
+                (left, right, row_span(self, start_line + 1, end_line)),
+                (left, end_x, row_span(self, end_line, end_line + 1)),
+            ]
+        };
+        for (image, (x0, x1, (top, bottom))) in
+            self.selection_images.iter_mut().zip(rects)
+        {
+            Self::place_rect(image, x0, x1, top, bottom);
+            image.enable &= show;
+        }
+    }
+}
+
+impl<P> Default for TextAreaContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        Self {
+            text: Watched::default(),
+            font_size: Watched::new(16.0),
+            color: Watched::new(Color::BLACK),
+            on_change: WatchedQueue::default(),
+            caret: Watched::new(0),
+            anchor: Watched::new(0),
+            composition: Watched::new(None),
+            first_line: Watched::new(0),
+            handle: UniqueHandle::default(),
+            laid_out: String::new(),
+            lines: Vec::new(),
+            composition_span: None,
+            measured_for: None,
+            line_height: 16.0,
+            caret_height: 16.0,
+            inner: SimpleRect::default(),
+            style: (16.0, Color::BLACK),
+            preferred_x: None,
+            last_caret: None,
+            caret_visible: true,
+            wheel_remainder: 0.0,
+            caret_area: None,
+            blink_start: time::Instant::now(),
+            background: P::SlicedImage::default(),
+            selection_images: Default::default(),
+            caret_image: Conditional::default(),
+            measure: P::Text::default(),
+            graphic: P::Text::default(),
+        }
+    }
+}
+
+impl<P> super::TextContent for TextAreaContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn set_text(&mut self, text: &str) {
+        text.clone_into(&mut self.text);
+    }
+}
+
+impl<P> widget::Content<P> for TextAreaContent<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, rect| {
+            this.background.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, _rect| {
+            this.background.set_color(Color::WHITE);
+            this.background.set_slice_padding(Padding2d::uniform(4.0));
+            this.background.set_corners(CornerStyle::Rounded);
+            for image in &mut this.selection_images {
+                image.graphic.set_color(Color::LIGHT_SKY_BLUE);
+            }
+            this.caret_image.graphic.set_color(Color::BLACK);
+        });
+        desc.watch(|this, _rect| {
+            // keep the caret valid if the text is changed directly
+            let text: &str = &this.text;
+            let caret = editing::floor_boundary(text, *this.caret);
+            let anchor = editing::floor_boundary(text, *this.anchor);
+            if caret != *this.caret {
+                *this.caret = caret;
+            }
+            if anchor != *this.anchor {
+                *this.anchor = anchor;
+            }
+        });
+        desc.watch(|this, rect| {
+            let mut inner = SimpleRect::default();
+            inner.set_fill(rect, &Padding2d::uniform(PADDING));
+            this.inner = inner;
+            this.style = (*this.font_size, *this.color);
+            let measured_for = (this.style.0, inner.width());
+            if this.measured_for != Some(measured_for) {
+                // the style or wrap width changed, re-wrap everything
+                this.measured_for = Some(measured_for);
+                this.measure_line_height();
+                this.lines.clear();
+                this.laid_out.clear();
+            }
+            this.update_lines();
+            let focused = this.handle.is_focused();
+            this.update_graphics(focused);
+            this.blink_start = app::time_unwatched();
+        });
+        desc.watch(|this, _rect| {
+            this.caret_image.enable = this.handle.is_focused()
+                && this.caret_visible
+                && editing::caret_blink_on(this.blink_start);
+        });
+        desc.graphic(|this| &mut this.background);
+        desc.graphic(|this| &mut this.selection_images[0]);
+        desc.graphic(|this| &mut this.selection_images[1]);
+        desc.graphic(|this| &mut this.selection_images[2]);
+        desc.graphic(|this| &mut this.graphic);
+        desc.graphic(|this| &mut this.caret_image);
+    }
+
+    fn focus_handle(&self) -> Option<UniqueHandleId> {
+        Some(self.handle.id())
+    }
+
+    fn text_input_area(&self, rect: &WidgetRect) -> Option<SimpleRect> {
+        Some(
+            self.caret_area
+                .unwrap_or_else(|| SimpleRect::new(rect.x(), rect.y())),
+        )
+    }
+
+    fn text_input(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut TextInputEvent<'_>,
+    ) -> bool {
+        match event.data() {
+            TextInputData::Input(input) => {
+                if self.composition.is_some() {
+                    *self.composition = None;
+                }
+                self.edit(self.selection(), input);
+            }
+            TextInputData::Editing { text, cursor } => {
+                if text.is_empty() {
+                    if self.composition.is_some() {
+                        *self.composition = None;
+                    }
+                } else {
+                    self.edit(self.selection(), "");
+                    *self.composition = Some((text.clone(), cursor.clone()));
+                }
+            }
+        }
+        true
+    }
+
+    fn key_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut KeyEvent<'_>,
+    ) -> bool {
+        let modifiers = event.modifiers();
+        let shift = modifiers.shift;
+        let word = modifiers.ctrl || modifiers.meta;
+        let handled = match event.key() {
+            Key::Left
+            | Key::Right
+            | Key::Up
+            | Key::Down
+            | Key::Home
+            | Key::End
+            | Key::PageUp
+            | Key::PageDown
+            | Key::Backspace
+            | Key::Delete
+            | Key::Enter => true,
+            Key::Character('a') if word => true,
+            // the characters themselves arrive as text input
+            Key::Character(_) | Key::Space => {
+                !(modifiers.ctrl || modifiers.alt || modifiers.meta)
+            }
+            _ => false,
+        };
+        let composing = self.composition.is_some();
+        if !handled || composing || event.action() != KeyAction::Down {
+            return handled;
+        }
+        let caret = *self.caret;
+        let selection = self.selection();
+        match event.key() {
+            Key::Left => {
+                let index = if !shift && !selection.is_empty() {
+                    selection.start
+                } else if word {
+                    editing::prev_word(&self.text, caret)
+                } else {
+                    editing::prev_grapheme(&self.text, caret)
+                };
+                self.move_caret(index, shift);
+            }
+            Key::Right => {
+                let index = if !shift && !selection.is_empty() {
+                    selection.end
+                } else if word {
+                    editing::next_word(&self.text, caret)
+                } else {
+                    editing::next_grapheme(&self.text, caret)
+                };
+                self.move_caret(index, shift);
+            }
+            Key::Up => self.move_lines(-1, shift),
+            Key::Down => self.move_lines(1, shift),
+            Key::PageUp | Key::PageDown => {
+                self.update_lines();
+                let rows = self.visible_rows() as isize;
+                let rows = if event.key() == Key::PageUp {
+                    -rows
+                } else {
+                    rows
+                };
+                self.scroll_lines(rows);
+                self.move_lines(rows, shift);
+            }
+            Key::Home | Key::End if word => {
+                let index = if event.key() == Key::Home {
+                    0
+                } else {
+                    self.text.len()
+                };
+                self.move_caret(index, shift);
+            }
+            Key::Home => {
+                self.update_lines();
+                let line = self.line_of(self.display_index(caret));
+                let index = self.text_index(self.lines[line].start);
+                self.move_caret(index, shift);
+            }
+            Key::End => {
+                self.update_lines();
+                let line = self.line_of(self.display_index(caret));
+                let stops = self.line_stops(line);
+                let end = stops.last().map_or(caret, |&(i, _)| i);
+                self.move_caret(self.text_index(end), shift);
+            }
+            Key::Backspace => {
+                let range = if selection.is_empty() {
+                    let start = if word {
+                        editing::prev_word(&self.text, caret)
+                    } else {
+                        editing::prev_grapheme(&self.text, caret)
+                    };
+                    start..caret
+                } else {
+                    selection
+                };
+                self.edit(range, "");
+            }
+            Key::Delete => {
+                let range = if selection.is_empty() {
+                    let end = if word {
+                        editing::next_word(&self.text, caret)
+                    } else {
+                        editing::next_grapheme(&self.text, caret)
+                    };
+                    caret..end
+                } else {
+                    selection
+                };
+                self.edit(range, "");
+            }
+            Key::Enter => self.edit(selection, "\n"),
+            Key::Character('a') if word => self.select_all(),
+            _ => {}
+        }
+        true
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down => {
+                let grabbed = rect.contains(event.pos())
+                    && event.try_grab(self.handle.id());
+                if grabbed {
+                    let index = self.index_at(event.pos());
+                    self.move_caret(index, false);
+                }
+                grabbed
+            }
+            PointerAction::Move(_, _) => {
+                let grabbed = event.is_grabbed_by(self.handle.id());
+                if grabbed {
+                    let index = self.index_at(event.pos());
+                    self.move_caret(index, true);
+                }
+                grabbed
+            }
+            PointerAction::Up => event.try_ungrab(self.handle.id()),
+            &PointerAction::Wheel(_, y) => {
+                let hit = rect.contains(event.pos());
+                if hit {
+                    self.update_lines();
+                    let scroll = self.wheel_remainder + y;
+                    let lines = (scroll / self.line_height).trunc();
+                    self.wheel_remainder = scroll - lines * self.line_height;
+                    self.scroll_lines(lines as isize);
+                }
+                hit
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,557 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use std::{ops::Range, time};
+
+use drying_paint::{Watched, WatchedQueue};
+
+use crate::{
+    app,
+    dims::{Padding2d, Rect, SimpleRect},
+    graphics::{Color, Conditional, CornerStyle},
+    keyboard::{Key, KeyAction, KeyEvent, TextInputData, TextInputEvent},
+    platform::{
+        graphics::{SlicedImage, Text, TextStyle},
+        RenderPlatform,
+    },
+    pointer::{PointerAction, PointerEvent},
+    text,
+    widget::{self, UniqueHandle, UniqueHandleId, Widget, WidgetRect},
+};
+
+use super::editing::{self, CARET_WIDTH, PADDING};
+
+/// A widget which allows editing a single line of text
+#[cfg(feature = "platform-opengl")]
+pub type TextInput<P = crate::platforms::DefaultRenderPlatform> =
+    Widget<TextInputContent<P>>;
+
+/// A widget which allows editing a single line of text
+#[cfg(not(feature = "platform-opengl"))]
+pub type TextInput<P> = Widget<TextInputContent<P>>;
+
+/// The content for a widget which allows editing a single line of text.
+///
+/// The widget takes focus when clicked, or through tab navigation, and
+/// accepts text input while focused.  Use `TextInput::on_change` to handle
+/// edits and `TextInput::on_submit` to handle the enter key.
+pub struct TextInputContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    pub text: Watched<String>,
+    /// Text displayed when `text` is empty.
+    pub placeholder: Watched<String>,
+    pub font_size: Watched<f32>,
+    pub color: Watched<Color>,
+    pub placeholder_color: Watched<Color>,
+    /// If set, every character is displayed as this character instead,
+    /// e.g. for password entry.
+    pub mask: Watched<Option<char>>,
+    /// The maximum number of characters which can be entered.
+    pub max_length: Watched<Option<usize>>,
+    on_change: WatchedQueue<'static, ()>,
+    on_submit: WatchedQueue<'static, ()>,
+    caret: Watched<usize>,
+    anchor: Watched<usize>,
+    composition: Watched<Option<(String, Range<usize>)>>,
+    handle: UniqueHandle,
+    // the displayed text and font size the positions were measured for
+    measured_for: Option<(String, f32)>,
+    /// The grapheme boundaries of the displayed text, and their distance
+    /// from its start.
+    positions: Vec<(usize, f32)>,
+    /// The bottom and top of the caret, relative to the layout origin.
+    caret_extent: Option<(f32, f32)>,
+    scroll: f32,
+    stops: Vec<(usize, f32)>,
+    caret_area: Option<SimpleRect>,
+    blink_start: time::Instant,
+    background: P::SlicedImage,
+    selection_image: Conditional<P::SlicedImage>,
+    caret_image: Conditional<P::SlicedImage>,
+    measure: P::Text,
+    graphic: P::Text,
+}
+
+/// The text actually laid out by a text input.
+struct Display {
+    text: String,
+    color: Color,
+    /// Pairs of indices into the edited text and the displayed text.
+    bounds: Vec<(usize, usize)>,
+    caret: usize,
+}
+
+impl<P> TextInputContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    /// Handle changes to the text made by the user.
+    pub fn on_change<F: FnOnce()>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.on_change.handle_item(arg, |()| f());
+        });
+    }
+
+    /// Handle the user pressing enter.
+    pub fn on_submit<F: FnOnce()>(&self, f: F) {
+        crate::watch::WatchArg::try_with_current(|arg| {
+            self.on_submit.handle_item(arg, |()| f());
+        });
+    }
+
+    /// Get the position of the caret, as a byte index into `text`.
+    pub fn caret(&self) -> usize {
+        *self.caret
+    }
+
+    /// Get the selected range of `text`.
+    ///
+    /// The range is empty if no text is selected.
+    pub fn selection(&self) -> Range<usize> {
+        editing::ordered(*self.caret, *self.anchor)
+    }
+
+    /// Select a range of `text`, placing the caret at the end of the range.
+    pub fn set_selection(&mut self, range: Range<usize>) {
+        let start = editing::floor_boundary(&self.text, range.start);
+        let end = editing::floor_boundary(&self.text, range.end);
+        *self.anchor = start;
+        *self.caret = end;
+    }
+
+    /// Select all of `text`.
+    pub fn select_all(&mut self) {
+        *self.anchor = 0;
+        *self.caret = self.text.len();
+    }
+
+    fn move_caret(&mut self, index: usize, extend: bool) {
+        *self.caret = index;
+        if !extend {
+            *self.anchor = index;
+        }
+    }
+
+    fn edit(&mut self, range: Range<usize>, input: &str) {
+        if range.is_empty() && input.is_empty() {
+            return;
+        }
+        let max_length = *self.max_length;
+        let end = editing::replace(&mut self.text, range, input, max_length);
+        self.move_caret(end, false);
+        self.on_change.push_external(());
+    }
+
+    fn nearest_index(&self, x: f32) -> usize {
+        self.stops
+            .iter()
+            .min_by(|(_, a), (_, b)| (a - x).abs().total_cmp(&(b - x).abs()))
+            .map_or(0, |&(index, _)| index)
+    }
+
+    fn layout(origin_x: f32, origin_y: f32) -> text::Layout {
+        text::Layout {
+            alignment: text::Alignment::Left,
+            line: text::Line::BetweenBaseAndCap,
+            flow: text::Flow::Out,
+            origin_x,
+            origin_y,
+            wrap_width: f32::INFINITY,
+            vertical_limit: text::VerticalLimit::Lines(1),
+            overflow_mode: text::OverflowMode::Truncate,
+        }
+    }
+
+    /// Measure the positions of the grapheme boundaries of the displayed
+    /// text, if it or the font size changed since they were last measured.
+    fn measure_positions(&mut self, display: &Display) {
+        let font_size = *self.font_size;
+        let measured_for = (display.text.clone(), font_size);
+        if self.measured_for.as_ref() == Some(&measured_for) {
+            return;
+        }
+        let style = TextStyle::with_size_and_color(font_size, display.color);
+        self.measure.set_layout(Self::layout(0.0, 0.0));
+        self.measure.clear();
+        self.measure.push_span(style, &display.text);
+        self.measure.finish();
+        let measure = &self.measure;
+        self.positions = editing::grapheme_boundaries(&display.text)
+            .map(|i| (i, measure.caret_rect(i).map_or(0.0, |r| r.left())))
+            .collect();
+        self.caret_extent =
+            measure.caret_rect(0).map(|r| (r.bottom(), r.top()));
+        self.measured_for = Some(measured_for);
+    }
+
+    fn display(&self) -> Display {
+        let text: &str = &self.text;
+        let composition = &*self.composition;
+        if text.is_empty() && composition.is_none() {
+            return Display {
+                text: (*self.placeholder).clone(),
+                color: *self.placeholder_color,
+                bounds: vec![(0, 0)],
+                caret: 0,
+            };
+        }
+        let mask = *self.mask;
+        let masked_len = |s: &str| match mask {
+            Some(mask) => s.chars().count() * mask.len_utf8(),
+            None => s.len(),
+        };
+        let push = |display: &mut String, s: &str| match mask {
+            Some(mask) => display.extend(s.chars().map(|_| mask)),
+            None => display.push_str(s),
+        };
+        let caret = *self.caret;
+        let mut display = Display {
+            text: String::new(),
+            color: *self.color,
+            bounds: Vec::new(),
+            caret: 0,
+        };
+        for index in editing::grapheme_boundaries(text) {
+            display.bounds.push((index, display.text.len()));
+            if index == caret {
+                display.caret = display.text.len();
+                if let Some((comp, cursor)) = composition {
+                    let cursor = editing::floor_boundary(comp, cursor.start);
+                    display.caret += masked_len(&comp[..cursor]);
+                    push(&mut display.text, comp);
+                }
+            }
+            let next = editing::next_grapheme(text, index);
+            push(&mut display.text, &text[index..next]);
+        }
+        display
+    }
+}
+
+impl<P> Default for TextInputContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn default() -> Self {
+        Self {
+            text: Watched::default(),
+            placeholder: Watched::default(),
+            font_size: Watched::new(16.0),
+            color: Watched::new(Color::BLACK),
+            placeholder_color: Watched::new(Color::DARK_GRAY),
+            mask: Watched::new(None),
+            max_length: Watched::new(None),
+            on_change: WatchedQueue::default(),
+            on_submit: WatchedQueue::default(),
+            caret: Watched::new(0),
+            anchor: Watched::new(0),
+            composition: Watched::new(None),
+            handle: UniqueHandle::default(),
+            measured_for: None,
+            positions: Vec::new(),
+            caret_extent: None,
+            scroll: 0.0,
+            stops: Vec::new(),
+            caret_area: None,
+            blink_start: time::Instant::now(),
+            background: P::SlicedImage::default(),
+            selection_image: Conditional::default(),
+            caret_image: Conditional::default(),
+            measure: P::Text::default(),
+            graphic: P::Text::default(),
+        }
+    }
+}
+
+impl<P> super::TextContent for TextInputContent<P>
+where
+    P: ?Sized + RenderPlatform,
+{
+    fn set_text(&mut self, text: &str) {
+        text.clone_into(&mut self.text);
+    }
+}
+
+impl<P> widget::Content<P> for TextInputContent<P>
+where
+    P: RenderPlatform,
+{
+    fn desc(mut desc: impl widget::Desc<Self, P>) {
+        desc.watch(|this, rect| {
+            this.background.set_fill(rect, &Padding2d::zero());
+        });
+        desc.watch(|this, _rect| {
+            this.background.set_color(Color::WHITE);
+            this.background.set_slice_padding(Padding2d::uniform(4.0));
+            this.background.set_corners(CornerStyle::Rounded);
+            this.selection_image
+                .graphic
+                .set_color(Color::LIGHT_SKY_BLUE);
+            this.caret_image.graphic.set_color(Color::BLACK);
+        });
+        desc.watch(|this, _rect| {
+            // keep the caret valid if the text is changed directly
+            let text: &str = &this.text;
+            let caret = editing::floor_boundary(text, *this.caret);
+            let anchor = editing::floor_boundary(text, *this.anchor);
+            if caret != *this.caret {
+                *this.caret = caret;
+            }
+            if anchor != *this.anchor {
+                *this.anchor = anchor;
+            }
+        });
+        desc.watch(|this, rect| {
+            let display = this.display();
+            this.measure_positions(&display);
+            let positions = &this.positions;
+            let position_of = |index: usize| {
+                positions
+                    .binary_search_by_key(&index, |&(i, _)| i)
+                    .map_or(0.0, |pos| positions[pos].1)
+            };
+            let caret_x = position_of(display.caret);
+            // scroll so that the caret is visible
+            let inner_left = rect.left() + PADDING;
+            let inner_width = (rect.width() - 2.0 * PADDING).max(0.0);
+            let total = positions.last().map_or(0.0, |&(_, x)| x);
+            let mut scroll = this.scroll;
+            if caret_x - scroll > inner_width {
+                scroll = caret_x - inner_width;
+            }
+            if caret_x < scroll {
+                scroll = caret_x;
+            }
+            scroll = scroll.min(total - inner_width).max(0.0);
+            this.scroll = scroll;
+            let screen_x = |x: f32| inner_left - scroll + x;
+            // only lay out the characters which fit
+            const EPSILON: f32 = 0.01;
+            let (start, start_x) = positions
+                .iter()
+                .copied()
+                .find(|&(_, x)| x >= scroll - EPSILON)
+                .unwrap_or_default();
+            let end = positions
+                .iter()
+                .rev()
+                .find(|&&(_, x)| x <= scroll + inner_width + EPSILON)
+                .map_or(start, |&(i, _)| i.max(start));
+            let origin_y = rect.center_y();
+            let style =
+                TextStyle::with_size_and_color(*this.font_size, display.color);
+            this.graphic
+                .set_layout(Self::layout(screen_x(start_x), origin_y));
+            this.graphic.clear();
+            this.graphic.push_span(style, &display.text[start..end]);
+            this.graphic.finish();
+            this.stops = display
+                .bounds
+                .iter()
+                .map(|&(index, display_index)| {
+                    (index, screen_x(position_of(display_index)))
+                })
+                .collect();
+            // position the caret and selection
+            let inner_right = inner_left + inner_width;
+            let clamp_x = |x: f32| x.max(inner_left).min(inner_right);
+            let (bottom, top) = this
+                .caret_extent
+                .map_or((rect.bottom(), rect.top()), |(bottom, top)| {
+                    (origin_y + bottom, origin_y + top)
+                });
+            let caret_x = clamp_x(screen_x(caret_x));
+            let mut caret_area = SimpleRect::default();
+            caret_area.set_horizontal_stretch(caret_x, caret_x);
+            caret_area.set_vertical_stretch(bottom, top);
+            this.caret_area = Some(caret_area);
+            this.caret_image.graphic.set_horizontal_stretch(
+                caret_x - CARET_WIDTH / 2.0,
+                caret_x + CARET_WIDTH / 2.0,
+            );
+            this.caret_image.graphic.set_vertical_stretch(bottom, top);
+            let selection = this.selection();
+            let selection_x = |index: usize| {
+                let display_index = display
+                    .bounds
+                    .binary_search_by_key(&index, |&(i, _)| i)
+                    .map_or(0, |pos| display.bounds[pos].1);
+                clamp_x(screen_x(position_of(display_index)))
+            };
+            this.selection_image.enable =
+                !selection.is_empty() && this.handle.is_focused();
+            this.selection_image.graphic.set_horizontal_stretch(
+                selection_x(selection.start),
+                selection_x(selection.end),
+            );
+            this.selection_image
+                .graphic
+                .set_vertical_stretch(bottom, top);
+            this.blink_start = app::time_unwatched();
+        });
+        desc.watch(|this, _rect| {
+            this.caret_image.enable = this.handle.is_focused()
+                && editing::caret_blink_on(this.blink_start);
+        });
+        desc.graphic(|this| &mut this.background);
+        desc.graphic(|this| &mut this.selection_image);
+        desc.graphic(|this| &mut this.graphic);
+        desc.graphic(|this| &mut this.caret_image);
+    }
+
+    fn focus_handle(&self) -> Option<UniqueHandleId> {
+        Some(self.handle.id())
+    }
+
+    fn text_input_area(&self, rect: &WidgetRect) -> Option<SimpleRect> {
+        Some(
+            self.caret_area
+                .unwrap_or_else(|| SimpleRect::new(rect.x(), rect.y())),
+        )
+    }
+
+    fn text_input(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut TextInputEvent<'_>,
+    ) -> bool {
+        match event.data() {
+            TextInputData::Input(input) => {
+                if self.composition.is_some() {
+                    *self.composition = None;
+                }
+                self.edit(self.selection(), input);
+            }
+            TextInputData::Editing { text, cursor } => {
+                if text.is_empty() {
+                    if self.composition.is_some() {
+                        *self.composition = None;
+                    }
+                } else {
+                    self.edit(self.selection(), "");
+                    *self.composition = Some((text.clone(), cursor.clone()));
+                }
+            }
+        }
+        true
+    }
+
+    fn key_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut KeyEvent<'_>,
+    ) -> bool {
+        let modifiers = event.modifiers();
+        let shift = modifiers.shift;
+        let word = modifiers.ctrl || modifiers.meta;
+        let handled = match event.key() {
+            Key::Left
+            | Key::Right
+            | Key::Home
+            | Key::End
+            | Key::Backspace
+            | Key::Delete
+            | Key::Enter => true,
+            Key::Character('a') if word => true,
+            // the characters themselves arrive as text input
+            Key::Character(_) | Key::Space => {
+                !(modifiers.ctrl || modifiers.alt || modifiers.meta)
+            }
+            _ => false,
+        };
+        let composing = self.composition.is_some();
+        if !handled || composing || event.action() != KeyAction::Down {
+            return handled;
+        }
+        let text: &str = &self.text;
+        let caret = *self.caret;
+        let selection = self.selection();
+        match event.key() {
+            Key::Left => {
+                let index = if !shift && !selection.is_empty() {
+                    selection.start
+                } else if word {
+                    editing::prev_word(text, caret)
+                } else {
+                    editing::prev_grapheme(text, caret)
+                };
+                self.move_caret(index, shift);
+            }
+            Key::Right => {
+                let index = if !shift && !selection.is_empty() {
+                    selection.end
+                } else if word {
+                    editing::next_word(text, caret)
+                } else {
+                    editing::next_grapheme(text, caret)
+                };
+                self.move_caret(index, shift);
+            }
+            Key::Home => self.move_caret(0, shift),
+            Key::End => {
+                let end = text.len();
+                self.move_caret(end, shift);
+            }
+            Key::Backspace => {
+                let range = if selection.is_empty() {
+                    let start = if word {
+                        editing::prev_word(text, caret)
+                    } else {
+                        editing::prev_grapheme(text, caret)
+                    };
+                    start..caret
+                } else {
+                    selection
+                };
+                self.edit(range, "");
+            }
+            Key::Delete => {
+                let range = if selection.is_empty() {
+                    let end = if word {
+                        editing::next_word(text, caret)
+                    } else {
+                        editing::next_grapheme(text, caret)
+                    };
+                    caret..end
+                } else {
+                    selection
+                };
+                self.edit(range, "");
+            }
+            Key::Enter => self.on_submit.push_external(()),
+            Key::Character('a') if word => self.select_all(),
+            _ => {}
+        }
+        true
+    }
+
+    fn pointer_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut PointerEvent<'_>,
+    ) -> bool {
+        match event.action() {
+            PointerAction::Down => {
+                let grabbed = rect.contains(event.pos())
+                    && event.try_grab(self.handle.id());
+                if grabbed {
+                    let index = self.nearest_index(event.x());
+                    self.move_caret(index, false);
+                }
+                grabbed
+            }
+            PointerAction::Move(_, _) => {
+                let grabbed = event.is_grabbed_by(self.handle.id());
+                if grabbed {
+                    let index = self.nearest_index(event.x());
+                    self.move_caret(index, true);
+                }
+                grabbed
+            }
+            PointerAction::Up => event.try_ungrab(self.handle.id()),
+            _ => false,
+        }
+    }
+}
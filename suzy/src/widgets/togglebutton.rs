@@ -6,9 +6,12 @@ use std::rc::Rc;
 use drying_paint::{Watched, WatchedCell};
 
 use crate::{
+    keyboard::{Key, KeyAction, KeyEvent},
     pointer::{PointerAction, PointerEvent},
-    selectable::{Selectable, SelectionState, SelectionStateV1},
-    widget::{self, UniqueHandle, Widget, WidgetRect},
+    selectable::{
+        Selectable, SelectionState, SelectionStateV1, SelectionStateV2,
+    },
+    widget::{self, UniqueHandle, UniqueHandleId, Widget, WidgetRect},
 };
 
 /// A group of toggle buttons make members of the group mutually exclusive.
@@ -85,6 +88,7 @@ pub struct ToggleButtonContent<T, V = ()> {
     allow_unselect: bool,
     interactable: Watched<bool>,
     pointers_down: usize,
+    key_down: bool,
     just_clicked: bool,
     currently_selected: bool,
     handle: UniqueHandle,
@@ -121,6 +125,33 @@ impl<T, V> ToggleButtonContent<T, V> {
             SelectionState::normal()
         }
     }
+
+    fn rest_state(&self) -> SelectionState {
+        if !self.currently_selected && self.handle.is_focused() {
+            SelectionState::focus()
+        } else {
+            self.base_state()
+        }
+    }
+
+    fn toggle(&mut self, rect: &WidgetRect)
+    where
+        T: ToggleButtonValue<V>,
+    {
+        if !self.currently_selected {
+            self.just_clicked = true;
+            if let Some(group) = &*self.group {
+                group.set(self.content.get_value(rect));
+            }
+            self.currently_selected = true;
+        } else if self.allow_unselect {
+            if let Some(group) = &*self.group {
+                group.unset();
+            }
+            self.currently_selected = false;
+        }
+        *self.state = self.rest_state();
+    }
 }
 
 impl<T, V, P> widget::Content<P> for ToggleButtonContent<T, V>
@@ -155,7 +186,19 @@ where
             }
         });
         desc.watch(|button, _rect| {
-            let base_state = button.base_state();
+            let focused = button.handle.is_focused();
+            match (button.state.v2(), focused) {
+                (SelectionStateV2::Normal, true) => {
+                    *button.state = SelectionState::focus();
+                }
+                (SelectionStateV2::Focus, false) => {
+                    *button.state = SelectionState::normal();
+                }
+                _ => {}
+            }
+        });
+        desc.watch(|button, _rect| {
+            let rest_state = button.rest_state();
             let Self {
                 pointers_down,
                 state,
@@ -165,7 +208,7 @@ where
             handle.handle_pointer_grab_stolen(|_pointer_id| {
                 *pointers_down -= 1;
                 if *pointers_down == 0 {
-                    **state = base_state;
+                    **state = rest_state;
                 }
             });
         });
@@ -175,6 +218,35 @@ where
         self.content.hittest(rect, point)
     }
 
+    fn focus_handle(&self) -> Option<UniqueHandleId> {
+        (*self.interactable).then(|| self.handle.id())
+    }
+
+    fn key_event(
+        &mut self,
+        rect: &WidgetRect,
+        event: &mut KeyEvent<'_>,
+    ) -> bool {
+        if !matches!(event.key(), Key::Enter | Key::Space) {
+            return false;
+        }
+        match event.action() {
+            KeyAction::Down => {
+                if !event.is_repeat() && *self.interactable {
+                    self.key_down = true;
+                    *self.state = SelectionState::pressed();
+                }
+            }
+            KeyAction::Up => {
+                if self.key_down {
+                    self.key_down = false;
+                    self.toggle(rect);
+                }
+            }
+        }
+        true
+    }
+
     fn pointer_event(
         &mut self,
         rect: &WidgetRect,
@@ -199,7 +271,7 @@ where
                 if ungrabbed {
                     self.pointers_down -= 1;
                     if self.pointers_down == 0 {
-                        *self.state = self.base_state();
+                        *self.state = self.rest_state();
                     }
                 }
                 ungrabbed
@@ -209,26 +281,17 @@ where
                 if ungrabbed {
                     self.pointers_down -= 1;
                     if self.pointers_down == 0 {
-                        if !self.currently_selected {
-                            self.just_clicked = true;
-                            if let Some(group) = &*self.group {
-                                group.set(self.content.get_value(rect));
-                            }
-                            self.currently_selected = true;
-                        } else if self.allow_unselect {
-                            if let Some(group) = &*self.group {
-                                group.unset();
-                            }
-                            self.currently_selected = false;
-                        }
-                        *self.state = self.base_state();
+                        self.toggle(rect);
                     }
                 }
                 ungrabbed
             }
             PointerAction::Hover(_, _) => {
                 match (self.state.v1(), self.hittest(rect, event.pos())) {
-                    (SelectionStateV1::Normal, true) => {
+                    (
+                        SelectionStateV1::Normal | SelectionStateV1::Focus,
+                        true,
+                    ) => {
                         let grabbed = event.try_grab(self.handle.id());
                         if grabbed && *self.interactable {
                             *self.state = SelectionState::hover();
@@ -238,7 +301,7 @@ where
                     (SelectionStateV1::Hover, false) => {
                         let ungrabbed = event.try_ungrab(self.handle.id());
                         if ungrabbed {
-                            *self.state = self.base_state();
+                            *self.state = self.rest_state();
                         }
                         ungrabbed
                     }
@@ -258,6 +321,7 @@ impl<T: Default, V> Default for ToggleButtonContent<T, V> {
             allow_unselect: true,
             interactable: Watched::new(true),
             pointers_down: 0,
+            key_down: false,
             just_clicked: false,
             currently_selected: false,
             handle: UniqueHandle::default(),
@@ -0,0 +1,117 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+#![cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+
+use suzy::{
+    dims::{Padding, Padding2d, Rect},
+    graphics::Color,
+    platforms::opengl::{
+        ClipRect, Mat4, OpenGlRenderPlatform, SlicedImage, Transform,
+    },
+    widget,
+};
+
+mod utils;
+use utils::*;
+
+#[derive(Default)]
+struct TopHalf {
+    clip: ClipRect,
+    image: SlicedImage,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for TopHalf {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.clip.set_fill_width(rect, Padding::zero());
+            root.clip.set_height(rect.height() / 2.0);
+            root.clip.set_top(rect.top());
+            root.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.clip);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn clip_top_half() {
+    let capture = render_opengl::<TopHalf>();
+    let index = (capture.len() / 2) & ALIGN_MASK;
+    let (bottom, top) = capture.split_at(index);
+    assert!(is_color(bottom, Color::BLACK));
+    assert!(is_color(top, Color::WHITE));
+}
+
+#[derive(Default)]
+struct Nested {
+    top: ClipRect,
+    right: ClipRect,
+    image: SlicedImage,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Nested {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.top.set_fill_width(rect, Padding::zero());
+            root.top.set_height(rect.height() / 2.0);
+            root.top.set_top(rect.top());
+            root.right.set_fill_height(rect, Padding::zero());
+            root.right.set_width(rect.width() / 2.0);
+            root.right.set_right(rect.right());
+            root.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.top);
+        desc.graphic(|this| &mut this.right);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn nested_clips_intersect() {
+    let capture = render_opengl::<Nested>();
+    for (y, row) in capture.chunks_exact(480 * 4).enumerate() {
+        let (left, right) = row.split_at(240 * 4);
+        assert!(is_color(left, Color::BLACK), "row {}", y);
+        let expected = if y < 180 { Color::BLACK } else { Color::WHITE };
+        assert!(is_color(right, expected), "row {}", y);
+    }
+}
+
+#[derive(Default)]
+struct Rotated {
+    transform: Transform,
+    clip: ClipRect,
+    image: SlicedImage,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Rotated {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            let (x, y) = (rect.center_x(), rect.center_y());
+            root.transform.matrix = Mat4::translate(x, y)
+                * Mat4::rotate(std::f32::consts::FRAC_PI_4)
+                * Mat4::translate(-x, -y);
+            root.clip.set_width(100.0);
+            root.clip.set_height(100.0);
+            root.clip.set_center_x(x);
+            root.clip.set_center_y(y);
+            root.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.transform);
+        desc.graphic(|this| &mut this.clip);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn rotated_clip_uses_a_mask() {
+    let capture = render_opengl::<Rotated>();
+    // the clip is a diamond, so its corners reach further out than the
+    // unrotated square along the axes, and the square's corners are cut off
+    assert_eq!(pixel(&capture, 240, 180), WHITE);
+    assert_eq!(pixel(&capture, 240, 240), WHITE);
+    assert_eq!(pixel(&capture, 300, 180), WHITE);
+    assert_eq!(pixel(&capture, 285, 225), BLACK);
+    assert_eq!(pixel(&capture, 195, 135), BLACK);
+}
@@ -0,0 +1,116 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use std::{cell::Cell, rc::Rc};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::Rect,
+    keyboard::{Key, KeyAction, KeyEventData, Modifiers, ScanCode},
+    platforms::no_graphics::NoGraphics,
+    pointer::{PointerAction, PointerEventData, PointerId},
+    selectable::{SelectableIgnored, SelectionState},
+    widget::{self, Widget},
+    widgets::Button,
+};
+
+#[derive(Default)]
+struct Root {
+    first: Button<SelectableIgnored<()>>,
+    second: Button<SelectableIgnored<()>>,
+    states: Rc<Cell<[SelectionState; 2]>>,
+    clicks: Rc<Cell<u32>>,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.watch(|root, _rect| {
+            root.first.set_horizontal_stretch(0.0, 100.0);
+            root.first.set_vertical_stretch(0.0, 100.0);
+            root.second.set_horizontal_stretch(200.0, 300.0);
+            root.second.set_vertical_stretch(0.0, 100.0);
+        });
+        desc.watch(|root, _rect| {
+            root.states.set([root.first.state(), root.second.state()]);
+        });
+        desc.watch(|root, _rect| {
+            let clicks = &root.clicks;
+            root.second.on_click(|| clicks.set(clicks.get() + 1));
+        });
+        desc.child(|root| &mut root.first);
+        desc.child(|root| &mut root.second);
+    }
+}
+
+#[test]
+fn tab_navigation() {
+    const FOCUS: SelectionState = SelectionState::focus();
+    const NORMAL: SelectionState = SelectionState::normal();
+
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let root = Widget::<Root>::default();
+    let states = Rc::clone(&root.states);
+    let clicks = Rc::clone(&root.clicks);
+    app.add_root(root);
+    app.update_watches();
+    assert_eq!(states.get(), [NORMAL, NORMAL]);
+
+    app.key_press(Key::Tab);
+    app.update_watches();
+    assert_eq!(states.get(), [FOCUS, NORMAL]);
+
+    app.key_press(Key::Tab);
+    app.update_watches();
+    assert_eq!(states.get(), [NORMAL, FOCUS]);
+
+    // focus wraps around
+    app.key_press(Key::Tab);
+    app.update_watches();
+    assert_eq!(states.get(), [FOCUS, NORMAL]);
+
+    app.key_event(KeyEventData {
+        key: Key::Tab,
+        scancode: ScanCode(0),
+        modifiers: Modifiers {
+            shift: true,
+            ..Modifiers::NONE
+        },
+        action: KeyAction::Down,
+        repeat: false,
+    });
+    app.update_watches();
+    assert_eq!(states.get(), [NORMAL, FOCUS]);
+
+    app.key_press(Key::Enter);
+    app.update_watches();
+    assert_eq!(clicks.get(), 1);
+    assert_eq!(states.get(), [NORMAL, FOCUS]);
+}
+
+#[test]
+fn click_to_focus() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let root = Widget::<Root>::default();
+    let states = Rc::clone(&root.states);
+    app.add_root(root);
+    app.update_watches();
+
+    app.mouse_click([250.0, 50.0]);
+    app.update_watches();
+    assert_eq!(states.get()[1], SelectionState::hover());
+
+    // moving the mouse away leaves the button focused
+    app.pointer_event(PointerEventData {
+        id: PointerId::Mouse,
+        action: PointerAction::Hover(150.0, 250.0),
+        x: 400.0,
+        y: 300.0,
+    });
+    app.update_watches();
+    assert_eq!(states.get()[1], SelectionState::focus());
+
+    // clicking on nothing clears focus
+    app.mouse_click([400.0, 300.0]);
+    app.update_watches();
+    assert_eq!(states.get()[1], SelectionState::normal());
+}
@@ -0,0 +1,157 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::{Rect, SimpleRect},
+    keyboard::{Key, KeyAction, KeyEvent, TextInputData, TextInputEvent},
+    platforms::no_graphics::NoGraphics,
+    widget::{self, UniqueHandle, UniqueHandleId, Widget, WidgetRect},
+};
+
+type Log = Rc<RefCell<Vec<(&'static str, Key)>>>;
+
+#[derive(Default)]
+struct Leaf {
+    name: &'static str,
+    handle: UniqueHandle,
+    handles: Option<Key>,
+    log: Log,
+}
+
+impl widget::Content<NoGraphics> for Leaf {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn key_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut KeyEvent<'_>,
+    ) -> bool {
+        if event.action() == KeyAction::Down {
+            self.log.borrow_mut().push((self.name, event.key()));
+        }
+        self.handles == Some(event.key())
+    }
+
+    fn focus_handle(&self) -> Option<UniqueHandleId> {
+        Some(self.handle.id())
+    }
+}
+
+#[derive(Default)]
+struct Root {
+    first: Widget<Leaf>,
+    second: Widget<Leaf>,
+    log: Log,
+}
+
+impl widget::Content<NoGraphics> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, NoGraphics>) {
+        desc.child(|root| &mut root.first);
+        desc.child(|root| &mut root.second);
+    }
+
+    fn key_event(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut KeyEvent<'_>,
+    ) -> bool {
+        if event.action() == KeyAction::Down {
+            self.log.borrow_mut().push(("root", event.key()));
+        }
+        true
+    }
+}
+
+#[test]
+fn key_events_follow_focus() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let log = Log::default();
+    let mut root = Widget::<Root>::default();
+    root.log = Rc::clone(&log);
+    root.first.name = "first";
+    root.first.log = Rc::clone(&log);
+    root.second.name = "second";
+    root.second.log = Rc::clone(&log);
+    root.second.handles = Some(Key::Enter);
+    let focus = root.second.handle.id();
+    app.add_root(root);
+
+    // with no focus, the root receives the event
+    app.key_press(Key::Character('a'));
+    app.set_focus(Some(focus));
+    app.key_press(Key::Enter);
+    // unhandled by the focused widget, so it bubbles up to the root
+    app.key_press(Key::Escape);
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            ("root", Key::Character('a')),
+            ("second", Key::Enter),
+            ("second", Key::Escape),
+            ("root", Key::Escape),
+        ],
+    );
+}
+
+#[derive(Default)]
+struct TextField {
+    handle: UniqueHandle,
+    committed: String,
+    composing: Rc<RefCell<Option<(String, String)>>>,
+}
+
+impl widget::Content<NoGraphics> for TextField {
+    fn desc(_desc: impl widget::Desc<Self, NoGraphics>) {}
+
+    fn text_input(
+        &mut self,
+        _rect: &WidgetRect,
+        event: &mut TextInputEvent<'_>,
+    ) -> bool {
+        let preedit = match event.data() {
+            TextInputData::Input(text) => {
+                self.committed.push_str(text);
+                String::new()
+            }
+            TextInputData::Editing { text, cursor } => {
+                text[..cursor.start].to_string()
+            }
+        };
+        *self.composing.borrow_mut() = Some((self.committed.clone(), preedit));
+        true
+    }
+
+    fn text_input_area(&self, rect: &WidgetRect) -> Option<SimpleRect> {
+        Some(SimpleRect::new(rect.x(), rect.y()))
+    }
+
+    fn focus_handle(&self) -> Option<UniqueHandleId> {
+        Some(self.handle.id())
+    }
+}
+
+#[test]
+fn text_input_requires_focus() {
+    let mut app = App::<NoGraphics>::new(480.0, 360.0);
+    let field = Widget::<TextField>::default();
+    let state = Rc::clone(&field.composing);
+    let focus = field.handle.id();
+    app.add_root(field);
+
+    assert!(app.text_input_area().is_none());
+    app.set_focus(Some(focus));
+    let area = app.text_input_area().expect("field accepts text");
+    assert!((area.width() - 480.0).abs() < f32::EPSILON);
+
+    app.text_input(TextInputData::Editing {
+        text: "にほ".to_string(),
+        cursor: 6..6,
+    });
+    assert_eq!(*state.borrow(), Some((String::new(), "にほ".to_string())),);
+    app.text_input(TextInputData::Input("日本".to_string()));
+    assert_eq!(*state.borrow(), Some(("日本".to_string(), String::new())),);
+}
@@ -51,3 +51,90 @@ fn mask_right_half() {
     assert!(is_color(bottom, Color::BLACK));
     assert!(is_color(top, Color::WHITE));
 }
+
+#[derive(Default)]
+struct TwoLevels {
+    top: Mask<SlicedImage>,
+    right: Mask<SlicedImage>,
+    image: SlicedImage,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for TwoLevels {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.top.graphic.set_fill_width(rect, Padding::zero());
+            root.top.graphic.set_height(rect.height() / 2.0);
+            root.top.graphic.set_top(rect.top());
+            root.right.graphic.set_fill_height(rect, Padding::zero());
+            root.right.graphic.set_width(rect.width() / 2.0);
+            root.right.graphic.set_right(rect.right());
+            root.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.top);
+        desc.graphic(|this| &mut this.right);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn mask_two_levels() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    app.add_root(Widget::<TwoLevels>::default());
+    let capture = window.draw_and_take_screenshot(&mut app);
+    // only the top right quarter is inside both masks
+    assert_eq!(pixel(&capture, 360, 270), WHITE);
+    assert_eq!(pixel(&capture, 120, 270), BLACK);
+    assert_eq!(pixel(&capture, 360, 90), BLACK);
+    assert_eq!(pixel(&capture, 120, 90), BLACK);
+}
+
+#[derive(Default)]
+struct ThreeLevels {
+    top: Mask<SlicedImage>,
+    right: Mask<SlicedImage>,
+    middle: Mask<SlicedImage>,
+    image: SlicedImage,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for ThreeLevels {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.top.graphic.set_fill_width(rect, Padding::zero());
+            root.top.graphic.set_height(rect.height() / 2.0);
+            root.top.graphic.set_top(rect.top());
+            root.right.graphic.set_fill_height(rect, Padding::zero());
+            root.right.graphic.set_width(rect.width() / 2.0);
+            root.right.graphic.set_right(rect.right());
+            root.middle.graphic.set_fill(
+                rect,
+                &Padding2d::new(
+                    rect.height() / 4.0,
+                    rect.width() / 4.0,
+                    rect.height() / 4.0,
+                    rect.width() / 4.0,
+                ),
+            );
+            root.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.top);
+        desc.graphic(|this| &mut this.right);
+        desc.graphic(|this| &mut this.middle);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn mask_three_levels() {
+    let mut window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    app.add_root(Widget::<ThreeLevels>::default());
+    let capture = window.draw_and_take_screenshot(&mut app);
+    // only the part of the middle in the top right quarter is inside all
+    // three masks
+    assert_eq!(pixel(&capture, 300, 225), WHITE);
+    assert_eq!(pixel(&capture, 420, 225), BLACK);
+    assert_eq!(pixel(&capture, 300, 315), BLACK);
+    assert_eq!(pixel(&capture, 180, 225), BLACK);
+    assert_eq!(pixel(&capture, 300, 135), BLACK);
+}
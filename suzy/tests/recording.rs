@@ -0,0 +1,209 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+#![cfg(feature = "platform-recording")]
+
+use suzy::{
+    app::App,
+    dims::{Padding, Padding2d, Rect},
+    graphics::{Color, CornerRadii, CornerStyle, ShapeOutline},
+    platform::graphics::{Shape as _, SlicedImage as _},
+    platforms::{
+        opengl::Mat4,
+        recording::{
+            Mask, MaskEntry, RecordedGraphic, Recorder,
+            RecordingRenderPlatform, Shape, SlicedImage, Transform,
+        },
+    },
+    widget::{self, Widget},
+};
+
+fn record<T>() -> Recorder
+where
+    T: widget::Content<RecordingRenderPlatform> + Default,
+{
+    let mut recorder = Recorder::new();
+    let mut app = App::<RecordingRenderPlatform>::new(480.0, 360.0);
+    app.add_root(Widget::<T>::default());
+    recorder.record(&mut app);
+    recorder
+}
+
+#[test]
+fn smoke() {
+    assert!(record::<()>().commands().is_empty());
+}
+
+#[derive(Default)]
+struct Tinted {
+    image: SlicedImage,
+}
+
+impl widget::Content<RecordingRenderPlatform> for Tinted {
+    fn desc(mut desc: impl widget::Desc<Self, RecordingRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.image.set_fill(rect, &Padding2d::uniform(40.0));
+            root.image.set_color(Color::RED);
+            root.image.set_slice_padding(Padding2d::uniform(12.0));
+            root.image.set_corners(CornerStyle::Rounded);
+        });
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn image_state_is_recorded() {
+    let recorder = record::<Tinted>();
+    let [command] = recorder.commands() else {
+        panic!("expected one command: {:?}", recorder.commands());
+    };
+    let RecordedGraphic::Image(image) = &command.graphic else {
+        panic!("expected an image: {:?}", command.graphic);
+    };
+    assert_eq!(image.rect.left(), 40.0);
+    assert_eq!(image.rect.right(), 440.0);
+    assert_eq!(image.rect.bottom(), 40.0);
+    assert_eq!(image.rect.top(), 320.0);
+    assert_eq!(image.color, Color::RED);
+    assert_eq!(image.slice_padding.left(), 12.0);
+    assert_eq!(image.corners, CornerStyle::Rounded);
+    assert_eq!(image.texture_key, None);
+    assert!(command.masks.is_empty());
+    assert!(command.transforms.is_empty());
+}
+
+#[derive(Default)]
+struct Avatar {
+    shape: Shape,
+}
+
+impl widget::Content<RecordingRenderPlatform> for Avatar {
+    fn desc(mut desc: impl widget::Desc<Self, RecordingRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.shape.set_fill(rect, &Padding2d::uniform(40.0));
+            root.shape.set_outline(ShapeOutline::Rect(CornerRadii::new(
+                1.0, 2.0, 3.0, 4.0,
+            )));
+            root.shape.set_fill_color(Color::BLUE);
+            root.shape.set_stroke(Color::RED, 2.5);
+        });
+        desc.graphic(|this| &mut this.shape);
+    }
+}
+
+#[test]
+fn shape_state_is_recorded() {
+    let recorder = record::<Avatar>();
+    let [command] = recorder.commands() else {
+        panic!("expected one command: {:?}", recorder.commands());
+    };
+    let RecordedGraphic::Shape(shape) = &command.graphic else {
+        panic!("expected a shape: {:?}", command.graphic);
+    };
+    assert_eq!(shape.rect.left(), 40.0);
+    assert_eq!(shape.rect.top(), 320.0);
+    assert_eq!(
+        shape.outline,
+        ShapeOutline::Rect(CornerRadii {
+            top_left: 1.0,
+            top_right: 2.0,
+            bottom_right: 3.0,
+            bottom_left: 4.0,
+        }),
+    );
+    assert_eq!(shape.fill_color, Color::BLUE);
+    assert_eq!(shape.stroke_color, Color::RED);
+    assert_eq!(shape.stroke_width, 2.5);
+}
+
+#[derive(Default)]
+struct Stacked {
+    transform: Transform,
+    mask: Mask<SlicedImage>,
+    image: SlicedImage,
+}
+
+impl widget::Content<RecordingRenderPlatform> for Stacked {
+    fn desc(mut desc: impl widget::Desc<Self, RecordingRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.transform.matrix = Mat4::translate(10.0, 20.0);
+            root.mask.graphic.set_fill(rect, &Padding2d::zero());
+            root.image.set_fill_width(rect, Padding::zero());
+        });
+        desc.graphic(|this| &mut this.transform);
+        desc.graphic(|this| &mut this.mask);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn mask_and_transform_stacks_are_recorded() {
+    let recorder = record::<Stacked>();
+    let [shape, image] = recorder.commands() else {
+        panic!("expected two commands: {:?}", recorder.commands());
+    };
+    let translate = Mat4::translate(10.0, 20.0);
+    assert_eq!(
+        shape.masks,
+        [MaskEntry {
+            id: 0,
+            is_shape: true
+        }],
+    );
+    assert!(shape.is_mask_shape());
+    assert_eq!(shape.transforms, [translate]);
+    assert_eq!(
+        image.masks,
+        [MaskEntry {
+            id: 0,
+            is_shape: false
+        }],
+    );
+    assert!(!image.is_mask_shape());
+    assert_eq!(image.matrix(), translate);
+}
+
+#[cfg(feature = "default-font")]
+mod text {
+    use suzy::{
+        text::Alignment,
+        widgets::{Button, DefaultButtonContent, TextContent},
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Root {
+        button: Button<DefaultButtonContent<RecordingRenderPlatform>>,
+    }
+
+    impl widget::Content<RecordingRenderPlatform> for Root {
+        fn desc(mut desc: impl widget::Desc<Self, RecordingRenderPlatform>) {
+            desc.watch(|root, rect| {
+                root.button.set_width(200.0);
+                root.button.set_height(60.0);
+                root.button.set_center_x(rect.center_x() + 50.0);
+                root.button.set_center_y(rect.center_y());
+            });
+            desc.watch(|root, _rect| {
+                root.button.content_mut().set_text("OK");
+            });
+            desc.child(|this| &mut this.button);
+        }
+    }
+
+    #[test]
+    fn button_label_is_centered() {
+        let recorder = record::<Root>();
+        let label = recorder.find_text("OK").expect("label should be drawn");
+        assert_eq!(label.layout.alignment, Alignment::Center);
+        assert_eq!(label.layout.origin_x, 290.0);
+        assert_eq!(label.layout.origin_y, 180.0);
+        assert_eq!(label.spans.len(), 1);
+        assert_eq!(label.spans[0].range, 0..2);
+        assert!(!label.is_truncated);
+        let bounds = label.bounds.expect("label should have bounds");
+        assert!((bounds.center_x() - 290.0).abs() < 1.0, "{:?}", bounds);
+        assert!((bounds.center_y() - 180.0).abs() < 4.0, "{:?}", bounds);
+    }
+}
@@ -0,0 +1,443 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+#![cfg(feature = "platform-software")]
+
+use std::rc::Rc;
+
+use suzy::{
+    dims::{Padding, Padding2d, Rect},
+    graphics::{Color, CornerRadii, CornerStyle, ShapeOutline},
+    platform::graphics::{Shape as _, SlicedImage as _},
+    platforms::{
+        opengl::{
+            opengl_bindings::types::GLenum, Mat4, OpenGlBindings,
+            PopulateTexture, PopulateTextureUtil, Texture, TexturePixels,
+            TextureSize,
+        },
+        software::{
+            Mask, Shape, SlicedImage, SoftwareRenderPlatform, Transform,
+        },
+    },
+    widget,
+};
+
+mod utils;
+use utils::*;
+
+#[test]
+fn smoke() {
+    let capture = render_software::<()>();
+    assert!(capture.chunks_exact(4).all(|pixel| pixel == BLACK));
+}
+
+#[derive(Default)]
+struct Masked {
+    mask: Mask<SlicedImage>,
+    image: SlicedImage,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Masked {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.mask.graphic.set_fill_width(rect, Padding::zero());
+            root.mask.graphic.set_height(rect.height() / 2.0);
+            root.mask.graphic.set_top(rect.top());
+        });
+        desc.watch(|root, rect| {
+            root.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.mask);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn mask_top_half() {
+    let capture = render_software::<Masked>();
+    for y in 0..HEIGHT {
+        let expected = if y < HEIGHT / 2 { BLACK } else { WHITE };
+        for x in 0..WIDTH {
+            assert_eq!(pixel(&capture, x, y), expected, "at {x}, {y}");
+        }
+    }
+}
+
+#[derive(Default)]
+struct Rounded {
+    image: SlicedImage,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Rounded {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.image.set_fill(rect, &Padding2d::uniform(40.0));
+            root.image.set_slice_padding(Padding2d::uniform(20.0));
+            root.image.set_corners(CornerStyle::Rounded);
+        });
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn rounded_corners() {
+    let capture = render_software::<Rounded>();
+    // outside the image
+    assert_eq!(pixel(&capture, 20, 180), BLACK);
+    // the corner of the rect is cut off, but not the middle of the edges
+    assert_eq!(pixel(&capture, 41, 41), BLACK);
+    assert_eq!(pixel(&capture, 240, 41), WHITE);
+    assert_eq!(pixel(&capture, 41, 180), WHITE);
+    assert_eq!(pixel(&capture, 240, 180), WHITE);
+    // the curve of the corner is smoothed
+    let [edge, ..] = pixel(&capture, 46, 46);
+    assert!(edge > 0 && edge < 255, "{}", edge);
+}
+
+#[derive(Default)]
+struct Moved {
+    transform: Transform,
+    image: SlicedImage,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Moved {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.transform.matrix = Mat4::translate(rect.width() / 2.0, 0.0);
+            root.image.set_fill_height(rect, Padding::zero());
+            root.image.set_width(rect.width() / 2.0);
+            root.image.set_left(rect.left());
+        });
+        desc.graphic(|this| &mut this.transform);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn transform_moves_children() {
+    let capture = render_software::<Moved>();
+    for x in 0..WIDTH {
+        let expected = if x < WIDTH / 2 { BLACK } else { WHITE };
+        for y in 0..HEIGHT {
+            assert_eq!(pixel(&capture, x, y), expected, "at {x}, {y}");
+        }
+    }
+}
+
+#[derive(Default)]
+struct NestedMasks {
+    top: Mask<SlicedImage>,
+    right: Mask<SlicedImage>,
+    middle: Mask<SlicedImage>,
+    image: SlicedImage,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for NestedMasks {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.top.graphic.set_fill_width(rect, Padding::zero());
+            root.top.graphic.set_height(rect.height() / 2.0);
+            root.top.graphic.set_top(rect.top());
+            root.right.graphic.set_fill_height(rect, Padding::zero());
+            root.right.graphic.set_width(rect.width() / 2.0);
+            root.right.graphic.set_right(rect.right());
+            root.middle.graphic.set_fill(
+                rect,
+                &Padding2d::new(
+                    rect.height() / 4.0,
+                    rect.width() / 4.0,
+                    rect.height() / 4.0,
+                    rect.width() / 4.0,
+                ),
+            );
+            root.image.set_fill(rect, &Padding2d::zero());
+        });
+        desc.graphic(|this| &mut this.top);
+        desc.graphic(|this| &mut this.right);
+        desc.graphic(|this| &mut this.middle);
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn nested_masks_intersect() {
+    let capture = render_software::<NestedMasks>();
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let inside = (WIDTH / 2..WIDTH * 3 / 4).contains(&x)
+                && (HEIGHT / 2..HEIGHT * 3 / 4).contains(&y);
+            let expected = if inside { WHITE } else { BLACK };
+            assert_eq!(pixel(&capture, x, y), expected, "at {x}, {y}");
+        }
+    }
+}
+
+/// A 24x24 texture, red with a green square in the middle.
+struct Framed;
+
+impl Framed {
+    fn rgba() -> Vec<u8> {
+        let mut rgba = Vec::new();
+        for y in 0..24 {
+            for x in 0..24 {
+                let inside = (8..16).contains(&x) && (8..16).contains(&y);
+                let color = if inside { [0, 255, 0] } else { [255, 0, 0] };
+                rgba.extend_from_slice(&color);
+                rgba.push(255);
+            }
+        }
+        rgba
+    }
+}
+
+impl PopulateTexture for Framed {
+    fn populate(
+        &self,
+        _gl: &OpenGlBindings,
+        _target: GLenum,
+    ) -> Result<TextureSize, String> {
+        Err("only populated in memory".to_string())
+    }
+
+    fn populate_pixels(&self) -> Result<TexturePixels, String> {
+        Ok(PopulateTextureUtil::pixels_color_rgba(
+            24,
+            24,
+            1,
+            &Self::rgba(),
+        ))
+    }
+
+    fn texture_key(&self) -> &[u8] {
+        b"tests/software.rs:Framed"
+    }
+}
+
+#[derive(Default)]
+struct Sliced {
+    image: SlicedImage,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Sliced {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.image.texture = Texture::new(Rc::new(Framed));
+            root.image.set_fill(rect, &Padding2d::zero());
+            root.image.set_slice_padding(Padding2d::uniform(8.0));
+        });
+        desc.graphic(|this| &mut this.image);
+    }
+}
+
+#[test]
+fn sliced_texture_keeps_borders() {
+    let capture = render_software::<Sliced>();
+    let red = [255, 0, 0, 255];
+    let green = [0, 255, 0, 255];
+    for (x, y) in [(2, 2), (2, 180), (240, 357), (477, 100)] {
+        assert_eq!(pixel(&capture, x, y), red, "at {x}, {y}");
+    }
+    for (x, y) in [(100, 100), (240, 180), (380, 260)] {
+        assert_eq!(pixel(&capture, x, y), green, "at {x}, {y}");
+    }
+}
+
+#[derive(Default)]
+struct Circle {
+    shape: Shape,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Circle {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.shape.set_width(200.0);
+            root.shape.set_height(200.0);
+            root.shape.set_center(rect.center());
+            root.shape.set_outline(ShapeOutline::Ellipse);
+            root.shape.set_fill_color(Color::RED);
+        });
+        desc.graphic(|this| &mut this.shape);
+    }
+}
+
+#[test]
+fn circle() {
+    let capture = render_software::<Circle>();
+    let red = [255, 0, 0, 255];
+    for (x, y) in [(240, 180), (240, 278), (141, 180), (300, 240)] {
+        assert_eq!(pixel(&capture, x, y), red, "at {x}, {y}");
+    }
+    // the corners of the rect are outside the circle
+    for (x, y) in [(141, 81), (338, 81), (141, 278), (338, 278)] {
+        assert_eq!(pixel(&capture, x, y), BLACK, "at {x}, {y}");
+    }
+    // the edge is smoothed
+    let [edge, ..] = pixel(&capture, 310, 250);
+    assert_ne!(edge, 0);
+    assert_ne!(edge, 255);
+}
+
+#[derive(Default)]
+struct Ring {
+    shape: Shape,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Ring {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.shape.set_width(200.0);
+            root.shape.set_height(200.0);
+            root.shape.set_center(rect.center());
+            root.shape.set_outline(ShapeOutline::Ellipse);
+            root.shape
+                .set_fill_color(Color::from_rgba(0.0, 0.0, 0.0, 0.0));
+            root.shape.set_stroke(Color::WHITE, 10.0);
+        });
+        desc.graphic(|this| &mut this.shape);
+    }
+}
+
+#[test]
+fn stroke_only_circle() {
+    let capture = render_software::<Ring>();
+    for (x, y) in [(240, 275), (144, 180), (307, 247)] {
+        assert_eq!(pixel(&capture, x, y), WHITE, "at {x}, {y}");
+    }
+    for (x, y) in [(240, 180), (240, 265), (141, 81), (298, 238)] {
+        assert_eq!(pixel(&capture, x, y), BLACK, "at {x}, {y}");
+    }
+}
+
+#[derive(Default)]
+struct Corners {
+    shape: Shape,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Corners {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.shape.set_fill(rect, &Padding2d::uniform(80.0));
+            root.shape.set_outline(ShapeOutline::Rect(CornerRadii::new(
+                60.0, 0.0, 20.0, 0.0,
+            )));
+        });
+        desc.graphic(|this| &mut this.shape);
+    }
+}
+
+#[test]
+fn independent_corner_radii() {
+    let capture = render_software::<Corners>();
+    // only the top left and bottom right corners are rounded
+    for (x, y) in [(81, 278), (90, 260), (398, 81)] {
+        assert_eq!(pixel(&capture, x, y), BLACK, "at {x}, {y}");
+    }
+    for (x, y) in [(398, 278), (81, 81), (110, 250), (392, 88), (240, 180)] {
+        assert_eq!(pixel(&capture, x, y), WHITE, "at {x}, {y}");
+    }
+}
+
+#[derive(Default)]
+struct Bordered {
+    shape: Shape,
+}
+
+impl widget::Content<SoftwareRenderPlatform> for Bordered {
+    fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.shape
+                .set_fill(rect, &Padding2d::new(120.0, 40.0, 120.0, 40.0));
+            root.shape
+                .set_outline(ShapeOutline::Rect(CornerRadii::uniform(
+                    f32::INFINITY,
+                )));
+            root.shape.set_fill_color(Color::RED);
+            root.shape.set_stroke(Color::WHITE, 8.0);
+        });
+        desc.graphic(|this| &mut this.shape);
+    }
+}
+
+#[test]
+fn pill_with_fill_and_stroke() {
+    let capture = render_software::<Bordered>();
+    let red = [255, 0, 0, 255];
+    // the ends are semicircles
+    for (x, y) in [(41, 121), (41, 238), (438, 121), (438, 238)] {
+        assert_eq!(pixel(&capture, x, y), BLACK, "at {x}, {y}");
+    }
+    for (x, y) in [(41, 180), (240, 121), (240, 238), (438, 180)] {
+        assert_eq!(pixel(&capture, x, y), WHITE, "at {x}, {y}");
+    }
+    for (x, y) in [(52, 180), (240, 132), (240, 180)] {
+        assert_eq!(pixel(&capture, x, y), red, "at {x}, {y}");
+    }
+}
+
+#[cfg(feature = "default-font")]
+mod text {
+    use suzy::{
+        platform::graphics::{Text as _, TextStyle as _},
+        platforms::software::{Text, TextStyle},
+        text::Layout,
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Hello {
+        text: Text,
+    }
+
+    impl widget::Content<SoftwareRenderPlatform> for Hello {
+        fn desc(mut desc: impl widget::Desc<Self, SoftwareRenderPlatform>) {
+            desc.watch(|root, _rect| {
+                root.text.set_layout(Layout {
+                    origin_x: 100.0,
+                    origin_y: 200.0,
+                    ..Layout::default()
+                });
+                root.text.clear();
+                root.text.push_span(
+                    TextStyle::with_size_and_color(48.0, Color::WHITE),
+                    "Hello",
+                );
+                root.text.finish();
+            });
+            desc.graphic(|this| &mut this.text);
+        }
+    }
+
+    #[test]
+    fn text_is_drawn_inside_its_bounds() {
+        let capture = render_software::<Hello>();
+        let mut text = Text::default();
+        text.set_layout(Layout {
+            origin_x: 100.0,
+            origin_y: 200.0,
+            ..Layout::default()
+        });
+        text.push_span(
+            TextStyle::with_size_and_color(48.0, Color::WHITE),
+            "Hello",
+        );
+        text.finish();
+        let bounds = text.bounds().expect("text should have bounds");
+        let (mut solid, mut smoothed) = (0, 0);
+        for y in 0..HEIGHT {
+            for x in 0..WIDTH {
+                let [value, ..] = pixel(&capture, x, y);
+                let center = [f32::from(x) + 0.5, f32::from(y) + 0.5];
+                if !bounds.contains(center) {
+                    assert_eq!(value, 0, "at {x}, {y}");
+                } else if value == 255 {
+                    solid += 1;
+                } else if value > 0 {
+                    smoothed += 1;
+                }
+            }
+        }
+        assert!(solid > 500, "{}", solid);
+        assert!(smoothed > 100, "{}", smoothed);
+    }
+}
@@ -0,0 +1,166 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+#![cfg(all(
+    feature = "default-font",
+    any(feature = "platform-osmesa", feature = "platform-sdl")
+))]
+
+use suzy::{
+    dims::Rect,
+    graphics::Color,
+    platform::graphics::{Text as _, TextStyle as _},
+    platforms::{
+        opengl::{FontWeight, Text, TextStyle},
+        TEST_ENV,
+    },
+    text::{Affinity, Layout, OverflowMode, VerticalLimit},
+};
+
+fn layout_text(content: &str) -> Text {
+    let mut text = Text::default();
+    text.set_layout(Layout {
+        origin_x: 10.0,
+        origin_y: 200.0,
+        ..Layout::default()
+    });
+    text.clear();
+    text.push_span(
+        TextStyle::with_size_and_color(24.0, Color::WHITE),
+        content,
+    );
+    text.finish();
+    text
+}
+
+#[test]
+fn line_metrics_and_bounds() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let text = layout_text("hello\nworld");
+    let lines = text.line_metrics();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].range, 0..6);
+    assert_eq!(lines[1].range, 6..11);
+    assert!(lines[0].baseline > lines[1].baseline);
+    assert!(lines[0].ascent > 0.0 && lines[0].descent < 0.0);
+    let bounds = text.bounds().unwrap();
+    assert_eq!(bounds.left(), 10.0);
+    assert_eq!(bounds.top(), lines[0].top());
+    assert_eq!(bounds.bottom(), lines[1].bottom());
+}
+
+#[test]
+fn hit_test_round_trips_carets() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let text = layout_text("hello\nworld");
+    for index in [0, 1, 4, 6, 8, 10] {
+        let caret = text.caret_rect(index).unwrap();
+        let next = text.caret_rect(index + 1).unwrap();
+        let leading = [caret.left() + 0.1, caret.center_y()];
+        let hit = text.hit_test(leading).unwrap();
+        assert_eq!(hit.index, index);
+        assert_eq!(hit.affinity, Affinity::Leading);
+        assert_eq!(hit.caret, index);
+        assert!(hit.inside);
+        let trailing = [next.left() - 0.1, caret.center_y()];
+        let hit = text.hit_test(trailing).unwrap();
+        assert_eq!(hit.index, index);
+        assert_eq!(hit.affinity, Affinity::Trailing);
+        assert_eq!(hit.caret, index + 1);
+    }
+    // past the end of the first line, the caret goes before the line break
+    let caret = text.caret_rect(2).unwrap();
+    let hit = text.hit_test([400.0, caret.center_y()]).unwrap();
+    assert_eq!((hit.index, hit.caret, hit.inside), (5, 5, false));
+}
+
+#[test]
+fn selection_rects_cover_each_line() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let text = layout_text("hello\nworld");
+    let rects = text.selection_rects(2..8);
+    assert_eq!(rects.len(), 2);
+    assert_eq!(rects[0].left(), text.caret_rect(2).unwrap().left());
+    assert_eq!(rects[1].left(), 10.0);
+    assert_eq!(rects[1].right(), text.caret_rect(8).unwrap().left());
+    assert!(text.selection_rects(3..3).is_empty());
+}
+
+#[test]
+fn measure_matches_layout_bounds() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let text = layout_text("hello\nworld");
+    let bounds = text.bounds().unwrap();
+    let size = text.measure(
+        Layout::default(),
+        [(
+            TextStyle::with_size_and_color(24.0, Color::WHITE),
+            "hello\nworld",
+        )],
+    );
+    assert_eq!(size, [bounds.width(), bounds.height()]);
+    // measuring doesn't disturb the existing layout
+    assert_eq!(text.bounds().unwrap().left(), 10.0);
+    let empty = text.measure(Layout::default(), []);
+    assert_eq!(empty[0], 0.0);
+}
+
+#[test]
+fn bold_weight_selects_the_bold_face() {
+    let window = unsafe { TEST_ENV.initialize(480, 360) };
+    let text = Text::default();
+    let regular = TextStyle::with_size_and_color(24.0, Color::WHITE);
+    let mut bold = regular.clone();
+    bold.weight = FontWeight::BOLD;
+    let [regular_width, _] =
+        text.measure(Layout::default(), [(regular, "Hi")]);
+    let [bold_width, _] = text.measure(Layout::default(), [(bold, "Hi")]);
+    assert!(bold_width > regular_width);
+    let fonts = window.fonts();
+    assert!(fonts
+        .find_font("Missing", FontWeight::REGULAR, false)
+        .is_none());
+}
+
+#[test]
+fn missing_glyphs_are_replaced() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let text = Text::default();
+    let style = TextStyle::with_size_and_color(24.0, Color::WHITE)
+        .with_fallback("Unregistered");
+    let [plain, _] = text.measure(Layout::default(), [(style.clone(), "ab")]);
+    let [replaced, _] =
+        text.measure(Layout::default(), [(style, "a\u{4e2d}b")]);
+    assert!(replaced > plain);
+}
+
+#[test]
+fn vertical_limit_truncates_with_ellipsis() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let content = "a long description which doesn't fit";
+    let layout = Layout {
+        wrap_width: 150.0,
+        vertical_limit: VerticalLimit::Lines(2),
+        overflow_mode: OverflowMode::Ellipsis,
+        ..Layout::default()
+    };
+    let mut text = Text::default();
+    text.set_layout(layout);
+    text.clear();
+    text.push_span(
+        TextStyle::with_size_and_color(24.0, Color::WHITE),
+        content,
+    );
+    text.finish();
+    assert!(text.is_truncated());
+    let lines = text.line_metrics();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].right - lines[1].left <= 150.0);
+    let bounds = text.bounds().unwrap();
+    let size = text.measure(
+        layout,
+        [(TextStyle::with_size_and_color(24.0, Color::WHITE), content)],
+    );
+    assert_eq!(size, [bounds.width(), bounds.height()]);
+    assert!(!layout_text("fits").is_truncated());
+}
@@ -0,0 +1,75 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+#![cfg(all(
+    feature = "default-font",
+    any(feature = "platform-osmesa", feature = "platform-sdl")
+))]
+
+use std::{cell::Cell, rc::Rc};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::{Padding2d, Rect},
+    keyboard::{Key, TextInputData},
+    platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+    widget::{self, Widget},
+    widgets::TextArea,
+};
+
+#[derive(Default)]
+struct Root {
+    area: TextArea<OpenGlRenderPlatform>,
+    caret: Rc<Cell<usize>>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.area.set_fill(rect, &Padding2d::uniform(20.0));
+        });
+        desc.watch(|root, _rect| {
+            root.caret.set(root.area.caret());
+        });
+        desc.child(|root| &mut root.area);
+    }
+}
+
+#[test]
+fn text_area_line_movement() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let root = Widget::<Root>::default();
+    let caret = Rc::clone(&root.caret);
+    app.add_root(root);
+    app.update_watches();
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+
+    app.text_input(TextInputData::Input("abc".to_string()));
+    app.key_press(Key::Enter);
+    app.text_input(TextInputData::Input("de".to_string()));
+    app.key_press(Key::Enter);
+    app.text_input(TextInputData::Input("fghij".to_string()));
+    app.update_watches();
+    assert_eq!(caret.get(), 12);
+
+    // moving between lines keeps the original horizontal position
+    app.key_press(Key::Up);
+    app.update_watches();
+    assert_eq!(caret.get(), 6);
+    app.key_press(Key::Up);
+    app.update_watches();
+    assert_eq!(caret.get(), 3);
+    app.key_press(Key::Down);
+    app.key_press(Key::Down);
+    app.update_watches();
+    assert_eq!(caret.get(), 12);
+
+    app.key_press(Key::Home);
+    app.update_watches();
+    assert_eq!(caret.get(), 7);
+    app.key_press(Key::PageUp);
+    app.update_watches();
+    assert_eq!(caret.get(), 0);
+}
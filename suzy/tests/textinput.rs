@@ -0,0 +1,101 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+#![cfg(all(
+    feature = "default-font",
+    any(feature = "platform-osmesa", feature = "platform-sdl")
+))]
+
+use std::{cell::RefCell, rc::Rc};
+
+use suzy::{
+    app::{App, TestingExt},
+    dims::{Padding2d, Rect},
+    keyboard::{
+        Key, KeyAction, KeyEventData, Modifiers, ScanCode, TextInputData,
+    },
+    platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+    widget::{self, Widget},
+    widgets::TextInput,
+};
+
+#[derive(Default)]
+struct Root {
+    input: TextInput<OpenGlRenderPlatform>,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl widget::Content<OpenGlRenderPlatform> for Root {
+    fn desc(mut desc: impl widget::Desc<Self, OpenGlRenderPlatform>) {
+        desc.watch(|root, rect| {
+            root.input.set_fill(rect, &Padding2d::uniform(100.0));
+        });
+        desc.watch(|root, _rect| {
+            let Self { input, log } = root;
+            input.on_change(|| log.borrow_mut().push((*input.text).clone()));
+        });
+        desc.watch(|root, _rect| {
+            let Self { input, log } = root;
+            input.on_submit(|| log.borrow_mut().push("submit".to_string()));
+        });
+        desc.child(|root| &mut root.input);
+    }
+}
+
+fn key_with(app: &mut App<OpenGlRenderPlatform>, key: Key, mods: Modifiers) {
+    for action in [KeyAction::Down, KeyAction::Up] {
+        app.key_event(KeyEventData {
+            key,
+            scancode: ScanCode(0),
+            modifiers: mods,
+            action,
+            repeat: false,
+        });
+    }
+}
+
+#[test]
+fn text_input_editing() {
+    let _window = unsafe { TEST_ENV.initialize(480, 360) };
+    let mut app = App::<OpenGlRenderPlatform>::new(480.0, 360.0);
+    let root = Widget::<Root>::default();
+    let log = Rc::clone(&root.log);
+    app.add_root(root);
+    app.update_watches();
+
+    // text is ignored until the input is focused
+    assert!(!app.text_input(TextInputData::Input("x".to_string())));
+    app.mouse_click([240.0, 180.0]);
+    app.update_watches();
+    assert!(app.text_input_area().is_some());
+
+    app.text_input(TextInputData::Input("hello".to_string()));
+    app.update_watches();
+    app.text_input(TextInputData::Input(" world".to_string()));
+    app.update_watches();
+    key_with(&mut app, Key::Backspace, Modifiers::NONE);
+    app.update_watches();
+    let ctrl = Modifiers {
+        ctrl: true,
+        ..Modifiers::NONE
+    };
+    key_with(&mut app, Key::Left, ctrl);
+    key_with(&mut app, Key::Backspace, Modifiers::NONE);
+    app.update_watches();
+    key_with(&mut app, Key::Character('a'), ctrl);
+    app.text_input(TextInputData::Input("bye".to_string()));
+    app.update_watches();
+    app.key_press(Key::Enter);
+    app.update_watches();
+    assert_eq!(
+        *log.borrow(),
+        [
+            "hello",
+            "hello world",
+            "hello worl",
+            "helloworl",
+            "bye",
+            "submit"
+        ],
+    );
+}
@@ -39,3 +39,81 @@ pub fn is_color(buffer: &[u8], color: Color) -> bool {
         buf_color == color
     })
 }
+
+/// The width of the window or canvas the tests draw to.
+#[allow(unused)]
+pub const WIDTH: u16 = 480;
+
+/// The height of the window or canvas the tests draw to.
+#[allow(unused)]
+pub const HEIGHT: u16 = 360;
+
+#[allow(unused)]
+pub const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+#[allow(unused)]
+pub const WHITE: [u8; 4] = [255; 4];
+
+/// Get the pixel at `x` pixels from the left and `y` from the bottom of a
+/// capture `WIDTH` pixels wide.
+#[must_use]
+#[allow(unused)]
+pub fn pixel(capture: &[u8], x: u16, y: u16) -> [u8; 4] {
+    let start = (usize::from(y) * usize::from(WIDTH) + usize::from(x)) * 4;
+    [
+        capture[start],
+        capture[start + 1],
+        capture[start + 2],
+        capture[start + 3],
+    ]
+}
+
+/// Draw a single frame with a root widget of type `T` in the test window,
+/// and capture it.
+#[cfg(any(feature = "platform-osmesa", feature = "platform-sdl"))]
+#[must_use]
+#[allow(unused)]
+pub fn render_opengl<T>() -> Box<[u8]>
+where
+    T: suzy::widget::Content<suzy::platforms::opengl::OpenGlRenderPlatform>
+        + Default,
+{
+    use suzy::{
+        app::App,
+        platforms::{opengl::OpenGlRenderPlatform, TEST_ENV},
+        widget::Widget,
+    };
+
+    let mut window = unsafe { TEST_ENV.initialize(WIDTH, HEIGHT) };
+    let mut app =
+        App::<OpenGlRenderPlatform>::new(f32::from(WIDTH), f32::from(HEIGHT));
+    app.add_root(Widget::<T>::default());
+    window.draw_and_take_screenshot(&mut app)
+}
+
+/// Draw a single frame with a root widget of type `T` on a black canvas,
+/// and capture it.
+#[cfg(feature = "platform-software")]
+#[must_use]
+#[allow(unused)]
+pub fn render_software<T>() -> Box<[u8]>
+where
+    T: suzy::widget::Content<
+            suzy::platforms::software::SoftwareRenderPlatform,
+        > + Default,
+{
+    use suzy::{
+        app::App,
+        platforms::software::{Canvas, SoftwareRenderPlatform},
+        widget::Widget,
+    };
+
+    let mut canvas = Canvas::new(WIDTH, HEIGHT);
+    canvas.clear_color(Color::BLACK);
+    let mut app = App::<SoftwareRenderPlatform>::new(
+        f32::from(WIDTH),
+        f32::from(HEIGHT),
+    );
+    app.add_root(Widget::<T>::default());
+    canvas.draw_and_take_screenshot(&mut app)
+}
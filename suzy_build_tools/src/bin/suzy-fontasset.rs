@@ -0,0 +1,356 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Build a signed distance field font atlas from the command line.
+
+use std::{collections::BTreeSet, path::PathBuf, process::ExitCode};
+
+use suzy_build_tools::fontasset::{self, AtlasKind, FontAtlas, TextureDim};
+
+const USAGE: &str = "\
+Usage: suzy-fontasset [OPTIONS] --font NAME=PATH... (--module PATH | --asset PATH)
+
+Build a signed distance field font atlas for Suzy.
+
+Options:
+  --font NAME=PATH    Add the font file at PATH to the atlas as a face
+                      called NAME.  May be repeated.
+  --chars SET         Add characters to build for every font.  SET is a
+                      preset (ascii, latin1, latin1-french, hexdigits), a
+                      range like U+0400-U+04FF or a-z, or a single
+                      character.  May be repeated.  Defaults to latin1.
+  --chars-from FILE   Add all the characters found in a UTF-8 text file.
+                      May be repeated.
+  --skip-missing      Leave out characters a font doesn't have, instead of
+                      failing.
+  --width DIM         The width of the texture, a power of two from 16 to
+                      8192.  Defaults to 1024.
+  --height DIM        The height of the texture.  Defaults to 512.
+  --padding RATIO     The padding around each glyph, relative to the font
+                      size.  Defaults to 0.2.
+  --msdf              Build a multi-channel signed distance field, which
+                      keeps corners sharp when drawn large.
+  --module PATH       Write a Rust module, with the texture data beside it
+                      in PATH.texture.
+  --asset PATH        Write a binary font asset, which can be loaded at
+                      runtime.
+  -h, --help          Print this message.
+";
+
+#[derive(Debug)]
+enum Output {
+    Module(PathBuf),
+    Asset(PathBuf),
+}
+
+#[derive(Debug)]
+struct Options {
+    fonts: Vec<(String, PathBuf)>,
+    chars: BTreeSet<char>,
+    skip_missing: bool,
+    width: TextureDim,
+    height: TextureDim,
+    padding_ratio: f32,
+    atlas_kind: AtlasKind,
+    output: Output,
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1);
+    let options = match parse_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(msg) => {
+            eprintln!("error: {msg}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match build(options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("error: {msg}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parse the command line, returning `None` if help was requested.
+fn parse_args(
+    args: impl IntoIterator<Item = String>,
+) -> Result<Option<Options>, String> {
+    let mut fonts = Vec::new();
+    let mut chars = BTreeSet::new();
+    let mut any_chars = false;
+    let mut skip_missing = false;
+    let mut width = TextureDim::V1024;
+    let mut height = TextureDim::V512;
+    let mut padding_ratio = 0.2;
+    let mut atlas_kind = AtlasKind::Sdf;
+    let mut output = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => {
+                (name.to_string(), Some(value.to_string()))
+            }
+            _ => (arg, None),
+        };
+        let mut value = || {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{name} requires a value"))
+        };
+        match name.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--skip-missing" => skip_missing = true,
+            "--msdf" => atlas_kind = AtlasKind::Msdf,
+            "--font" => {
+                let value = value()?;
+                let (face, path) = value
+                    .split_once('=')
+                    .filter(|(face, path)| {
+                        !face.is_empty() && !path.is_empty()
+                    })
+                    .ok_or_else(|| {
+                        format!("expected --font NAME=PATH, found {value:?}")
+                    })?;
+                fonts.push((face.to_string(), PathBuf::from(path)));
+            }
+            "--chars" => {
+                chars.extend(parse_char_set(&value()?)?);
+                any_chars = true;
+            }
+            "--chars-from" => {
+                let path = value()?;
+                let text = std::fs::read_to_string(&path)
+                    .map_err(|err| format!("failed to read {path}: {err}"))?;
+                chars.extend(text.chars());
+                any_chars = true;
+            }
+            "--width" => width = parse_texture_dim(&value()?)?,
+            "--height" => height = parse_texture_dim(&value()?)?,
+            "--padding" => {
+                let value = value()?;
+                padding_ratio = value
+                    .parse()
+                    .ok()
+                    .filter(|ratio: &f32| ratio.is_finite() && *ratio >= 0.0)
+                    .ok_or_else(|| {
+                        format!("invalid padding ratio: {value}")
+                    })?;
+            }
+            "--module" | "--asset" => {
+                if output.is_some() {
+                    return Err(
+                        "only one of --module or --asset may be given".into(),
+                    );
+                }
+                let path = PathBuf::from(value()?);
+                output = Some(if name == "--module" {
+                    Output::Module(path)
+                } else {
+                    Output::Asset(path)
+                });
+            }
+            _ => return Err(format!("unexpected argument: {name}")),
+        }
+    }
+    if fonts.is_empty() {
+        return Err("at least one --font is required".into());
+    }
+    let output =
+        output.ok_or("one of --module or --asset is required".to_string())?;
+    if let Output::Module(_) = output {
+        if let Some((face, _)) =
+            fonts.iter().find(|(face, _)| !is_identifier(face))
+        {
+            return Err(format!(
+                "face names must be Rust identifiers to write a module, \
+                 found {face:?}"
+            ));
+        }
+    }
+    if !any_chars {
+        chars.extend(fontasset::latin1());
+    }
+    // characters which are never drawn don't need glyphs
+    chars.retain(|ch| !ch.is_whitespace() && !ch.is_control());
+    Ok(Some(Options {
+        fonts,
+        chars,
+        skip_missing,
+        width,
+        height,
+        padding_ratio,
+        atlas_kind,
+        output,
+    }))
+}
+
+fn parse_char_set(value: &str) -> Result<Vec<char>, String> {
+    let chars: Vec<char> = match value {
+        "ascii" => fontasset::ascii().collect(),
+        "latin1" => fontasset::latin1().collect(),
+        "latin1-french" => fontasset::latin1_french().collect(),
+        "hexdigits" => fontasset::hexdigits().collect(),
+        _ => {
+            // the separator is after the first character, so that ranges
+            // can start with a hyphen
+            let first_len = value.chars().next().map_or(0, char::len_utf8);
+            let (start, end) = match value[first_len..].find('-') {
+                Some(pos) if value.chars().count() > 1 => {
+                    (&value[..first_len + pos], &value[first_len + pos + 1..])
+                }
+                _ => (value, value),
+            };
+            let invalid = || format!("invalid character set: {value:?}");
+            let start = parse_char(start).ok_or_else(invalid)?;
+            let end = parse_char(end).ok_or_else(invalid)?;
+            if start > end {
+                return Err(invalid());
+            }
+            (start..=end).collect()
+        }
+    };
+    Ok(chars)
+}
+
+/// Parse a character written literally, or as a hexadecimal code point
+/// like `U+00E9` or `0xe9`.
+fn parse_char(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        return Some(ch);
+    }
+    let hex = ["U+", "u+", "0x", "0X"]
+        .iter()
+        .find_map(|prefix| value.strip_prefix(prefix))?;
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+fn parse_texture_dim(value: &str) -> Result<TextureDim, String> {
+    Ok(match value {
+        "16" => TextureDim::V16,
+        "32" => TextureDim::V32,
+        "64" => TextureDim::V64,
+        "128" => TextureDim::V128,
+        "256" => TextureDim::V256,
+        "512" => TextureDim::V512,
+        "1024" => TextureDim::V1024,
+        "2048" => TextureDim::V2048,
+        "4096" => TextureDim::V4096,
+        "8192" => TextureDim::V8192,
+        _ => {
+            return Err(format!(
+                "invalid texture dimension {value}, expected a power of two \
+                 from 16 to 8192"
+            ))
+        }
+    })
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|ch| ch == '_' || ch.is_ascii_alphabetic())
+        && chars.all(|ch| ch == '_' || ch.is_ascii_alphanumeric())
+}
+
+fn build(options: Options) -> Result<(), String> {
+    let Options {
+        fonts,
+        chars,
+        skip_missing,
+        width,
+        height,
+        padding_ratio,
+        atlas_kind,
+        output,
+    } = options;
+    let mut atlas = FontAtlas::with_texture_size(width, height)
+        .with_padding_ratio(padding_ratio)
+        .with_atlas_kind(atlas_kind)
+        .into_dynamic();
+    for (face, path) in fonts {
+        let read_error =
+            |err| format!("failed to read {}: {}", path.display(), err);
+        let face_chars: Vec<char> = if skip_missing {
+            let data = std::fs::read(&path).map_err(read_error)?;
+            let font =
+                blurry::ttf_parser::Face::parse(&data, 0).map_err(|err| {
+                    format!("failed to parse {}: {}", path.display(), err)
+                })?;
+            // glyphs without outlines can't be drawn either
+            chars
+                .iter()
+                .copied()
+                .filter(|&ch| {
+                    font.glyph_index(ch)
+                        .and_then(|id| font.glyph_bounding_box(id))
+                        .is_some()
+                })
+                .collect()
+        } else {
+            chars.iter().copied().collect()
+        };
+        atlas
+            .push_font(face, &path, face_chars.into_iter())
+            .map_err(read_error)?;
+    }
+    let result = match &output {
+        Output::Module(path) => atlas.write_module(path),
+        Output::Asset(path) => atlas.write_asset(path),
+    };
+    result.map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Option<Options>, String> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn character_sets() {
+        assert_eq!(parse_char_set("a-e"), Ok(vec!['a', 'b', 'c', 'd', 'e']));
+        assert_eq!(parse_char_set("U+3b1-U+3b3"), Ok(vec!['α', 'β', 'γ']));
+        assert_eq!(parse_char_set("--/"), Ok(vec!['-', '.', '/']));
+        assert_eq!(parse_char_set("-"), Ok(vec!['-']));
+        assert_eq!(parse_char_set("hexdigits").map(|v| v.len()), Ok(24));
+        assert!(parse_char_set("z-a").is_err());
+        assert!(parse_char_set("nonsense").is_err());
+    }
+
+    #[test]
+    fn command_lines() {
+        let options = args(
+            "--font regular=a.ttf --font=bold=b.ttf --chars a-c \
+             --chars U+20 --width 256 --asset out.bin",
+        )
+        .expect("valid arguments")
+        .expect("not a help request");
+        assert_eq!(options.fonts.len(), 2);
+        assert_eq!(options.fonts[1].0, "bold");
+        // the space is left out, since it has no glyph
+        assert_eq!(options.chars.into_iter().collect::<String>(), "abc");
+        assert_eq!(options.width, TextureDim::V256);
+        assert_eq!(options.atlas_kind, AtlasKind::Sdf);
+        assert!(matches!(options.output, Output::Asset(_)));
+        let options = args("--font f=a.ttf --msdf --module m.rs")
+            .expect("valid arguments")
+            .expect("not a help request");
+        assert!(options.chars.contains(&'é'));
+        assert_eq!(options.atlas_kind, AtlasKind::Msdf);
+        assert!(args("--help").expect("valid arguments").is_none());
+        assert!(args("--font f=a.ttf").is_err());
+        assert!(args("--font f=a.ttf --width 100 --asset x").is_err());
+        assert!(args("--font my-font=a.ttf --module m.rs").is_err());
+    }
+}
@@ -2,12 +2,20 @@
 /* Copyright © 2021 Violet Leonard */
 
 use std::{
+    convert::TryFrom,
+    fmt,
     io::{self, Write},
     path::Path,
 };
 
+use blurry::ttf_parser::Face;
+
+use crate::kerning::Kerning;
+
 pub use blurry::{ascii, hexdigits, latin1, latin1_french};
 
+mod msdf;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u16)]
 #[non_exhaustive]
@@ -24,6 +32,19 @@ pub enum TextureDim {
     V8192 = 8192,
 }
 
+/// The kind of distance field to build a font atlas as.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AtlasKind {
+    /// A single-channel signed distance field, which rounds off the
+    /// corners of glyphs drawn much larger than the atlas.
+    #[default]
+    Sdf,
+    /// A multi-channel signed distance field, which keeps corners sharp,
+    /// at three times the texture memory.
+    Msdf,
+}
+
 #[derive(Debug)]
 struct FontData {
     name: String,
@@ -36,6 +57,7 @@ pub struct FontAtlas<I> {
     font_data: Vec<FontData>,
     spec: I,
     padding_ratio: f32,
+    kind: AtlasKind,
 }
 
 impl FontAtlas<std::iter::Empty<FontSpec>> {
@@ -51,6 +73,7 @@ impl FontAtlas<std::iter::Empty<FontSpec>> {
             font_data: Vec::new(),
             spec: std::iter::empty(),
             padding_ratio: initial_padding,
+            kind: AtlasKind::Sdf,
         }
     }
 }
@@ -63,9 +86,17 @@ impl<I> FontAtlas<I> {
             font_data: self.font_data,
             spec: self.spec,
             padding_ratio: padding,
+            kind: self.kind,
         }
     }
 
+    /// Set the kind of distance field to build.  The default is
+    /// [`AtlasKind::Sdf`].
+    #[must_use]
+    pub fn with_atlas_kind(self, kind: AtlasKind) -> Self {
+        Self { kind, ..self }
+    }
+
     pub fn add_font(
         self,
         out_name: String,
@@ -80,6 +111,7 @@ impl<I> FontAtlas<I> {
             mut font_data,
             spec,
             padding_ratio,
+            kind,
         } = self;
         let font_index = font_data.len();
         let data = std::fs::read(font)?;
@@ -92,10 +124,235 @@ impl<I> FontAtlas<I> {
             font_data,
             spec: spec.chain(chars.map(move |ch| FontSpec { font_index, ch })),
             padding_ratio,
+            kind,
         })
     }
 
+    /// Collect the glyphs requested so far, so that more fonts can be added
+    /// in place with [`push_font`](FontAtlas::push_font).  This is useful
+    /// when the fonts to add aren't known until runtime.
+    #[must_use]
+    pub fn into_dynamic(self) -> FontAtlas<std::vec::IntoIter<FontSpec>>
+    where
+        I: Iterator<Item = FontSpec>,
+    {
+        FontAtlas {
+            builder: self.builder,
+            font_data: self.font_data,
+            spec: self.spec.collect::<Vec<_>>().into_iter(),
+            padding_ratio: self.padding_ratio,
+            kind: self.kind,
+        }
+    }
+
     pub fn write_module(self, path: impl AsRef<Path>) -> Result<(), Error>
+    where
+        I: Clone + Iterator<Item = FontSpec>,
+    {
+        let atlas = self.build()?;
+        let mut texture_path = path.as_ref().as_os_str().to_os_string();
+        texture_path.push(".texture");
+        std::fs::write(texture_path, &atlas.data)?;
+        let mut mod_file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(path)?;
+        write!(mod_file, "pub const TEXTURE_WIDTH: u16 = {};", atlas.width)?;
+        write!(
+            mod_file,
+            "pub const TEXTURE_HEIGHT: u16 = {};",
+            atlas.height
+        )?;
+        write!(
+            mod_file,
+            "pub const TEXTURE_CHANNELS: u16 = {};",
+            atlas.channels
+        )?;
+        for face in &atlas.faces {
+            let BuiltFace {
+                name,
+                padding_ratio,
+                line_spacing,
+                ascent,
+                descent,
+                capline,
+                x_height,
+                ..
+            } = face;
+            write!(mod_file, "pub mod {name} {{")?;
+            write!(
+                mod_file,
+                "
+                use super::{{Glyph, KerningPair}};
+                pub const PADDING_RATIO: f32 = {padding_ratio}f32;
+                pub const LINE_SPACING: f32 = {line_spacing}f32;
+                pub const ASCENT: f32 = {ascent}f32;
+                pub const DESCENT: f32 = {descent}f32;
+                ",
+            )?;
+            write!(mod_file, "pub static KERNING: &[KerningPair] = &[")?;
+            for &(left, right, kerning) in &face.kerning {
+                let left = left.escape_unicode();
+                let right = right.escape_unicode();
+                write!(
+                    mod_file,
+                    "
+                    KerningPair {{
+                        left:'{left}',
+                        right:'{right}',
+                        kerning:{kerning}f32,
+                    }},"
+                )?;
+            }
+            write!(mod_file, "];")?;
+            write!(mod_file, "pub static GLYPHS: &[Glyph] = &[")?;
+            for glyph in &face.glyphs {
+                let BuiltGlyph {
+                    ch,
+                    advance,
+                    left,
+                    right,
+                    bottom,
+                    top,
+                    tex_left,
+                    tex_right,
+                    tex_bottom,
+                    tex_top,
+                } = *glyph;
+                let ch = ch.escape_unicode();
+                write!(
+                    mod_file,
+                    "
+                    Glyph {{
+                        ch:'{ch}',
+                        advance:{advance}f32,
+                        bb_left:{left}f32,
+                        bb_right:{right}f32,
+                        bb_bottom:{bottom}f32,
+                        bb_top:{top}f32,
+                        tex_left:{tex_left},
+                        tex_right:{tex_right},
+                        tex_bottom:{tex_bottom},
+                        tex_top:{tex_top},
+                    }},"
+                )?;
+            }
+            write!(mod_file, "];")?;
+            write!(mod_file, "pub const CAPLINE: f32 = {capline}f32;")?;
+            write!(mod_file, "pub const X_HEIGHT: f32 = {x_height}f32;")?;
+            write!(mod_file, "}}")?;
+        }
+        Ok(())
+    }
+
+    /// Write the atlas as a binary font asset, which can be loaded at
+    /// runtime with `suzy::platforms::opengl::Font::load_from_bytes`.
+    ///
+    /// See [`write_asset_to`](Self::write_asset_to) for a description of
+    /// the format.
+    pub fn write_asset(self, path: impl AsRef<Path>) -> Result<(), Error>
+    where
+        I: Clone + Iterator<Item = FontSpec>,
+    {
+        let file = std::fs::File::create(path)?;
+        self.write_asset_to(io::BufWriter::new(file))
+    }
+
+    /// Write the atlas as a binary font asset.
+    ///
+    /// All values are little-endian.  The asset starts with the 8-byte
+    /// magic `SUZYFONT`, followed by:
+    ///
+    /// - `u16` format version, currently 2
+    /// - `u16` texture width and `u16` texture height
+    /// - `u16` number of texture channels: 1 for a signed distance field,
+    ///   or 3 for a multi-channel signed distance field.  Absent in
+    ///   version 1, which is always single-channel.
+    /// - `u16` number of faces, then for each face:
+    ///   - `u16` name length, followed by the UTF-8 name
+    ///   - `f32` padding ratio, line spacing, ascent, descent, capline and
+    ///     x-height, relative to the font size
+    ///   - `u32` number of glyphs, then for each glyph, sorted by
+    ///     character: `u32` character, `f32` advance, `f32` left, right,
+    ///     bottom and top bounds, `u16` left, right, bottom and top
+    ///     texture coordinates
+    ///   - `u32` number of kerning pairs, then for each pair, sorted by
+    ///     characters: `u32` left character, `u32` right character, `f32`
+    ///     kerning
+    /// - `u32` length of the texture data, followed by one byte of signed
+    ///   distance field per channel per texture pixel, in rows from the
+    ///   bottom
+    pub fn write_asset_to(self, mut out: impl Write) -> Result<(), Error>
+    where
+        I: Clone + Iterator<Item = FontSpec>,
+    {
+        let atlas = self.build()?;
+        let too_large = || Error::AssetTooLarge;
+        out.write_all(ASSET_MAGIC)?;
+        out.write_all(&ASSET_VERSION.to_le_bytes())?;
+        out.write_all(&atlas.width.to_le_bytes())?;
+        out.write_all(&atlas.height.to_le_bytes())?;
+        out.write_all(&atlas.channels.to_le_bytes())?;
+        let face_count =
+            u16::try_from(atlas.faces.len()).map_err(|_| too_large())?;
+        out.write_all(&face_count.to_le_bytes())?;
+        for face in &atlas.faces {
+            let name_len =
+                u16::try_from(face.name.len()).map_err(|_| too_large())?;
+            out.write_all(&name_len.to_le_bytes())?;
+            out.write_all(face.name.as_bytes())?;
+            for value in [
+                face.padding_ratio,
+                face.line_spacing,
+                face.ascent,
+                face.descent,
+                face.capline,
+                face.x_height,
+            ] {
+                out.write_all(&value.to_le_bytes())?;
+            }
+            let glyph_count =
+                u32::try_from(face.glyphs.len()).map_err(|_| too_large())?;
+            out.write_all(&glyph_count.to_le_bytes())?;
+            for glyph in &face.glyphs {
+                out.write_all(&u32::from(glyph.ch).to_le_bytes())?;
+                for value in [
+                    glyph.advance,
+                    glyph.left,
+                    glyph.right,
+                    glyph.bottom,
+                    glyph.top,
+                ] {
+                    out.write_all(&value.to_le_bytes())?;
+                }
+                for value in [
+                    glyph.tex_left,
+                    glyph.tex_right,
+                    glyph.tex_bottom,
+                    glyph.tex_top,
+                ] {
+                    out.write_all(&value.to_le_bytes())?;
+                }
+            }
+            let pair_count =
+                u32::try_from(face.kerning.len()).map_err(|_| too_large())?;
+            out.write_all(&pair_count.to_le_bytes())?;
+            for &(left, right, kerning) in &face.kerning {
+                out.write_all(&u32::from(left).to_le_bytes())?;
+                out.write_all(&u32::from(right).to_le_bytes())?;
+                out.write_all(&kerning.to_le_bytes())?;
+            }
+        }
+        let data_len =
+            u32::try_from(atlas.data.len()).map_err(|_| too_large())?;
+        out.write_all(&data_len.to_le_bytes())?;
+        out.write_all(&atlas.data)?;
+        out.flush()?;
+        Ok(())
+    }
+
+    fn build(self) -> Result<BuiltAtlas, Error>
     where
         I: Clone + Iterator<Item = FontSpec>,
     {
@@ -104,6 +361,7 @@ impl<I> FontAtlas<I> {
             font_data,
             spec,
             padding_ratio,
+            kind,
         } = self;
         let faces = font_data
             .iter()
@@ -121,20 +379,7 @@ impl<I> FontAtlas<I> {
                 blurry::Error::PackingAtlasFailed => Error::PackingAtlasFailed,
                 _ => Error::FontParseError,
             })?;
-        let mut texture_path = path.as_ref().as_os_str().to_os_string();
-        texture_path.push(".texture");
-        std::fs::write(texture_path, asset.data)?;
-        let mut mod_file = std::fs::OpenOptions::new()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(path)?;
-        write!(mod_file, "pub const TEXTURE_WIDTH: u16 = {};", asset.width)?;
-        write!(
-            mod_file,
-            "pub const TEXTURE_HEIGHT: u16 = {};",
-            asset.height
-        )?;
+        let mut built_faces = Vec::with_capacity(font_data.len());
         for (index, font) in font_data.iter().enumerate() {
             let font_face = &faces[index];
             let height = f32::from(font_face.units_per_em());
@@ -144,24 +389,22 @@ impl<I> FontAtlas<I> {
             let mut capline = font_face.capital_height().map(rel_value);
             let mut x_height = font_face.x_height().map(rel_value);
             let descent = rel_value(font_face.descender());
-            write!(mod_file, "pub mod {} {{", font.name)?;
-            write!(
-                mod_file,
-                "
-                use super::Glyph;
-                pub const PADDING_RATIO: f32 = {padding_ratio}f32;
-                pub const LINE_SPACING: f32 = {line_spacing}f32;
-                pub const ASCENT: f32 = {ascent}f32;
-                pub const DESCENT: f32 = {descent}f32;
-                ",
-            )?;
             let mut glyphs: Vec<_> = asset
                 .metadata
                 .iter()
                 .filter(|glyph| glyph.user_data == index)
                 .collect();
             glyphs.sort_by_key(|glyph| glyph.codepoint);
-            write!(mod_file, "pub static GLYPHS: &[Glyph] = &[")?;
+            let chars: Vec<char> =
+                glyphs.iter().map(|glyph| glyph.codepoint).collect();
+            let kerning = Kerning::new(font_face)
+                .pairs(font_face, &chars)
+                .into_iter()
+                .map(|(left, right, kerning)| {
+                    (left, right, f32::from(kerning) / height)
+                })
+                .collect();
+            let mut built_glyphs = Vec::with_capacity(glyphs.len());
             for glyph in glyphs {
                 let blurry::Glyph {
                     codepoint,
@@ -181,7 +424,6 @@ impl<I> FontAtlas<I> {
                 if capline.is_none() && codepoint == 'I' {
                     capline = Some(top);
                 }
-                let ch = codepoint.escape_unicode();
                 let advance = font_face
                     .glyph_index(codepoint)
                     .and_then(|id| font_face.glyph_hor_advance(id))
@@ -195,40 +437,175 @@ impl<I> FontAtlas<I> {
                     .round_ties_even() as u16;
                 let tex_top = (tex_top * f32::from(asset.height))
                     .round_ties_even() as u16;
-                write!(
-                    mod_file,
-                    "
-                    Glyph {{
-                        ch:'{ch}',
-                        advance:{advance}f32,
-                        bb_left:{left}f32,
-                        bb_right:{right}f32,
-                        bb_bottom:{bottom}f32,
-                        bb_top:{top}f32,
-                        tex_left:{tex_left},
-                        tex_right:{tex_right},
-                        tex_bottom:{tex_bottom},
-                        tex_top:{tex_top},
-                    }},"
-                )?;
+                built_glyphs.push(BuiltGlyph {
+                    ch: codepoint,
+                    advance,
+                    left,
+                    right,
+                    bottom,
+                    top,
+                    tex_left,
+                    tex_right,
+                    tex_bottom,
+                    tex_top,
+                });
             }
-            write!(mod_file, "];")?;
-            let capline = capline.unwrap_or(0.7);
-            let x_height = x_height.unwrap_or(0.5);
-            write!(mod_file, "pub const CAPLINE: f32 = {capline}f32;")?;
-            write!(mod_file, "pub const X_HEIGHT: f32 = {x_height}f32;")?;
-            write!(mod_file, "}}")?;
+            built_faces.push(BuiltFace {
+                name: font.name.clone(),
+                padding_ratio,
+                line_spacing,
+                ascent,
+                descent,
+                capline: capline.unwrap_or(0.7),
+                x_height: x_height.unwrap_or(0.5),
+                glyphs: built_glyphs,
+                kerning,
+            });
         }
+        let (channels, data) = match kind {
+            AtlasKind::Sdf => (1, asset.data),
+            AtlasKind::Msdf => {
+                let size = [asset.width, asset.height];
+                (3, render_msdf(&faces, &built_faces, size, padding_ratio)?)
+            }
+        };
+        Ok(BuiltAtlas {
+            width: asset.width,
+            height: asset.height,
+            channels,
+            data,
+            faces: built_faces,
+        })
+    }
+}
+
+impl FontAtlas<std::vec::IntoIter<FontSpec>> {
+    /// Add a font, like [`add_font`](FontAtlas::add_font), but without
+    /// changing the type of the atlas.
+    pub fn push_font(
+        &mut self,
+        out_name: String,
+        font: impl AsRef<Path>,
+        chars: impl Iterator<Item = char>,
+    ) -> Result<(), io::Error> {
+        let font_index = self.font_data.len();
+        let data = std::fs::read(font)?;
+        self.font_data.push(FontData {
+            name: out_name,
+            data,
+        });
+        let mut spec: Vec<_> = self.spec.by_ref().collect();
+        spec.extend(chars.map(|ch| FontSpec { font_index, ch }));
+        self.spec = spec.into_iter();
         Ok(())
     }
 }
 
+const ASSET_MAGIC: &[u8; 8] = b"SUZYFONT";
+const ASSET_VERSION: u16 = 2;
+
+struct BuiltAtlas {
+    width: u16,
+    height: u16,
+    channels: u16,
+    data: Vec<u8>,
+    faces: Vec<BuiltFace>,
+}
+
+struct BuiltFace {
+    name: String,
+    padding_ratio: f32,
+    line_spacing: f32,
+    ascent: f32,
+    descent: f32,
+    capline: f32,
+    x_height: f32,
+    glyphs: Vec<BuiltGlyph>,
+    kerning: Vec<(char, char, f32)>,
+}
+
+#[derive(Clone, Copy)]
+struct BuiltGlyph {
+    ch: char,
+    advance: f32,
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+    tex_left: u16,
+    tex_right: u16,
+    tex_bottom: u16,
+    tex_top: u16,
+}
+
+/// Render the glyphs of an atlas again as a multi-channel signed distance
+/// field, in the places blurry packed them.
+fn render_msdf(
+    faces: &[Face<'_>],
+    built_faces: &[BuiltFace],
+    [width, height]: [u16; 2],
+    padding_ratio: f32,
+) -> Result<Vec<u8>, Error> {
+    let width = usize::from(width);
+    let mut data = vec![0; width * usize::from(height) * 3];
+    for (face, built) in faces.iter().zip(built_faces) {
+        for glyph in &built.glyphs {
+            let shape = msdf::Shape::from_glyph(face, glyph.ch)
+                .ok_or(Error::MissingGlyph(glyph.ch))?;
+            let bounds = [glyph.left, glyph.right, glyph.bottom, glyph.top];
+            let rect = [
+                glyph.tex_left,
+                glyph.tex_bottom,
+                glyph.tex_right - glyph.tex_left,
+                glyph.tex_top - glyph.tex_bottom,
+            ]
+            .map(usize::from);
+            msdf::render(
+                &shape,
+                bounds,
+                padding_ratio,
+                (&mut data, width),
+                rect,
+            );
+        }
+    }
+    Ok(data)
+}
+
 #[derive(Debug)]
 pub enum Error {
     Io(io::Error),
     FontParseError,
     MissingGlyph(char),
     PackingAtlasFailed,
+    AssetTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => err.fmt(f),
+            Self::FontParseError => f.write_str("failed to parse font"),
+            Self::MissingGlyph(ch) => {
+                write!(f, "font has no glyph for {ch:?}")
+            }
+            Self::PackingAtlasFailed => {
+                f.write_str("glyphs don't fit in the texture")
+            }
+            Self::AssetTooLarge => {
+                f.write_str("font asset is too large for the format")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl From<io::Error> for Error {
@@ -237,6 +614,7 @@ impl From<io::Error> for Error {
     }
 }
 
+#[derive(Clone, Copy, Debug)]
 pub struct FontSpec {
     font_index: usize,
     ch: char,
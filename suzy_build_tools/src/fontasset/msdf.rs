@@ -0,0 +1,477 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Multi-channel signed distance fields.
+//!
+//! The edges of each glyph outline are given colors, so that at every
+//! corner the two edges which meet there share only one of the red, green
+//! and blue channels.  Each channel stores the distance to the nearest
+//! edge of its color, and the median of the three channels reconstructs
+//! the distance to the outline with the corners kept sharp.
+
+use blurry::ttf_parser::{Face, OutlineBuilder};
+
+type Point = [f32; 2];
+
+const RED: u8 = 1;
+const GREEN: u8 = 2;
+const BLUE: u8 = 4;
+const WHITE: u8 = RED | GREEN | BLUE;
+const YELLOW: u8 = RED | GREEN;
+const MAGENTA: u8 = RED | BLUE;
+const CYAN: u8 = GREEN | BLUE;
+
+/// Edges which meet at an angle with a sine larger than this form a
+/// corner, about 8 degrees.
+const CORNER_SINE: f32 = 0.14;
+
+/// The number of points along a curve checked before refining the
+/// nearest one.
+const CURVE_SAMPLES: u16 = 12;
+
+const NEWTON_STEPS: u16 = 5;
+
+fn sub(a: Point, b: Point) -> Point {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot(a: Point, b: Point) -> f32 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn cross(a: Point, b: Point) -> f32 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn normalize(a: Point) -> Point {
+    let len = dot(a, a).sqrt();
+    if len > 0.0 {
+        [a[0] / len, a[1] / len]
+    } else {
+        [0.0, 0.0]
+    }
+}
+
+fn mix(a: Point, b: Point, t: f32) -> Point {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Segment {
+    Line([Point; 2]),
+    Quad([Point; 3]),
+    Cubic([Point; 4]),
+}
+
+impl Segment {
+    fn point(&self, t: f32) -> Point {
+        match *self {
+            Self::Line([a, b]) => mix(a, b, t),
+            Self::Quad([a, b, c]) => mix(mix(a, b, t), mix(b, c, t), t),
+            Self::Cubic([a, b, c, d]) => {
+                let ab = mix(a, b, t);
+                let bc = mix(b, c, t);
+                let cd = mix(c, d, t);
+                mix(mix(ab, bc, t), mix(bc, cd, t), t)
+            }
+        }
+    }
+
+    fn derivative(&self, t: f32) -> Point {
+        match *self {
+            Self::Line([a, b]) => sub(b, a),
+            Self::Quad([a, b, c]) => {
+                let d = mix(sub(b, a), sub(c, b), t);
+                [2.0 * d[0], 2.0 * d[1]]
+            }
+            Self::Cubic([a, b, c, d]) => {
+                let ab = sub(b, a);
+                let bc = sub(c, b);
+                let cd = sub(d, c);
+                let d = mix(mix(ab, bc, t), mix(bc, cd, t), t);
+                [3.0 * d[0], 3.0 * d[1]]
+            }
+        }
+    }
+
+    fn second_derivative(&self, t: f32) -> Point {
+        match *self {
+            Self::Line(_) => [0.0, 0.0],
+            Self::Quad([a, b, c]) => {
+                let d = sub(sub(c, b), sub(b, a));
+                [2.0 * d[0], 2.0 * d[1]]
+            }
+            Self::Cubic([a, b, c, d]) => {
+                let first = sub(sub(c, b), sub(b, a));
+                let second = sub(sub(d, c), sub(c, b));
+                let d = mix(first, second, t);
+                [6.0 * d[0], 6.0 * d[1]]
+            }
+        }
+    }
+
+    /// The direction of the segment at `t`, falling back to the chord where
+    /// control points coincide with an end point.
+    fn direction(&self, t: f32) -> Point {
+        let direction = self.derivative(t);
+        if dot(direction, direction) > f32::EPSILON {
+            return direction;
+        }
+        sub(self.point(1.0), self.point(0.0))
+    }
+
+    /// Find the point on the segment nearest `p`, returning its parameter.
+    fn nearest_t(&self, p: Point) -> f32 {
+        if let Self::Line([a, b]) = *self {
+            let ab = sub(b, a);
+            let len2 = dot(ab, ab);
+            return if len2 > 0.0 {
+                (dot(sub(p, a), ab) / len2).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+        }
+        let dist2 = |t: f32| {
+            let v = sub(self.point(t), p);
+            dot(v, v)
+        };
+        let mut best_t = (0..=CURVE_SAMPLES)
+            .map(|i| f32::from(i) / f32::from(CURVE_SAMPLES))
+            .min_by(|&a, &b| dist2(a).total_cmp(&dist2(b)))
+            .unwrap_or(0.0);
+        for _ in 0..NEWTON_STEPS {
+            let v = sub(self.point(best_t), p);
+            let d1 = self.derivative(best_t);
+            let d2 = self.second_derivative(best_t);
+            let slope = dot(d1, d1) + dot(v, d2);
+            if slope.abs() <= f32::EPSILON {
+                break;
+            }
+            let t = (best_t - dot(v, d1) / slope).clamp(0.0, 1.0);
+            if dist2(t) >= dist2(best_t) {
+                break;
+            }
+            best_t = t;
+        }
+        best_t
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    segment: Segment,
+    color: u8,
+}
+
+/// The distance from a point to an edge.
+#[derive(Clone, Copy, Debug)]
+struct EdgeDistance {
+    /// The distance to the nearest point, positive inside the outline.
+    signed: f32,
+    /// How far from perpendicular the edge is to the nearest point, which
+    /// decides between edges equally distant from a corner.
+    obliqueness: f32,
+    t: f32,
+    edge: Edge,
+}
+
+impl EdgeDistance {
+    fn new(edge: Edge, p: Point) -> Self {
+        let t = edge.segment.nearest_t(p);
+        let to_point = sub(p, edge.segment.point(t));
+        let direction = normalize(edge.segment.direction(t));
+        let dist = dot(to_point, to_point).sqrt();
+        // outlines wind clockwise, so the inside is on the right
+        let side = if cross(direction, to_point) < 0.0 {
+            1.0
+        } else {
+            -1.0
+        };
+        Self {
+            signed: side * dist,
+            obliqueness: dot(direction, normalize(to_point)).abs(),
+            t,
+            edge,
+        }
+    }
+
+    fn is_nearer(&self, other: &Self) -> bool {
+        let (dist, other_dist) = (self.signed.abs(), other.signed.abs());
+        dist < other_dist
+            || (dist == other_dist && self.obliqueness < other.obliqueness)
+    }
+
+    /// The distance to the edge as if it were extended along its tangent
+    /// past its end points, which keeps the distances of corners sharp.
+    fn pseudo(&self, p: Point) -> f32 {
+        let segment = &self.edge.segment;
+        let end = if self.t <= 0.0 {
+            0.0
+        } else if self.t >= 1.0 {
+            1.0
+        } else {
+            return self.signed;
+        };
+        let direction = normalize(segment.direction(end));
+        let to_point = sub(p, segment.point(end));
+        let beyond = dot(to_point, direction);
+        if (end == 0.0 && beyond < 0.0) || (end == 1.0 && beyond > 0.0) {
+            let pseudo = -cross(direction, to_point);
+            if pseudo.abs() <= self.signed.abs() {
+                return pseudo;
+            }
+        }
+        self.signed
+    }
+}
+
+/// The outline of a glyph, relative to the font size.
+#[derive(Debug, Default)]
+pub(super) struct Shape {
+    contours: Vec<Vec<Edge>>,
+    scale: f32,
+    start: Point,
+    cursor: Point,
+}
+
+impl Shape {
+    pub fn from_glyph(face: &Face<'_>, ch: char) -> Option<Self> {
+        let mut shape = Self {
+            scale: 1.0 / f32::from(face.units_per_em()),
+            ..Self::default()
+        };
+        face.outline_glyph(face.glyph_index(ch)?, &mut shape)?;
+        shape.close();
+        shape.contours.retain(|contour| !contour.is_empty());
+        for contour in &mut shape.contours {
+            color_edges(contour);
+        }
+        Some(shape)
+    }
+
+    fn push(&mut self, segment: Segment, end: Point) {
+        if let Some(contour) = self.contours.last_mut() {
+            contour.push(Edge {
+                segment,
+                color: WHITE,
+            });
+        }
+        self.cursor = end;
+    }
+
+    fn scaled(&self, x: f32, y: f32) -> Point {
+        [x * self.scale, y * self.scale]
+    }
+
+    /// Find the color channels of the distance field at a point, as
+    /// distances relative to the font size.
+    pub fn distances(&self, p: Point) -> [f32; 3] {
+        let edges = self.contours.iter().flatten();
+        let mut nearest: Option<EdgeDistance> = None;
+        let mut channels: [Option<EdgeDistance>; 3] = [None; 3];
+        for &edge in edges {
+            let distance = EdgeDistance::new(edge, p);
+            let channel_bits = [RED, GREEN, BLUE];
+            let slots = std::iter::once(&mut nearest).chain(
+                channels
+                    .iter_mut()
+                    .zip(channel_bits)
+                    .filter(|(_, bit)| edge.color & bit != 0)
+                    .map(|(slot, _)| slot),
+            );
+            for slot in slots {
+                if slot.is_none_or(|current| distance.is_nearer(&current)) {
+                    *slot = Some(distance);
+                }
+            }
+        }
+        let Some(nearest) = nearest else {
+            return [f32::NEG_INFINITY; 3];
+        };
+        let values = channels.map(|channel| {
+            channel.map_or(nearest.signed, |channel| channel.pseudo(p))
+        });
+        // where the channels disagree with the true distance about which
+        // side of the outline the point is on, the median would give an
+        // artifact, so fall back to a single-channel distance
+        let [r, g, b] = values;
+        let median = r.min(g).max(r.max(g).min(b));
+        if (median > 0.0) != (nearest.signed > 0.0) {
+            [nearest.signed; 3]
+        } else {
+            values
+        }
+    }
+}
+
+impl OutlineBuilder for Shape {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.close();
+        self.contours.push(Vec::new());
+        self.start = self.scaled(x, y);
+        self.cursor = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let end = self.scaled(x, y);
+        if end != self.cursor {
+            self.push(Segment::Line([self.cursor, end]), end);
+        }
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let end = self.scaled(x, y);
+        let control = self.scaled(x1, y1);
+        self.push(Segment::Quad([self.cursor, control, end]), end);
+    }
+
+    fn curve_to(
+        &mut self,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        x: f32,
+        y: f32,
+    ) {
+        let end = self.scaled(x, y);
+        let first = self.scaled(x1, y1);
+        let second = self.scaled(x2, y2);
+        self.push(Segment::Cubic([self.cursor, first, second, end]), end);
+    }
+
+    fn close(&mut self) {
+        if self.cursor != self.start {
+            let start = self.start;
+            self.push(Segment::Line([self.cursor, start]), start);
+        }
+    }
+}
+
+fn is_corner(before: Point, after: Point) -> bool {
+    let (before, after) = (normalize(before), normalize(after));
+    dot(before, after) <= 0.0 || cross(before, after).abs() > CORNER_SINE
+}
+
+/// Color the edges of a contour so that the edges on each side of a corner
+/// share only one channel.
+fn color_edges(contour: &mut [Edge]) {
+    let len = contour.len();
+    let corners: Vec<usize> = (0..len)
+        .filter(|&i| {
+            let before = contour[(i + len - 1) % len].segment.direction(1.0);
+            is_corner(before, contour[i].segment.direction(0.0))
+        })
+        .collect();
+    match *corners.as_slice() {
+        [] => {}
+        [corner] => {
+            // a teardrop: split the contour into thirds, the middle one
+            // sharing a channel with each of the others
+            if len >= 3 {
+                for offset in 0..len {
+                    let third = offset * 3 / len;
+                    contour[(corner + offset) % len].color =
+                        [MAGENTA, WHITE, YELLOW][third];
+                }
+            }
+        }
+        _ => {
+            let cycle = [CYAN, MAGENTA, YELLOW];
+            let splines = corners.len();
+            for (spline, &corner) in corners.iter().enumerate() {
+                let mut color = cycle[spline % 3];
+                // the last spline also meets the first one
+                if spline + 1 == splines && spline % 3 == 0 {
+                    color = cycle[1];
+                }
+                let end = corners[(spline + 1) % splines];
+                let count = (end + len - corner - 1) % len + 1;
+                for offset in 0..count {
+                    contour[(corner + offset) % len].color = color;
+                }
+            }
+        }
+    }
+}
+
+/// Render a glyph's distance field into an area of an RGB image.
+///
+/// `bounds` are the left, right, bottom and top edges of the area,
+/// relative to the font size, and `rect` is the left and bottom pixel of
+/// the area and its width and height.
+pub(super) fn render(
+    shape: &Shape,
+    bounds: [f32; 4],
+    padding_ratio: f32,
+    image: (&mut [u8], usize),
+    rect: [usize; 4],
+) {
+    let (data, image_width) = image;
+    let [left, right, bottom, top] = bounds;
+    let [x0, y0, width, height] = rect;
+    for py in 0..height {
+        let y = bottom + (py as f32 + 0.5) / height as f32 * (top - bottom);
+        for px in 0..width {
+            let x = left + (px as f32 + 0.5) / width as f32 * (right - left);
+            let distances = shape.distances([x, y]);
+            let offset = ((y0 + py) * image_width + x0 + px) * 3;
+            for (byte, distance) in
+                data[offset..offset + 3].iter_mut().zip(distances)
+            {
+                let value = 0.5 + 0.5 * distance / padding_ratio;
+                *byte = (value.clamp(0.0, 1.0) * 255.0) as u8;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Shape {
+        let mut shape = Shape {
+            scale: 1.0,
+            ..Shape::default()
+        };
+        // clockwise, like a TrueType outer contour
+        shape.move_to(0.0, 0.0);
+        shape.line_to(0.0, 1.0);
+        shape.line_to(1.0, 1.0);
+        shape.line_to(1.0, 0.0);
+        shape.close();
+        for contour in &mut shape.contours {
+            color_edges(contour);
+        }
+        shape
+    }
+
+    fn median([r, g, b]: [f32; 3]) -> f32 {
+        r.min(g).max(r.max(g).min(b))
+    }
+
+    #[test]
+    fn corners_separate_channels() {
+        let shape = square();
+        let colors: Vec<u8> =
+            shape.contours[0].iter().map(|edge| edge.color).collect();
+        for (i, &color) in colors.iter().enumerate() {
+            let next = colors[(i + 1) % colors.len()];
+            assert_eq!((color & next).count_ones(), 1);
+        }
+    }
+
+    #[test]
+    fn median_keeps_corners_sharp() {
+        let shape = square();
+        assert!(median(shape.distances([0.5, 0.5])) > 0.49);
+        assert!(median(shape.distances([1.5, 0.5])) < -0.49);
+        // beyond a corner, the distance to the extended edges is the
+        // larger of the two, rather than the distance to the corner point
+        let beyond = median(shape.distances([1.1, 1.1]));
+        assert!((beyond + 0.1).abs() < 1e-4, "{}", beyond);
+        let inside = median(shape.distances([0.9, 0.9]));
+        assert!((inside - 0.1).abs() < 1e-4, "{}", inside);
+    }
+}
@@ -0,0 +1,178 @@
+/* SPDX-License-Identifier: (Apache-2.0 OR MIT OR Zlib) */
+/* Copyright © 2023 Violet Leonard */
+
+//! Kerning between pairs of glyphs, read from a font's tables.
+
+use blurry::ttf_parser::{
+    gpos::{PairAdjustment, PositioningSubtable},
+    kern, Face, GlyphId, Tag,
+};
+
+/// The horizontal kerning between pairs of glyphs in a font.
+///
+/// Pair adjustments from the GPOS `kern` feature are used if the font has
+/// them, otherwise the legacy `kern` table.
+#[derive(Clone, Debug)]
+pub struct Kerning<'a> {
+    /// Pair adjustment subtables, grouped by lookup.
+    gpos_lookups: Vec<Vec<PairAdjustment<'a>>>,
+    kern: Option<kern::Table<'a>>,
+}
+
+impl<'a> Kerning<'a> {
+    /// Find the kerning tables of a font face.
+    #[must_use]
+    pub fn new(face: &Face<'a>) -> Self {
+        let gpos_lookups = face
+            .tables()
+            .gpos
+            .map(|gpos| {
+                let kern = Tag::from_bytes(b"kern");
+                let mut indices: Vec<_> = gpos
+                    .features
+                    .into_iter()
+                    .filter(|feature| feature.tag == kern)
+                    .flat_map(|feature| feature.lookup_indices)
+                    .collect();
+                indices.sort_unstable();
+                indices.dedup();
+                indices
+                    .into_iter()
+                    .filter_map(|index| gpos.lookups.get(index))
+                    .map(|lookup| {
+                        lookup
+                            .subtables
+                            .into_iter::<PositioningSubtable<'_>>()
+                            .filter_map(|subtable| match subtable {
+                                PositioningSubtable::Pair(pair) => Some(pair),
+                                _ => None,
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            gpos_lookups,
+            kern: face.tables().kern,
+        }
+    }
+
+    /// Find the horizontal kerning, in font units, between two glyphs.
+    #[must_use]
+    pub fn pair(&self, left: GlyphId, right: GlyphId) -> i16 {
+        if self.gpos_lookups.is_empty() {
+            self.kern
+                .into_iter()
+                .flat_map(|kern| kern.subtables)
+                .filter(|sub| {
+                    sub.horizontal
+                        && !sub.variable
+                        && !sub.has_cross_stream
+                        && !sub.has_state_machine
+                })
+                .filter_map(|sub| sub.glyphs_kerning(left, right))
+                .fold(0, i16::saturating_add)
+        } else {
+            // only the first subtable covering the pair applies in each
+            // lookup
+            self.gpos_lookups
+                .iter()
+                .filter_map(|subtables| {
+                    subtables.iter().find_map(|subtable| {
+                        pair_adjustment(subtable, left, right)
+                    })
+                })
+                .fold(0, i16::saturating_add)
+        }
+    }
+
+    /// Find the horizontal kerning, in font units, for each pair of
+    /// `chars`, leaving out pairs with no kerning.
+    ///
+    /// `chars` must be sorted, and the returned pairs are sorted the same
+    /// way.
+    #[must_use]
+    pub fn pairs(
+        &self,
+        face: &Face<'_>,
+        chars: &[char],
+    ) -> Vec<(char, char, i16)> {
+        let glyphs: Vec<_> = chars
+            .iter()
+            .filter_map(|&ch| Some((ch, face.glyph_index(ch)?)))
+            .collect();
+        let mut pairs = Vec::new();
+        for &(left_ch, left) in &glyphs {
+            for &(right_ch, right) in &glyphs {
+                let kerning = self.pair(left, right);
+                if kerning != 0 {
+                    pairs.push((left_ch, right_ch, kerning));
+                }
+            }
+        }
+        pairs
+    }
+}
+
+fn pair_adjustment(
+    subtable: &PairAdjustment<'_>,
+    left: GlyphId,
+    right: GlyphId,
+) -> Option<i16> {
+    let coverage_index = subtable.coverage().get(left)?;
+    let (first, _second) = match subtable {
+        PairAdjustment::Format1 { sets, .. } => {
+            sets.get(coverage_index)?.get(right)?
+        }
+        PairAdjustment::Format2 {
+            classes, matrix, ..
+        } => matrix.get((classes.0.get(left), classes.1.get(right)))?,
+    };
+    Some(first.x_advance)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    fn roboto_pairs() -> Vec<(char, char, i16)> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../build-default-font/src/roboto/Roboto-Regular.ttf");
+        let data = std::fs::read(path).expect("failed to read Roboto");
+        let face = Face::parse(&data, 0).expect("failed to parse Roboto");
+        let chars: Vec<char> = (' '..='~').collect();
+        Kerning::new(&face).pairs(&face, &chars)
+    }
+
+    fn kerning(pairs: &[(char, char, i16)], left: char, right: char) -> i16 {
+        pairs
+            .iter()
+            .find(|&&(l, r, _)| (l, r) == (left, right))
+            .map_or(0, |&(_, _, kerning)| kerning)
+    }
+
+    #[test]
+    fn roboto_has_kerning() {
+        let pairs = roboto_pairs();
+        assert!(kerning(&pairs, 'A', 'V') < 0);
+        assert!(kerning(&pairs, 'T', 'o') < 0);
+    }
+
+    #[test]
+    fn pairs_are_sorted() {
+        let pairs = roboto_pairs();
+        assert!(pairs
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+    }
+
+    #[test]
+    fn zero_kerning_is_left_out() {
+        let pairs = roboto_pairs();
+        assert!(!pairs.is_empty());
+        assert!(pairs.iter().all(|&(_, _, kerning)| kerning != 0));
+    }
+}
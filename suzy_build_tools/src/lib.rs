@@ -2,3 +2,4 @@
 /* Copyright © 2021 Violet Leonard */
 
 pub mod fontasset;
+pub mod kerning;